@@ -1,11 +1,62 @@
 use once_cell::sync::Lazy;
+use rand::Rng;
 use regex::Regex;
 
+/// Lowercase letters and digits for generated ids.
+pub const LOWERCASE_ALPHANUMERIC: &str = "abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Letters and digits for generated ids and secrets.
+pub const ALPHANUMERIC: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
 /// Returns true if value is None or empty.
 pub fn is_blank(value: &Option<String>) -> bool {
     value.as_ref().unwrap_or(&"".to_string()).is_empty()
 }
 
+/// Returns a random string of the characters.
+pub fn random_string(characters: &str, length: usize) -> String {
+    let characters = characters.as_bytes();
+    let mut rng = rand::thread_rng();
+    (0..length)
+        .map(|_| characters[rng.gen_range(0..characters.len())] as char)
+        .collect()
+}
+
+/// Percent-encodes all characters except the unreserved ones of RFC 3986.
+pub fn uri_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Returns a random (version 4) UUID.
+pub fn new_uuid() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// Developer provider name regex
+pub static DEVELOPER_PROVIDER_NAME_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\w._-]+").unwrap());
+
 /// Arn regex
 pub static ARN_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"arn:[\w+=/,.@-]+:[\w+=/,.@-]+:([\w+=/,.@-]*)?:[0-9]+:[\w+=/,.@-]+(:[\w+=/,.@-]+)?(:[\w+=/,.@-]+)?").unwrap()
@@ -25,6 +76,13 @@ pub static EMAIL_REGEX: Lazy<Regex> =
 pub static USER_POOL_ID_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"[\w-]+_[0-9a-zA-Z]+").unwrap());
 
+/// Identity pool id regex
+pub static IDENTITY_POOL_ID_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\w-]+:[0-9a-f-]+").unwrap());
+
+/// Identity id regex
+pub static IDENTITY_ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w-]+:[0-9a-f-]+").unwrap());
+
 /// Device key regex
 pub static DEVICE_KEY_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"[\w-]+_[0-9a-zA-Z]+").unwrap());
@@ -33,6 +91,25 @@ pub static DEVICE_KEY_REGEX: Lazy<Regex> =
 pub static NAME_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"[\p{L}\p{M}\p{S}\p{N}\p{P}]+").unwrap());
 
+/// Identity provider identifier regex
+pub static IDP_IDENTIFIER_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w\s+=.@-]+").unwrap());
+
+/// Resource server identifier regex
+pub static RESOURCE_SERVER_IDENTIFIER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\x21\x23-\x5B\x5D-\x7E]+").unwrap());
+
+/// Resource server name regex
+pub static RESOURCE_SERVER_NAME_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\w\s+=,.@-]+").unwrap());
+
+/// User pool domain regex
+pub static DOMAIN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[a-z0-9](?:[a-z0-9\-]{0,61}[a-z0-9])?").unwrap());
+
+/// User import job id regex
+pub static IMPORT_JOB_ID_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"import-[0-9a-zA-Z-]+").unwrap());
+
 /// Password regex
 pub static PASSWORD_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
 
@@ -69,4 +146,21 @@ mod tests {
     fn string_is_not_blank() {
         assert!(!is_blank(&Some("some".to_string())));
     }
+
+    #[test]
+    fn random_string_has_the_characters() {
+        let s = random_string(LOWERCASE_ALPHANUMERIC, 26);
+        assert_eq!(26, s.len());
+        assert!(s.chars().all(|c| LOWERCASE_ALPHANUMERIC.contains(c)));
+    }
+
+    #[test]
+    fn uuid_has_version_4_format() {
+        let uuid = new_uuid();
+        let re =
+            Regex::new(r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$")
+                .unwrap();
+        assert!(re.is_match(&uuid));
+        assert_ne!(uuid, new_uuid());
+    }
 }
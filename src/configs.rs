@@ -0,0 +1,136 @@
+use std::{collections::HashMap, path::PathBuf};
+use tokio::sync::OnceCell;
+
+pub type Config = HashMap<String, HashMap<String, String>>;
+
+/// Config key of the sections for identity pools.
+pub const CONFIG_IDENTITY_POOLS: &str = "IdentityPools";
+
+pub const CONFIG_ERROR_TYPE: &str = "error_type";
+
+const DEFAULT_USER_POOLS_CONFIG_PATH: &str = "./user_pools.yml";
+static CONFIG: OnceCell<Configs> = OnceCell::const_new();
+
+/// Config sections of the user pools (and the whole server) and of the identity pools.
+#[derive(Debug, Default)]
+struct Configs {
+    user_pools: Config,
+    identity_pools: Config,
+}
+
+pub fn get_config(action: &str, name: &str) -> Option<String> {
+    config()
+        .get(action)
+        .unwrap_or(&HashMap::new())
+        .get(name)
+        .cloned()
+}
+
+/// Initializes global config.
+pub async fn init_config(path: Option<&PathBuf>) {
+    CONFIG
+        .get_or_init(|| async move {
+            match read_config(path) {
+                Ok(c) => c,
+                Err(e) => {
+                    log::error!("{}", e);
+                    Configs::default()
+                }
+            }
+        })
+        .await;
+}
+
+fn read_config(path: Option<&PathBuf>) -> Result<Configs, Box<dyn std::error::Error>> {
+    let default_path = PathBuf::from(DEFAULT_USER_POOLS_CONFIG_PATH);
+    if path.is_none() && !default_path.exists() {
+        return Ok(Default::default());
+    }
+    let path = path.unwrap_or(&default_path);
+    if !path.exists() {
+        log::error!("No such file or directory: `{}`", path.display());
+        return Ok(Default::default());
+    }
+
+    let s = std::fs::read_to_string(path)?;
+
+    log::info!("read config: {}", path.display());
+    let mut m: HashMap<String, serde_yaml::Value> = serde_yaml::from_str(&s)?;
+    let identity_pools = match m.remove(CONFIG_IDENTITY_POOLS) {
+        Some(value) => to_config(&value)?,
+        None => Config::new(),
+    };
+    let user_pools = to_config(&m)?;
+    Ok(Configs {
+        user_pools,
+        identity_pools,
+    })
+}
+
+/// Reads sections from yaml again so that scalar values (e.g. `900`) are read as strings.
+fn to_config<T: serde::Serialize>(value: &T) -> Result<Config, serde_yaml::Error> {
+    serde_yaml::from_str(&serde_yaml::to_string(value)?)
+}
+
+/// Returns config of the user pools and the whole server.
+pub fn config() -> &'static Config {
+    &configs().user_pools
+}
+
+/// Returns config in the `IdentityPools` section.
+pub fn identity_pools_config() -> &'static Config {
+    &configs().identity_pools
+}
+
+/// Returns the global config, or empty config before it is initialized (e.g. in unit tests).
+fn configs() -> &'static Configs {
+    static EMPTY: once_cell::sync::Lazy<Configs> = once_cell::sync::Lazy::new(Configs::default);
+    CONFIG.get().unwrap_or(&EMPTY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const VALID_TEST_CONFIG: &str = "resources/test/test_valid_user_pools.yml";
+    const INVALID_TEST_CONFIG: &str = "resources/test/test_invalid_user_pools.yml";
+
+    #[test]
+    fn success_to_read_valid_config() {
+        let config = read_config(Some(&PathBuf::from(VALID_TEST_CONFIG)));
+        assert!(config.is_ok());
+
+        let admin_add_user_to_group = config
+            .as_ref()
+            .unwrap()
+            .user_pools
+            .get("AdminAddUserToGroup");
+        assert!(admin_add_user_to_group.is_some());
+        assert_eq!(
+            Some(&"InternalFailure".to_string()),
+            admin_add_user_to_group.unwrap().get(CONFIG_ERROR_TYPE)
+        );
+    }
+
+    #[test]
+    fn identity_pools_config_is_namespaced() {
+        let config = read_config(Some(&PathBuf::from(VALID_TEST_CONFIG))).unwrap();
+        assert!(!config.user_pools.contains_key(CONFIG_IDENTITY_POOLS));
+        assert!(!config.user_pools.contains_key("GetId"));
+        assert_eq!(
+            Some(&"InternalErrorException".to_string()),
+            config.identity_pools["GetId"].get(CONFIG_ERROR_TYPE)
+        );
+        assert_eq!(
+            Some(&"900".to_string()),
+            config.identity_pools["Credentials"].get("duration_seconds")
+        );
+    }
+
+    #[test]
+    fn error_to_read_invalid_config() {
+        let config = read_config(Some(&PathBuf::from(INVALID_TEST_CONFIG)));
+        assert!(config.is_err());
+    }
+}
@@ -7,7 +7,7 @@ use strum_macros::{Display, EnumString};
 #[macro_export]
 macro_rules! gen_response_err {
     ($t:ident,$($( $errs:ident )|+ => $s:expr),*) => {
-        use super::ToStatusCode;
+        use $crate::responses::ToStatusCode;
 
         #[allow(clippy::enum_variant_names)]
         #[derive(Display, EnumString)]
@@ -38,6 +38,7 @@ gen_response_err!(
     | InvalidParameterCombination
     | InvalidParameterValue
     | InvalidQueryParameter
+    | InvalidSignatureException
     | MissingAction
     | MissingParameter
     | NotAuthorized
@@ -0,0 +1,49 @@
+mod create_identity_pool;
+mod credentials;
+mod data_types;
+mod describe_identity_pool;
+mod developer_identities;
+mod get_credentials_for_identity;
+mod get_id;
+mod get_identity_pool_roles;
+mod get_open_id_token;
+mod get_open_id_token_for_developer_identity;
+mod lookup_developer_identity;
+mod merge_developer_identities;
+mod open_id_tokens;
+mod role_mappings;
+mod set_identity_pool_roles;
+mod store;
+mod unlink_developer_identity;
+
+pub use self::create_identity_pool::*;
+pub use self::describe_identity_pool::*;
+pub use self::get_credentials_for_identity::*;
+pub use self::get_id::*;
+pub use self::get_identity_pool_roles::*;
+pub use self::get_open_id_token::*;
+pub use self::get_open_id_token_for_developer_identity::*;
+pub use self::lookup_developer_identity::*;
+pub use self::merge_developer_identities::*;
+pub use self::set_identity_pool_roles::*;
+pub use self::unlink_developer_identity::*;
+
+pub use self::credentials::*;
+pub use self::data_types::*;
+pub use self::developer_identities::*;
+pub use self::open_id_tokens::*;
+pub use self::role_mappings::*;
+pub use self::store::*;
+
+use crate::configs::Config;
+use crate::errors::{gen_response_err, ResponseError};
+use crate::responses::{
+    to_applied_empty_response, to_converted_json_response, to_empty_response, to_json_response,
+    Response, ToActionName, ToResponse,
+};
+use crate::services::Service;
+
+/// Returns the `IdentityPools` config sections.
+fn config() -> &'static Config {
+    Service::IdentityPools.config()
+}
@@ -0,0 +1,112 @@
+use crate::common::{ARN_REGEX, DEVELOPER_PROVIDER_NAME_REGEX};
+use crate::{http, validator::regex_in_array};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const CREATE_IDENTITY_POOL_NAME: &str = "CreateIdentityPool";
+pub const CREATE_IDENTITY_POOL_ACTION_NAME: &str = "AWSCognitoIdentityService.CreateIdentityPool";
+
+static IDENTITY_POOL_NAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w\s+=,.@-]+").unwrap());
+
+fn validate_provider_arns(value: &[String]) -> Result<(), ValidationError> {
+    regex_in_array(value, &ARN_REGEX)
+}
+
+super::gen_response_err!(
+    CreateIdentityPoolError,
+    InvalidParameterException
+    | LimitExceededException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateIdentityPoolRequest {
+    pub allow_classic_flow: Option<bool>,
+    #[validate(required)]
+    pub allow_unauthenticated_identities: Option<bool>,
+    #[validate(nested)]
+    pub cognito_identity_providers: Option<Vec<super::CognitoIdentityProvider>>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *DEVELOPER_PROVIDER_NAME_REGEX))]
+    pub developer_provider_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *IDENTITY_POOL_NAME_REGEX))]
+    pub identity_pool_name: Option<String>,
+    pub identity_pool_tags: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "OpenIdConnectProviderARNs")]
+    #[validate(custom(function = validate_provider_arns))]
+    pub open_id_connect_provider_arns: Option<Vec<String>>,
+    #[serde(rename = "SamlProviderARNs")]
+    #[validate(custom(function = validate_provider_arns))]
+    pub saml_provider_arns: Option<Vec<String>>,
+    #[validate(length(max = 10))]
+    pub supported_login_providers: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for CreateIdentityPoolRequest {
+    fn to_action_name() -> &'static str {
+        CREATE_IDENTITY_POOL_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for CreateIdentityPoolRequest {
+    type E = CreateIdentityPoolError;
+    fn to_response(&self) -> super::Response {
+        super::to_converted_json_response(self, CREATE_IDENTITY_POOL_NAME, |mut value| {
+            let mut identity_pool = super::IdentityPoolState::new();
+            value["IdentityPoolId"] = identity_pool.id.as_str().into();
+            identity_pool.description = value.clone();
+            super::insert_identity_pool(identity_pool);
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = CreateIdentityPoolRequest {
+            allow_unauthenticated_identities: Some(false),
+            identity_pool_name: Some("identity_pool_name".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = CreateIdentityPoolRequest {
+            allow_unauthenticated_identities: None,
+            identity_pool_name: Some("identity_pool_name".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = CreateIdentityPoolError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = CreateIdentityPoolError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,16 @@
+/// Config section of the fake credentials returned for identities.
+pub const CONFIG_CREDENTIALS: &str = "Credentials";
+
+/// Lifetime of the fake credentials in seconds when the config does not specify it.
+pub const DEFAULT_CREDENTIALS_DURATION_SECONDS: i64 = 3600;
+
+const CONFIG_DURATION_SECONDS: &str = "duration_seconds";
+
+/// Returns the lifetime of the fake credentials in seconds.
+pub fn credentials_duration_seconds() -> i64 {
+    super::config()
+        .get(CONFIG_CREDENTIALS)
+        .and_then(|c| c.get(CONFIG_DURATION_SECONDS))
+        .and_then(|d| d.parse().ok())
+        .unwrap_or(DEFAULT_CREDENTIALS_DURATION_SECONDS)
+}
@@ -0,0 +1,9 @@
+mod cognito_identity_provider;
+mod mapping_rule;
+mod role_mapping;
+mod rules_configuration_type;
+
+pub use cognito_identity_provider::*;
+pub use mapping_rule::*;
+pub use role_mapping::*;
+pub use rules_configuration_type::*;
@@ -0,0 +1,20 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+static CLIENT_ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w_]+").unwrap());
+
+static PROVIDER_NAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w._:/-]+").unwrap());
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct CognitoIdentityProvider {
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *PROVIDER_NAME_REGEX))]
+    pub provider_name: Option<String>,
+    pub server_side_token_check: Option<bool>,
+}
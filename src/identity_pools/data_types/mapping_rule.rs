@@ -0,0 +1,33 @@
+use crate::common::ARN_REGEX;
+use crate::validator::includes;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+static CLAIM_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\p{L}\p{M}\p{S}\p{N}\p{P}]+").unwrap());
+
+fn validate_match_type(value: &str) -> Result<(), ValidationError> {
+    includes(value, vec!["Equals", "Contains", "StartsWith", "NotEqual"])
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct MappingRule {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 64))]
+    #[validate(regex(path = *CLAIM_REGEX))]
+    pub claim: Option<String>,
+    #[validate(required)]
+    #[validate(custom(function = validate_match_type))]
+    pub match_type: Option<String>,
+    #[serde(rename = "RoleARN")]
+    #[validate(required)]
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex(path = *ARN_REGEX))]
+    pub role_arn: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    pub value: Option<String>,
+}
@@ -0,0 +1,24 @@
+use crate::validator::includes;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+fn validate_ambiguous_role_resolution(value: &str) -> Result<(), ValidationError> {
+    includes(value, vec!["AuthenticatedRole", "Deny"])
+}
+
+fn validate_role_mapping_type(value: &str) -> Result<(), ValidationError> {
+    includes(value, vec!["Token", "Rules"])
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct RoleMapping {
+    #[validate(custom(function = validate_ambiguous_role_resolution))]
+    pub ambiguous_role_resolution: Option<String>,
+    #[validate(nested)]
+    pub rules_configuration: Option<super::RulesConfigurationType>,
+    #[serde(rename = "Type")]
+    #[validate(required)]
+    #[validate(custom(function = validate_role_mapping_type))]
+    pub mapping_type: Option<String>,
+}
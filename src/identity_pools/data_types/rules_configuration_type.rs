@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct RulesConfigurationType {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 400))]
+    #[validate(nested)]
+    pub rules: Option<Vec<super::MappingRule>>,
+}
@@ -0,0 +1,85 @@
+use crate::common::IDENTITY_POOL_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DESCRIBE_IDENTITY_POOL_NAME: &str = "DescribeIdentityPool";
+pub const DESCRIBE_IDENTITY_POOL_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.DescribeIdentityPool";
+
+super::gen_response_err!(
+    DescribeIdentityPoolError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DescribeIdentityPoolRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DescribeIdentityPoolRequest {
+    fn to_action_name() -> &'static str {
+        DESCRIBE_IDENTITY_POOL_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for DescribeIdentityPoolRequest {
+    type E = DescribeIdentityPoolError;
+    fn to_response(&self) -> super::Response {
+        let identity_pool_id = self.identity_pool_id.as_deref().unwrap_or_default();
+        match super::with_identity_pool(identity_pool_id, |p| p.description.clone()) {
+            Some(description) => {
+                super::to_converted_json_response(self, DESCRIBE_IDENTITY_POOL_NAME, |_| {
+                    Ok(description)
+                })
+            }
+            None => super::to_json_response(self, DESCRIBE_IDENTITY_POOL_NAME),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DescribeIdentityPoolRequest {
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DescribeIdentityPoolRequest {
+            identity_pool_id: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = DescribeIdentityPoolError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DescribeIdentityPoolError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,261 @@
+use crate::common;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Config section of the identities linked to developer user identifiers.
+pub const CONFIG_DEVELOPER_IDENTITIES: &str = "DeveloperIdentities";
+
+/// Identity pool id, developer provider name and developer user identifier.
+type DeveloperLogin = (String, String, String);
+
+/// Identity ids linked to developer user identifiers, or `None` for unlinked identifiers.
+///
+/// Links of this store take precedence over the configured `DeveloperIdentities`.
+static DEVELOPER_IDENTITIES: Lazy<RwLock<HashMap<DeveloperLogin, Option<String>>>> =
+    Lazy::new(Default::default);
+
+fn developer_login(
+    identity_pool_id: &str,
+    provider: &str,
+    developer_user_identifier: &str,
+) -> DeveloperLogin {
+    (
+        identity_pool_id.to_string(),
+        provider.to_string(),
+        developer_user_identifier.to_string(),
+    )
+}
+
+/// Returns the identity id linked to a developer user identifier, or the id derived for it.
+///
+/// Identifiers never linked get an id derived from the identity pool and the identifier, so the
+/// same user always gets the same identity.
+pub fn developer_identity_id(identity_pool_id: &str, developer_user_identifier: &str) -> String {
+    linked_identity_id(identity_pool_id, None, developer_user_identifier)
+        .unwrap_or_else(|| derived_identity_id(identity_pool_id, developer_user_identifier))
+}
+
+/// Returns the identity id linked to a developer user identifier of a provider, or of any
+/// provider if `provider` is `None`.
+pub fn linked_identity_id(
+    identity_pool_id: &str,
+    provider: Option<&str>,
+    developer_user_identifier: &str,
+) -> Option<String> {
+    let identities = DEVELOPER_IDENTITIES.read().unwrap();
+    let mut stored = identities.iter().filter(|((pool, p, identifier), _)| {
+        pool == identity_pool_id
+            && provider.is_none_or(|provider| p == provider)
+            && identifier == developer_user_identifier
+    });
+    match stored.next() {
+        Some((_, identity_id)) => identity_id.clone(),
+        None => configured_developer_identities()
+            .and_then(|c| c.get(developer_user_identifier))
+            .cloned(),
+    }
+}
+
+/// Links a developer user identifier to `identity_id` unless it is linked already, and returns
+/// the linked identity id.
+///
+/// Identifiers without `identity_id` are linked to the derived identity, or to a new identity if
+/// they have been unlinked.
+pub fn link_developer_identity(
+    identity_pool_id: &str,
+    provider: &str,
+    developer_user_identifier: &str,
+    identity_id: Option<&str>,
+) -> String {
+    if let Some(linked) =
+        linked_identity_id(identity_pool_id, Some(provider), developer_user_identifier)
+    {
+        return linked;
+    }
+    let login = developer_login(identity_pool_id, provider, developer_user_identifier);
+    let mut identities = DEVELOPER_IDENTITIES.write().unwrap();
+    let identity_id = match (identity_id, identities.contains_key(&login)) {
+        (Some(identity_id), _) => identity_id.to_string(),
+        (None, false) => derived_identity_id(identity_pool_id, developer_user_identifier),
+        (None, true) => {
+            let region = identity_pool_id.split(':').next().unwrap_or_default();
+            format!("{}:{}", region, common::new_uuid())
+        }
+    };
+    identities.insert(login, Some(identity_id.clone()));
+    identity_id
+}
+
+/// Links the source user identifier and every identifier linked to its identity to the identity
+/// of the destination user identifier, and returns the destination identity id.
+pub fn merge_developer_identities(
+    identity_pool_id: &str,
+    provider: &str,
+    source_user_identifier: &str,
+    destination_user_identifier: &str,
+) -> String {
+    let destination = link_developer_identity(
+        identity_pool_id,
+        provider,
+        destination_user_identifier,
+        None,
+    );
+    let source = link_developer_identity(identity_pool_id, provider, source_user_identifier, None);
+    let mut identities = DEVELOPER_IDENTITIES.write().unwrap();
+    for ((pool, p, _), identity_id) in identities.iter_mut() {
+        if pool == identity_pool_id && p == provider && identity_id.as_deref() == Some(&source) {
+            *identity_id = Some(destination.clone());
+        }
+    }
+    destination
+}
+
+/// Unlinks a developer user identifier from an identity.
+///
+/// Returns a message if the identifier is linked to another identity.
+pub fn unlink_developer_identity(
+    identity_pool_id: &str,
+    provider: &str,
+    developer_user_identifier: &str,
+    identity_id: &str,
+) -> Result<(), String> {
+    let linked = linked_identity_id(identity_pool_id, Some(provider), developer_user_identifier);
+    if linked
+        .as_deref()
+        .is_some_and(|linked| linked != identity_id)
+    {
+        return Err("Developer user identifier is linked to another identity.".to_string());
+    }
+    if linked.is_some() {
+        DEVELOPER_IDENTITIES.write().unwrap().insert(
+            developer_login(identity_pool_id, provider, developer_user_identifier),
+            None,
+        );
+    }
+    Ok(())
+}
+
+/// Returns the developer user identifiers linked to an identity id.
+pub fn developer_user_identifiers(identity_pool_id: &str, identity_id: &str) -> Vec<String> {
+    let mut identifiers = configured_developer_identities()
+        .map(|c| linked_user_identifiers(c, identity_id))
+        .unwrap_or_default();
+    identifiers.extend(
+        DEVELOPER_IDENTITIES
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|((pool, _, _), id)| {
+                pool == identity_pool_id && id.as_deref() == Some(identity_id)
+            })
+            .map(|((_, _, identifier), _)| identifier.clone()),
+    );
+    identifiers.retain(|identifier| {
+        linked_identity_id(identity_pool_id, None, identifier).as_deref() == Some(identity_id)
+    });
+    identifiers.sort_unstable();
+    identifiers.dedup();
+    identifiers
+}
+
+fn configured_developer_identities() -> Option<&'static HashMap<String, String>> {
+    super::config().get(CONFIG_DEVELOPER_IDENTITIES)
+}
+
+fn linked_user_identifiers(identities: &HashMap<String, String>, identity_id: &str) -> Vec<String> {
+    let mut identifiers = identities
+        .iter()
+        .filter(|(_, id)| id.as_str() == identity_id)
+        .map(|(identifier, _)| identifier.clone())
+        .collect::<Vec<_>>();
+    identifiers.sort_unstable();
+    identifiers
+}
+
+fn derived_identity_id(identity_pool_id: &str, developer_user_identifier: &str) -> String {
+    let region = identity_pool_id.split(':').next().unwrap_or_default();
+    let digest = Sha256::digest(format!(
+        "{}:{}",
+        identity_pool_id, developer_user_identifier
+    ));
+    let hex = digest
+        .iter()
+        .take(16)
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    format!(
+        "{}:{}-{}-{}-{}-{}",
+        region,
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IDENTITY_ID_REGEX;
+    use pretty_assertions::assert_eq;
+
+    const POOL_ID: &str = "us-east-1:00000000-0000-0000-0000-000000000000";
+
+    #[test]
+    fn derived_identity_id_is_stable_per_user() {
+        let identity_id = derived_identity_id(POOL_ID, "user01");
+        assert!(identity_id.starts_with("us-east-1:"));
+        assert_eq!(46, identity_id.len());
+        assert!(IDENTITY_ID_REGEX.is_match(&identity_id));
+        assert_eq!(identity_id, derived_identity_id(POOL_ID, "user01"));
+        assert_ne!(identity_id, derived_identity_id(POOL_ID, "user02"));
+    }
+
+    #[test]
+    fn developer_identities_can_be_merged_and_unlinked() {
+        let pool = "us-east-1:22222222-2222-2222-2222-222222222222";
+        let provider = "login.example.com";
+        let user01 = link_developer_identity(pool, provider, "user01", None);
+        assert_eq!(derived_identity_id(pool, "user01"), user01);
+        let user02 = link_developer_identity(pool, provider, "user02", None);
+        assert_ne!(user01, user02);
+
+        assert_eq!(
+            user01,
+            merge_developer_identities(pool, provider, "user02", "user01")
+        );
+        assert_eq!(
+            vec!["user01".to_string(), "user02".to_string()],
+            developer_user_identifiers(pool, &user01)
+        );
+        assert!(developer_user_identifiers(pool, &user02).is_empty());
+
+        assert!(unlink_developer_identity(pool, provider, "user02", &user02).is_err());
+        unlink_developer_identity(pool, provider, "user02", &user01).unwrap();
+        assert_eq!(None, linked_identity_id(pool, Some(provider), "user02"));
+        assert_eq!(
+            vec!["user01".to_string()],
+            developer_user_identifiers(pool, &user01)
+        );
+        let relinked = link_developer_identity(pool, provider, "user02", None);
+        assert_ne!(user01, relinked);
+        assert_ne!(user02, relinked);
+    }
+
+    #[test]
+    fn user_identifiers_are_linked_to_identity() {
+        let identities = HashMap::from([
+            ("user02".to_string(), "us-east-1:1111".to_string()),
+            ("user01".to_string(), "us-east-1:1111".to_string()),
+            ("user03".to_string(), "us-east-1:2222".to_string()),
+        ]);
+        assert_eq!(
+            vec!["user01".to_string(), "user02".to_string()],
+            linked_user_identifiers(&identities, "us-east-1:1111")
+        );
+        assert!(linked_user_identifiers(&identities, "us-east-1:3333").is_empty());
+    }
+}
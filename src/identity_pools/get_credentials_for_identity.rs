@@ -0,0 +1,144 @@
+use crate::common::{ARN_REGEX, IDENTITY_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+use warp::http::HeaderValue;
+
+pub const GET_CREDENTIALS_FOR_IDENTITY_NAME: &str = "GetCredentialsForIdentity";
+pub const GET_CREDENTIALS_FOR_IDENTITY_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.GetCredentialsForIdentity";
+
+const CREDENTIALS_KEY: &str = "Credentials";
+const ROLE_ARN_KEY: &str = "RoleArn";
+
+super::gen_response_err!(
+    GetCredentialsForIdentityError,
+    ExternalServiceException
+    | InvalidIdentityPoolConfigurationException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetCredentialsForIdentityRequest {
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex(path = *ARN_REGEX))]
+    pub custom_role_arn: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_ID_REGEX))]
+    pub identity_id: Option<String>,
+    #[validate(length(max = 10))]
+    pub logins: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for GetCredentialsForIdentityRequest {
+    fn to_action_name() -> &'static str {
+        GET_CREDENTIALS_FOR_IDENTITY_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for GetCredentialsForIdentityRequest {
+    type E = GetCredentialsForIdentityError;
+    fn to_response(&self) -> super::Response {
+        let mut role_arn = None;
+        let mut response = super::to_converted_json_response(
+            self,
+            GET_CREDENTIALS_FOR_IDENTITY_NAME,
+            |mut value| {
+                let role = self
+                    .resolve_role()
+                    .map_err(|(e, message)| (super::ResponseError::ActionError(e), message))?;
+                if let Some(credentials) = value.get_mut(CREDENTIALS_KEY) {
+                    if credentials.get("Expiration").is_none() {
+                        credentials["Expiration"] = (chrono::Utc::now().timestamp()
+                            + super::credentials_duration_seconds())
+                        .into();
+                    }
+                }
+                value[ROLE_ARN_KEY] = role.as_str().into();
+                role_arn = Some(role);
+                Ok(value)
+            },
+        );
+        if let Some(role_arn) = role_arn.and_then(|r| HeaderValue::from_str(&r).ok()) {
+            response
+                .headers_mut()
+                .insert(super::ROLE_ARN_DEBUG_HEADER, role_arn);
+        }
+        response
+    }
+}
+
+impl GetCredentialsForIdentityRequest {
+    /// Resolves the role of the identity by the roles and role mappings of the identity pool.
+    ///
+    /// Identities not issued by stored identity pools use the roles of the template.
+    fn resolve_role(&self) -> Result<String, (GetCredentialsForIdentityError, String)> {
+        let identity_pool_id =
+            super::identity_pool_id_of(self.identity_id.as_deref().unwrap_or_default())
+                .unwrap_or_default();
+        let roles = super::identity_pool_roles(&identity_pool_id).unwrap_or_default();
+        super::resolve_role(
+            &roles,
+            self.logins.as_ref(),
+            self.custom_role_arn.as_deref(),
+        )
+        .map_err(|error| match error {
+            super::RoleResolutionError::NotAuthorized(message) => (
+                GetCredentialsForIdentityError::NotAuthorizedException,
+                message,
+            ),
+            super::RoleResolutionError::InvalidConfiguration(message) => (
+                GetCredentialsForIdentityError::InvalidIdentityPoolConfigurationException,
+                message,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetCredentialsForIdentityRequest {
+            identity_id: Some("us-east-1:11111111-1111-1111-1111-111111111111".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetCredentialsForIdentityRequest {
+            custom_role_arn: Some("role".to_string()),
+            identity_id: Some("us-east-1:11111111-1111-1111-1111-111111111111".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetCredentialsForIdentityError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetCredentialsForIdentityError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::common::IDENTITY_POOL_ID_REGEX;
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_ID_NAME: &str = "GetId";
+pub const GET_ID_ACTION_NAME: &str = "AWSCognitoIdentityService.GetId";
+
+static ACCOUNT_ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\d+").unwrap());
+
+super::gen_response_err!(
+    GetIdError,
+    ExternalServiceException
+    | InvalidParameterException
+    | LimitExceededException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetIdRequest {
+    #[validate(length(min = 1, max = 15))]
+    #[validate(regex(path = *ACCOUNT_ID_REGEX))]
+    pub account_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+    #[validate(length(max = 10))]
+    pub logins: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for GetIdRequest {
+    fn to_action_name() -> &'static str {
+        GET_ID_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for GetIdRequest {
+    type E = GetIdError;
+    fn to_response(&self) -> super::Response {
+        let identity_pool_id = self.identity_pool_id.as_deref().unwrap_or_default();
+        if !super::identity_pool_exists(identity_pool_id) {
+            return super::to_json_response(self, GET_ID_NAME);
+        }
+        super::to_converted_json_response(self, GET_ID_NAME, |mut value| {
+            let logins = self.login_subjects();
+            if let Some(identity_id) = super::with_identity_pool(identity_pool_id, |p| {
+                if logins.is_empty() {
+                    p.new_identity()
+                } else {
+                    p.login_identity(&logins)
+                }
+            }) {
+                value["IdentityId"] = identity_id.into();
+            }
+            Ok(value)
+        })
+    }
+}
+
+impl GetIdRequest {
+    /// Returns the provider names and the subjects (`sub` claims) of the login tokens.
+    ///
+    /// Tokens which are not JWTs are the subjects themselves.
+    fn login_subjects(&self) -> Vec<(String, String)> {
+        let mut logins = self
+            .logins
+            .iter()
+            .flatten()
+            .map(|(provider, token)| {
+                let subject = crate::jwt::token_claims(token)
+                    .and_then(|claims| claims.get("sub")?.as_str().map(str::to_string))
+                    .unwrap_or_else(|| token.clone());
+                (provider.clone(), subject)
+            })
+            .collect::<Vec<_>>();
+        logins.sort_unstable();
+        logins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetIdRequest {
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetIdRequest {
+            account_id: Some("account_id".to_string()),
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetIdError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetIdError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::common::IDENTITY_POOL_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_IDENTITY_POOL_ROLES_NAME: &str = "GetIdentityPoolRoles";
+pub const GET_IDENTITY_POOL_ROLES_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.GetIdentityPoolRoles";
+
+super::gen_response_err!(
+    GetIdentityPoolRolesError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetIdentityPoolRolesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+}
+
+impl super::ToActionName for GetIdentityPoolRolesRequest {
+    fn to_action_name() -> &'static str {
+        GET_IDENTITY_POOL_ROLES_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for GetIdentityPoolRolesRequest {
+    type E = GetIdentityPoolRolesError;
+    fn to_response(&self) -> super::Response {
+        let identity_pool_id = self.identity_pool_id.as_deref().unwrap_or_default();
+        let roles = match super::with_identity_pool(identity_pool_id, |p| p.roles.clone()) {
+            Some(roles) => roles,
+            None => return super::to_json_response(self, GET_IDENTITY_POOL_ROLES_NAME),
+        };
+        super::to_converted_json_response(self, GET_IDENTITY_POOL_ROLES_NAME, |mut value| {
+            value["RoleMappings"] = serde_json::json!(roles.role_mappings);
+            value["Roles"] = serde_json::json!(roles.roles);
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetIdentityPoolRolesRequest {
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetIdentityPoolRolesRequest {
+            identity_pool_id: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetIdentityPoolRolesError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetIdentityPoolRolesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::common::IDENTITY_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_OPEN_ID_TOKEN_NAME: &str = "GetOpenIdToken";
+pub const GET_OPEN_ID_TOKEN_ACTION_NAME: &str = "AWSCognitoIdentityService.GetOpenIdToken";
+
+super::gen_response_err!(
+    GetOpenIdTokenError,
+    ExternalServiceException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetOpenIdTokenRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_ID_REGEX))]
+    pub identity_id: Option<String>,
+    #[validate(length(max = 10))]
+    pub logins: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for GetOpenIdTokenRequest {
+    fn to_action_name() -> &'static str {
+        GET_OPEN_ID_TOKEN_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for GetOpenIdTokenRequest {
+    type E = GetOpenIdTokenError;
+    fn to_response(&self) -> super::Response {
+        super::to_json_response(self, GET_OPEN_ID_TOKEN_NAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetOpenIdTokenRequest {
+            identity_id: Some("us-east-1:11111111-1111-1111-1111-111111111111".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetOpenIdTokenRequest {
+            identity_id: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetOpenIdTokenError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetOpenIdTokenError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
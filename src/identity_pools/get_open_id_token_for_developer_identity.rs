@@ -0,0 +1,164 @@
+use crate::common::{IDENTITY_ID_REGEX, IDENTITY_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_OPEN_ID_TOKEN_FOR_DEVELOPER_IDENTITY_NAME: &str =
+    "GetOpenIdTokenForDeveloperIdentity";
+pub const GET_OPEN_ID_TOKEN_FOR_DEVELOPER_IDENTITY_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.GetOpenIdTokenForDeveloperIdentity";
+
+super::gen_response_err!(
+    GetOpenIdTokenForDeveloperIdentityError,
+    DeveloperUserAlreadyRegisteredException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetOpenIdTokenForDeveloperIdentityRequest {
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_ID_REGEX))]
+    pub identity_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 10))]
+    pub logins: Option<HashMap<String, String>>,
+    #[validate(length(max = 50))]
+    pub principal_tags: Option<HashMap<String, String>>,
+    #[validate(range(min = 1, max = 86400))]
+    pub token_duration: Option<i64>,
+}
+
+impl super::ToActionName for GetOpenIdTokenForDeveloperIdentityRequest {
+    fn to_action_name() -> &'static str {
+        GET_OPEN_ID_TOKEN_FOR_DEVELOPER_IDENTITY_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for GetOpenIdTokenForDeveloperIdentityRequest {
+    type E = GetOpenIdTokenForDeveloperIdentityError;
+    fn to_response(&self) -> super::Response {
+        super::to_converted_json_response(
+            self,
+            GET_OPEN_ID_TOKEN_FOR_DEVELOPER_IDENTITY_NAME,
+            |mut value| {
+                let (provider, user_identifier) = match self.developer_login() {
+                    Some(login) => login,
+                    None => return Ok(value),
+                };
+                let identity_pool_id = self.identity_pool_id.as_deref().unwrap_or_default();
+                if value["IdentityId"].is_null() {
+                    let identity_id = super::link_developer_identity(
+                        identity_pool_id,
+                        provider,
+                        user_identifier,
+                        self.identity_id.as_deref(),
+                    );
+                    super::with_identity_pool(identity_pool_id, |identity_pool| {
+                        identity_pool.identities.insert(identity_id.clone())
+                    });
+                    value["IdentityId"] = identity_id.into();
+                }
+                if value["Token"].is_null() {
+                    let amr = vec![
+                        "authenticated".to_string(),
+                        provider.to_string(),
+                        format!("{}:{}:{}", provider, identity_pool_id, user_identifier),
+                    ];
+                    value["Token"] = super::open_id_token(
+                        value["IdentityId"].as_str().unwrap_or_default(),
+                        identity_pool_id,
+                        &amr,
+                        self.token_duration
+                            .unwrap_or(super::DEFAULT_OPEN_ID_TOKEN_DURATION_SECONDS),
+                    )
+                    .into();
+                }
+                Ok(value)
+            },
+        )
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let identity_id = self.identity_id.as_deref()?;
+        let (provider, user_identifier) = self.developer_login()?;
+        super::linked_identity_id(
+            self.identity_pool_id.as_deref().unwrap_or_default(),
+            Some(provider),
+            user_identifier,
+        )
+        .filter(|linked| linked.as_str() != identity_id)
+        .map(|_| {
+            (
+                GetOpenIdTokenForDeveloperIdentityError::DeveloperUserAlreadyRegisteredException,
+                "Developer user identifier is already registered to another identity.".to_string(),
+            )
+        })
+    }
+}
+
+impl GetOpenIdTokenForDeveloperIdentityRequest {
+    /// Returns the first login of the developer provider and its user identifier.
+    fn developer_login(&self) -> Option<(&str, &str)> {
+        self.logins
+            .as_ref()?
+            .iter()
+            .min_by_key(|(provider, _)| provider.as_str())
+            .map(|(provider, user_identifier)| (provider.as_str(), user_identifier.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetOpenIdTokenForDeveloperIdentityRequest {
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            logins: Some(HashMap::from([(
+                "login.example.com".to_string(),
+                "user01".to_string(),
+            )])),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetOpenIdTokenForDeveloperIdentityRequest {
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            logins: Some(HashMap::new()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetOpenIdTokenForDeveloperIdentityError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetOpenIdTokenForDeveloperIdentityError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::common::{IDENTITY_ID_REGEX, IDENTITY_POOL_ID_REGEX};
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LOOKUP_DEVELOPER_IDENTITY_NAME: &str = "LookupDeveloperIdentity";
+pub const LOOKUP_DEVELOPER_IDENTITY_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.LookupDeveloperIdentity";
+
+static NEXT_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    LookupDeveloperIdentityError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct LookupDeveloperIdentityRequest {
+    #[validate(length(min = 1, max = 1024))]
+    pub developer_user_identifier: Option<String>,
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_ID_REGEX))]
+    pub identity_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+    #[validate(range(min = 1, max = 60))]
+    pub max_results: Option<u8>,
+    #[validate(length(min = 1, max = 65535))]
+    #[validate(regex(path = *NEXT_TOKEN_REGEX))]
+    pub next_token: Option<String>,
+}
+
+impl super::ToActionName for LookupDeveloperIdentityRequest {
+    fn to_action_name() -> &'static str {
+        LOOKUP_DEVELOPER_IDENTITY_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for LookupDeveloperIdentityRequest {
+    type E = LookupDeveloperIdentityError;
+    fn to_response(&self) -> super::Response {
+        super::to_converted_json_response(self, LOOKUP_DEVELOPER_IDENTITY_NAME, |mut value| {
+            let identity_id = match (
+                self.identity_id.as_deref(),
+                self.developer_user_identifier.as_deref(),
+            ) {
+                (Some(identity_id), _) => identity_id.to_string(),
+                (None, Some(user_identifier)) => super::developer_identity_id(
+                    self.identity_pool_id.as_deref().unwrap_or_default(),
+                    user_identifier,
+                ),
+                (None, None) => return Ok(value),
+            };
+            let mut user_identifiers = super::developer_user_identifiers(
+                self.identity_pool_id.as_deref().unwrap_or_default(),
+                &identity_id,
+            );
+            if let Some(user_identifier) = self.developer_user_identifier.as_ref() {
+                if !user_identifiers.contains(user_identifier) {
+                    user_identifiers.insert(0, user_identifier.clone());
+                }
+            }
+            if user_identifiers.is_empty() {
+                return Err((
+                    super::ResponseError::ActionError(
+                        LookupDeveloperIdentityError::ResourceNotFoundException,
+                    ),
+                    "No developer user identifiers are linked to the identity.".to_string(),
+                ));
+            }
+            if value.get("IdentityId").is_none() {
+                value["IdentityId"] = identity_id.into();
+            }
+            if value.get("DeveloperUserIdentifierList").is_none() {
+                value["DeveloperUserIdentifierList"] = user_identifiers.into();
+            }
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        if self.identity_id.is_none() && self.developer_user_identifier.is_none() {
+            return Some((
+                LookupDeveloperIdentityError::InvalidParameterException,
+                "IdentityId or DeveloperUserIdentifier is required.".to_string(),
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = LookupDeveloperIdentityRequest {
+            developer_user_identifier: Some("user01".to_string()),
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = LookupDeveloperIdentityRequest {
+            developer_user_identifier: Some("".to_string()),
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = LookupDeveloperIdentityError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = LookupDeveloperIdentityError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
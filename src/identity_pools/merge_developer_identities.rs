@@ -0,0 +1,117 @@
+use crate::common::{DEVELOPER_PROVIDER_NAME_REGEX, IDENTITY_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const MERGE_DEVELOPER_IDENTITIES_NAME: &str = "MergeDeveloperIdentities";
+pub const MERGE_DEVELOPER_IDENTITIES_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.MergeDeveloperIdentities";
+
+super::gen_response_err!(
+    MergeDeveloperIdentitiesError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct MergeDeveloperIdentitiesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 1024))]
+    pub destination_user_identifier: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *DEVELOPER_PROVIDER_NAME_REGEX))]
+    pub developer_provider_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 1024))]
+    pub source_user_identifier: Option<String>,
+}
+
+impl super::ToActionName for MergeDeveloperIdentitiesRequest {
+    fn to_action_name() -> &'static str {
+        MERGE_DEVELOPER_IDENTITIES_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for MergeDeveloperIdentitiesRequest {
+    type E = MergeDeveloperIdentitiesError;
+    fn to_response(&self) -> super::Response {
+        super::to_converted_json_response(self, MERGE_DEVELOPER_IDENTITIES_NAME, |mut value| {
+            let identity_id = super::merge_developer_identities(
+                self.identity_pool_id.as_deref().unwrap_or_default(),
+                self.developer_provider_name.as_deref().unwrap_or_default(),
+                self.source_user_identifier.as_deref().unwrap_or_default(),
+                self.destination_user_identifier
+                    .as_deref()
+                    .unwrap_or_default(),
+            );
+            if value.get("IdentityId").is_none() {
+                value["IdentityId"] = identity_id.into();
+            }
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        if self.destination_user_identifier == self.source_user_identifier {
+            return Some((
+                MergeDeveloperIdentitiesError::InvalidParameterException,
+                "Source and destination user identifiers must be different.".to_string(),
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = MergeDeveloperIdentitiesRequest {
+            destination_user_identifier: Some("user01".to_string()),
+            developer_provider_name: Some("login.example.com".to_string()),
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            source_user_identifier: Some("user02".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = MergeDeveloperIdentitiesRequest {
+            destination_user_identifier: Some("user01".to_string()),
+            developer_provider_name: Some("".to_string()),
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            source_user_identifier: Some("user02".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = MergeDeveloperIdentitiesError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = MergeDeveloperIdentitiesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::jwt;
+use std::collections::HashMap;
+
+/// Config section of the OpenID tokens issued for identities.
+pub const CONFIG_OPEN_ID_TOKEN: &str = "OpenIdToken";
+
+/// Lifetime of OpenID tokens in seconds when the request does not specify it.
+pub const DEFAULT_OPEN_ID_TOKEN_DURATION_SECONDS: i64 = 600;
+
+const CONFIG_ISSUER: &str = "issuer";
+
+/// Returns `issuer` of `OpenIdToken` in the config file, or the issuer of the token signer.
+pub fn open_id_token_issuer() -> String {
+    issuer_from_config(
+        super::config()
+            .get(CONFIG_OPEN_ID_TOKEN)
+            .unwrap_or(&HashMap::new()),
+    )
+}
+
+fn issuer_from_config(config: &HashMap<String, String>) -> String {
+    config
+        .get(CONFIG_ISSUER)
+        .filter(|v| !v.is_empty())
+        .cloned()
+        .unwrap_or_else(|| jwt::token_signer().issuer.clone())
+}
+
+/// Returns an OpenID token of an identity signed (RS256) with the key of the token signer.
+pub fn open_id_token(
+    identity_id: &str,
+    audience: &str,
+    amr: &[String],
+    duration_seconds: i64,
+) -> String {
+    let now = chrono::Utc::now().timestamp();
+    jwt::token_signer().sign(&serde_json::json!({
+        "amr": amr,
+        "aud": audience,
+        "exp": now + duration_seconds,
+        "iat": now,
+        "iss": open_id_token_issuer(),
+        "sub": identity_id,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn issuer_can_read_from_config() {
+        let config = HashMap::from([(
+            CONFIG_ISSUER.to_string(),
+            "http://localhost:9229".to_string(),
+        )]);
+        assert_eq!("http://localhost:9229", issuer_from_config(&config));
+        assert_eq!(
+            jwt::token_signer().issuer,
+            issuer_from_config(&HashMap::new())
+        );
+    }
+
+    #[test]
+    fn signed_token_has_claims_and_rsa_signature() {
+        let token = open_id_token(
+            "us-east-1:11111111-1111-1111-1111-111111111111",
+            "us-east-1:00000000-0000-0000-0000-000000000000",
+            &["authenticated".to_string()],
+            600,
+        );
+        let header = URL_SAFE_NO_PAD.decode(token.split('.').next().unwrap());
+        let header: serde_json::Value = serde_json::from_slice(&header.unwrap()).unwrap();
+        assert_eq!(jwt::SIGNING_ALGORITHM, header["alg"]);
+        let claims = jwt::token_signer().verify(&token).unwrap();
+        assert_eq!(
+            "us-east-1:11111111-1111-1111-1111-111111111111",
+            claims["sub"]
+        );
+        assert_eq!(
+            600,
+            claims["exp"].as_i64().unwrap() - claims["iat"].as_i64().unwrap()
+        );
+    }
+}
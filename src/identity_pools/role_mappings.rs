@@ -0,0 +1,349 @@
+use crate::jwt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response header of the role chosen for fake credentials.
+pub const ROLE_ARN_DEBUG_HEADER: &str = "x-fakey-cognito-role-arn";
+
+const AUTHENTICATED_ROLE_KEY: &str = "authenticated";
+const UNAUTHENTICATED_ROLE_KEY: &str = "unauthenticated";
+const PREFERRED_ROLE_CLAIM: &str = "cognito:preferred_role";
+const ROLES_CLAIM: &str = "cognito:roles";
+const TOKEN_MAPPING_TYPE: &str = "Token";
+const RULES_MAPPING_TYPE: &str = "Rules";
+const AUTHENTICATED_ROLE_RESOLUTION: &str = "AuthenticatedRole";
+
+/// Roles and role mappings of an identity pool.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct IdentityPoolRoles {
+    #[serde(default)]
+    pub role_mappings: HashMap<String, super::RoleMapping>,
+    #[serde(default)]
+    pub roles: HashMap<String, String>,
+}
+
+/// Errors of resolving the role of an identity.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RoleResolutionError {
+    /// The identity is not allowed to get credentials.
+    NotAuthorized(String),
+    /// The identity pool has no role for the identity.
+    InvalidConfiguration(String),
+}
+
+/// Returns roles and role mappings of an identity pool.
+///
+/// Stored identity pools have the roles of SetIdentityPoolRoles, and the other identity pools
+/// have the roles rendered from the GetIdentityPoolRoles template.
+pub fn identity_pool_roles(identity_pool_id: &str) -> Option<IdentityPoolRoles> {
+    if let Some(roles) = super::with_identity_pool(identity_pool_id, |p| p.roles.clone()) {
+        return Some(roles);
+    }
+    let json = crate::templates::render_template(
+        super::Service::IdentityPools.template_dir(),
+        super::GET_IDENTITY_POOL_ROLES_NAME,
+        &super::GetIdentityPoolRolesRequest {
+            identity_pool_id: Some(identity_pool_id.to_string()),
+        },
+        "{}".to_string(),
+    )?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Returns a message if a role mapping lacks settings required by its type.
+pub fn check_role_mapping(provider: &str, role_mapping: &super::RoleMapping) -> Option<String> {
+    if role_mapping.ambiguous_role_resolution.is_none() {
+        Some(format!(
+            "AmbiguousRoleResolution is required for the role mapping of {}.",
+            provider
+        ))
+    } else if role_mapping.mapping_type.as_deref() == Some(RULES_MAPPING_TYPE)
+        && role_mapping.rules_configuration.is_none()
+    {
+        Some(format!(
+            "RulesConfiguration is required for the rules role mapping of {}.",
+            provider
+        ))
+    } else {
+        None
+    }
+}
+
+/// Returns the role ARN for an identity signed in with logins.
+pub fn resolve_role(
+    roles: &IdentityPoolRoles,
+    logins: Option<&HashMap<String, String>>,
+    custom_role_arn: Option<&str>,
+) -> Result<String, RoleResolutionError> {
+    let logins = match logins.filter(|l| !l.is_empty()) {
+        Some(logins) => logins,
+        None => {
+            return roles
+                .roles
+                .get(UNAUTHENTICATED_ROLE_KEY)
+                .cloned()
+                .ok_or_else(|| {
+                    RoleResolutionError::NotAuthorized(
+                        "Unauthenticated access is not supported for this identity pool."
+                            .to_string(),
+                    )
+                })
+        }
+    };
+    let authenticated_role = || {
+        roles
+            .roles
+            .get(AUTHENTICATED_ROLE_KEY)
+            .cloned()
+            .ok_or_else(|| {
+                RoleResolutionError::InvalidConfiguration(
+                    "Invalid identity pool configuration. Check assigned IAM roles for this pool."
+                        .to_string(),
+                )
+            })
+    };
+
+    let mut providers = logins.keys().collect::<Vec<_>>();
+    providers.sort_unstable();
+    let mapped = providers.into_iter().find_map(|provider| {
+        let claims = jwt::token_claims(&logins[provider]).unwrap_or_default();
+        let client_id = claims
+            .get("aud")
+            .or_else(|| claims.get("client_id"))
+            .and_then(|c| c.as_str())?;
+        roles
+            .role_mappings
+            .get(&format!("{}:{}", provider, client_id))
+            .map(|mapping| (mapping, claims))
+    });
+    let (mapping, claims) = match mapped {
+        Some(mapped) => mapped,
+        None => return authenticated_role(),
+    };
+
+    let role = match mapping.mapping_type.as_deref() {
+        Some(TOKEN_MAPPING_TYPE) => token_role(&claims, custom_role_arn),
+        Some(RULES_MAPPING_TYPE) => rules_role(mapping, &claims),
+        _ => None,
+    };
+    match role {
+        Some(role) => Ok(role),
+        None if mapping.ambiguous_role_resolution.as_deref()
+            == Some(AUTHENTICATED_ROLE_RESOLUTION) =>
+        {
+            authenticated_role()
+        }
+        None => Err(RoleResolutionError::NotAuthorized(
+            "No role mapping matches the identity and ambiguous roles are denied.".to_string(),
+        )),
+    }
+}
+
+fn token_role(
+    claims: &serde_json::Map<String, serde_json::Value>,
+    custom_role_arn: Option<&str>,
+) -> Option<String> {
+    let roles = claim_values(claims, ROLES_CLAIM);
+    match custom_role_arn {
+        Some(custom) => roles.into_iter().find(|role| role == custom),
+        None => claims
+            .get(PREFERRED_ROLE_CLAIM)
+            .and_then(|r| r.as_str())
+            .map(str::to_string)
+            .or_else(|| match roles.as_slice() {
+                [role] => Some(role.clone()),
+                _ => None,
+            }),
+    }
+}
+
+fn rules_role(
+    mapping: &super::RoleMapping,
+    claims: &serde_json::Map<String, serde_json::Value>,
+) -> Option<String> {
+    let rules = mapping.rules_configuration.as_ref()?.rules.as_ref()?;
+    rules
+        .iter()
+        .find(|rule| {
+            let (claim, value) = match (rule.claim.as_deref(), rule.value.as_deref()) {
+                (Some(claim), Some(value)) => (claim, value),
+                _ => return false,
+            };
+            let values = claim_values(claims, claim);
+            match rule.match_type.as_deref() {
+                Some("Equals") => values.iter().any(|v| v == value),
+                Some("Contains") => values.iter().any(|v| v.contains(value)),
+                Some("StartsWith") => values.iter().any(|v| v.starts_with(value)),
+                Some("NotEqual") => !values.is_empty() && values.iter().all(|v| v != value),
+                _ => false,
+            }
+        })
+        .and_then(|rule| rule.role_arn.clone())
+}
+
+fn claim_values(claims: &serde_json::Map<String, serde_json::Value>, claim: &str) -> Vec<String> {
+    match claims.get(claim) {
+        Some(serde_json::Value::String(value)) => vec![value.clone()],
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        Some(serde_json::Value::Null) | None => vec![],
+        Some(value) => vec![value.to_string()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity_pools::{MappingRule, RoleMapping, RulesConfigurationType};
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use pretty_assertions::assert_eq;
+
+    const PROVIDER: &str = "cognito-idp.us-east-1.amazonaws.com/us-east-1_example";
+    const AUTH_ROLE: &str = "arn:aws:iam::123456789012:role/Cognito_AuthRole";
+    const UNAUTH_ROLE: &str = "arn:aws:iam::123456789012:role/Cognito_UnauthRole";
+    const ADMIN_ROLE: &str = "arn:aws:iam::123456789012:role/AdminRole";
+    const TENANT_ROLE: &str = "arn:aws:iam::123456789012:role/TenantRole";
+
+    fn token(claims: serde_json::Value) -> String {
+        format!(
+            "header.{}.signature",
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    fn logins(claims: serde_json::Value) -> HashMap<String, String> {
+        HashMap::from([(PROVIDER.to_string(), token(claims))])
+    }
+
+    fn pool_roles(mapping: RoleMapping) -> IdentityPoolRoles {
+        IdentityPoolRoles {
+            role_mappings: HashMap::from([(format!("{}:client_id", PROVIDER), mapping)]),
+            roles: HashMap::from([
+                (AUTHENTICATED_ROLE_KEY.to_string(), AUTH_ROLE.to_string()),
+                (
+                    UNAUTHENTICATED_ROLE_KEY.to_string(),
+                    UNAUTH_ROLE.to_string(),
+                ),
+            ]),
+        }
+    }
+
+    fn token_mapping(resolution: &str) -> RoleMapping {
+        RoleMapping {
+            ambiguous_role_resolution: Some(resolution.to_string()),
+            mapping_type: Some(TOKEN_MAPPING_TYPE.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn rules_mapping(match_type: &str, value: &str) -> RoleMapping {
+        RoleMapping {
+            ambiguous_role_resolution: Some("Deny".to_string()),
+            rules_configuration: Some(RulesConfigurationType {
+                rules: Some(vec![MappingRule {
+                    claim: Some("custom:tenant".to_string()),
+                    match_type: Some(match_type.to_string()),
+                    role_arn: Some(TENANT_ROLE.to_string()),
+                    value: Some(value.to_string()),
+                }]),
+            }),
+            mapping_type: Some(RULES_MAPPING_TYPE.to_string()),
+        }
+    }
+
+    #[test]
+    fn default_roles_are_resolved_without_mappings() {
+        let roles = pool_roles(token_mapping("Deny"));
+        assert_eq!(
+            Ok(UNAUTH_ROLE.to_string()),
+            resolve_role(&roles, None, None)
+        );
+        let no_mapping = logins(serde_json::json!({ "aud": "other_client_id" }));
+        assert_eq!(
+            Ok(AUTH_ROLE.to_string()),
+            resolve_role(&roles, Some(&no_mapping), None)
+        );
+
+        let roles = IdentityPoolRoles::default();
+        assert!(matches!(
+            resolve_role(&roles, None, None),
+            Err(RoleResolutionError::NotAuthorized(_))
+        ));
+        assert!(matches!(
+            resolve_role(&roles, Some(&no_mapping), None),
+            Err(RoleResolutionError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn token_role_is_resolved() {
+        let roles = pool_roles(token_mapping("AuthenticatedRole"));
+        let preferred = logins(serde_json::json!({
+            "aud": "client_id",
+            "cognito:preferred_role": ADMIN_ROLE,
+            "cognito:roles": [ADMIN_ROLE, TENANT_ROLE]
+        }));
+        assert_eq!(
+            Ok(ADMIN_ROLE.to_string()),
+            resolve_role(&roles, Some(&preferred), None)
+        );
+        assert_eq!(
+            Ok(TENANT_ROLE.to_string()),
+            resolve_role(&roles, Some(&preferred), Some(TENANT_ROLE))
+        );
+
+        let ambiguous = logins(serde_json::json!({
+            "aud": "client_id",
+            "cognito:roles": [ADMIN_ROLE, TENANT_ROLE]
+        }));
+        assert_eq!(
+            Ok(AUTH_ROLE.to_string()),
+            resolve_role(&roles, Some(&ambiguous), None)
+        );
+        let roles = pool_roles(token_mapping("Deny"));
+        assert!(matches!(
+            resolve_role(&roles, Some(&ambiguous), None),
+            Err(RoleResolutionError::NotAuthorized(_))
+        ));
+    }
+
+    #[test]
+    fn rules_role_is_resolved() {
+        let tenant = logins(serde_json::json!({
+            "aud": "client_id",
+            "custom:tenant": "tenant-a"
+        }));
+        let role = |match_type, value| {
+            resolve_role(
+                &pool_roles(rules_mapping(match_type, value)),
+                Some(&tenant),
+                None,
+            )
+        };
+        assert_eq!(Ok(TENANT_ROLE.to_string()), role("Equals", "tenant-a"));
+        assert_eq!(Ok(TENANT_ROLE.to_string()), role("Contains", "ant-"));
+        assert_eq!(Ok(TENANT_ROLE.to_string()), role("StartsWith", "tenant"));
+        assert_eq!(Ok(TENANT_ROLE.to_string()), role("NotEqual", "tenant-b"));
+        assert!(role("Equals", "tenant-b").is_err());
+        assert!(role("NotEqual", "tenant-a").is_err());
+    }
+
+    #[test]
+    fn role_mapping_requires_settings_of_its_type() {
+        assert_eq!(None, check_role_mapping(PROVIDER, &token_mapping("Deny")));
+        let mapping = RoleMapping {
+            mapping_type: Some(TOKEN_MAPPING_TYPE.to_string()),
+            ..Default::default()
+        };
+        assert!(check_role_mapping(PROVIDER, &mapping).is_some());
+        let mapping = RoleMapping {
+            ambiguous_role_resolution: Some("Deny".to_string()),
+            mapping_type: Some(RULES_MAPPING_TYPE.to_string()),
+            ..Default::default()
+        };
+        assert!(check_role_mapping(PROVIDER, &mapping).is_some());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::common::{ARN_REGEX, IDENTITY_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const SET_IDENTITY_POOL_ROLES_NAME: &str = "SetIdentityPoolRoles";
+pub const SET_IDENTITY_POOL_ROLES_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.SetIdentityPoolRoles";
+
+const ROLE_KEYS: [&str; 2] = ["authenticated", "unauthenticated"];
+
+super::gen_response_err!(
+    SetIdentityPoolRolesError,
+    ConcurrentModificationException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetIdentityPoolRolesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+    #[validate(length(max = 10))]
+    #[validate(nested)]
+    pub role_mappings: Option<std::collections::HashMap<String, super::RoleMapping>>,
+    #[validate(required)]
+    #[validate(length(max = 2))]
+    pub roles: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for SetIdentityPoolRolesRequest {
+    fn to_action_name() -> &'static str {
+        SET_IDENTITY_POOL_ROLES_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for SetIdentityPoolRolesRequest {
+    type E = SetIdentityPoolRolesError;
+    fn to_response(&self) -> super::Response {
+        let identity_pool_id = self.identity_pool_id.as_deref().unwrap_or_default();
+        if !super::identity_pool_exists(identity_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_identity_pool(identity_pool_id, |identity_pool| {
+                identity_pool.roles = super::IdentityPoolRoles {
+                    role_mappings: self.role_mappings.clone().unwrap_or_default(),
+                    roles: self.roles.clone().unwrap_or_default(),
+                };
+            });
+            Ok(())
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let role_error = self.roles.as_ref()?.iter().find_map(|(key, arn)| {
+            if !ROLE_KEYS.contains(&key.as_str()) {
+                Some(format!("Invalid role key: {}", key))
+            } else if !ARN_REGEX.is_match(arn) {
+                Some(format!("Invalid role ARN: {}", arn))
+            } else {
+                None
+            }
+        });
+        role_error
+            .or_else(|| {
+                self.role_mappings
+                    .as_ref()?
+                    .iter()
+                    .find_map(|(provider, mapping)| super::check_role_mapping(provider, mapping))
+            })
+            .map(|message| {
+                (
+                    SetIdentityPoolRolesError::InvalidParameterException,
+                    message,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = SetIdentityPoolRolesRequest {
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            roles: Some(std::collections::HashMap::from([(
+                "authenticated".to_string(),
+                "arn:aws:iam::123456789012:role/Cognito_AuthRole".to_string(),
+            )])),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = SetIdentityPoolRolesRequest {
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = SetIdentityPoolRolesError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = SetIdentityPoolRolesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
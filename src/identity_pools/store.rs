@@ -0,0 +1,147 @@
+use super::IdentityPoolRoles;
+use crate::common;
+use crate::user_pools::REGION;
+use once_cell::sync::Lazy;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::RwLock;
+
+static IDENTITY_POOLS: Lazy<RwLock<HashMap<String, IdentityPoolState>>> =
+    Lazy::new(Default::default);
+
+/// Identity pool created by CreateIdentityPool.
+#[derive(Debug, Clone)]
+pub struct IdentityPoolState {
+    pub id: String,
+    /// `IdentityPool` of the CreateIdentityPool response.
+    pub description: serde_json::Value,
+    /// Roles and role mappings of SetIdentityPoolRoles.
+    pub roles: IdentityPoolRoles,
+    /// Identity ids issued for the identity pool.
+    pub identities: BTreeSet<String>,
+    /// Identity ids by the provider names and the user identifiers of their logins.
+    pub logins: BTreeMap<(String, String), String>,
+}
+
+impl IdentityPoolState {
+    pub fn new() -> Self {
+        IdentityPoolState {
+            id: format!("{}:{}", REGION, common::new_uuid()),
+            description: serde_json::Value::Null,
+            roles: IdentityPoolRoles::default(),
+            identities: BTreeSet::new(),
+            logins: BTreeMap::new(),
+        }
+    }
+
+    /// Issues a new identity of the identity pool.
+    pub fn new_identity(&mut self) -> String {
+        let region = self.id.split(':').next().unwrap_or(REGION);
+        let identity_id = format!("{}:{}", region, common::new_uuid());
+        self.identities.insert(identity_id.clone());
+        identity_id
+    }
+
+    /// Returns the identity linked to one of the logins, or a new identity, and links every login to it.
+    pub fn login_identity(&mut self, logins: &[(String, String)]) -> String {
+        let identity_id = logins
+            .iter()
+            .find_map(|login| self.logins.get(login).cloned())
+            .unwrap_or_else(|| self.new_identity());
+        for login in logins {
+            self.logins
+                .entry(login.clone())
+                .or_insert_with(|| identity_id.clone());
+        }
+        identity_id
+    }
+}
+
+impl Default for IdentityPoolState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Stores an identity pool.
+pub fn insert_identity_pool(identity_pool: IdentityPoolState) {
+    IDENTITY_POOLS
+        .write()
+        .unwrap()
+        .insert(identity_pool.id.clone(), identity_pool);
+}
+
+/// Returns true if the identity pool is created by CreateIdentityPool.
+///
+/// Actions on the other identity pools return responses rendered from the templates.
+pub fn identity_pool_exists(identity_pool_id: &str) -> bool {
+    IDENTITY_POOLS
+        .read()
+        .unwrap()
+        .contains_key(identity_pool_id)
+}
+
+/// Runs `f` with a stored identity pool, or returns `None` if it does not exist.
+pub fn with_identity_pool<T, F>(identity_pool_id: &str, f: F) -> Option<T>
+where
+    F: FnOnce(&mut IdentityPoolState) -> T,
+{
+    IDENTITY_POOLS
+        .write()
+        .unwrap()
+        .get_mut(identity_pool_id)
+        .map(f)
+}
+
+/// Returns the id of the stored identity pool which issued an identity.
+pub fn identity_pool_id_of(identity_id: &str) -> Option<String> {
+    IDENTITY_POOLS
+        .read()
+        .unwrap()
+        .values()
+        .find(|p| p.identities.contains(identity_id))
+        .map(|p| p.id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{IDENTITY_ID_REGEX, IDENTITY_POOL_ID_REGEX};
+    use pretty_assertions::assert_eq;
+
+    fn login(provider: &str, subject: &str) -> (String, String) {
+        (provider.to_string(), subject.to_string())
+    }
+
+    #[test]
+    fn identities_are_linked_to_logins() {
+        let mut identity_pool = IdentityPoolState::new();
+        assert!(IDENTITY_POOL_ID_REGEX.is_match(&identity_pool.id));
+        let identity_id = identity_pool.login_identity(&[login("provider", "user01")]);
+        assert!(IDENTITY_ID_REGEX.is_match(&identity_id));
+        assert!(identity_id.starts_with("us-east-1:"));
+        assert_eq!(
+            identity_id,
+            identity_pool.login_identity(&[login("other", "user"), login("provider", "user01")])
+        );
+        assert_eq!(
+            identity_id,
+            identity_pool.login_identity(&[login("other", "user")])
+        );
+        assert_ne!(
+            identity_id,
+            identity_pool.login_identity(&[login("provider", "user02")])
+        );
+        assert_eq!(2, identity_pool.identities.len());
+    }
+
+    #[test]
+    fn identity_pool_of_identity_is_found() {
+        let mut identity_pool = IdentityPoolState::new();
+        let identity_id = identity_pool.new_identity();
+        let identity_pool_id = identity_pool.id.clone();
+        insert_identity_pool(identity_pool);
+        assert!(identity_pool_exists(&identity_pool_id));
+        assert_eq!(Some(identity_pool_id), identity_pool_id_of(&identity_id));
+        assert_eq!(None, identity_pool_id_of("us-east-1:unknown"));
+    }
+}
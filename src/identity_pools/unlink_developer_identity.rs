@@ -0,0 +1,112 @@
+use crate::common::{DEVELOPER_PROVIDER_NAME_REGEX, IDENTITY_ID_REGEX, IDENTITY_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const UNLINK_DEVELOPER_IDENTITY_NAME: &str = "UnlinkDeveloperIdentity";
+pub const UNLINK_DEVELOPER_IDENTITY_ACTION_NAME: &str =
+    "AWSCognitoIdentityService.UnlinkDeveloperIdentity";
+
+super::gen_response_err!(
+    UnlinkDeveloperIdentityError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceConflictException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UnlinkDeveloperIdentityRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *DEVELOPER_PROVIDER_NAME_REGEX))]
+    pub developer_provider_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 1024))]
+    pub developer_user_identifier: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_ID_REGEX))]
+    pub identity_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IDENTITY_POOL_ID_REGEX))]
+    pub identity_pool_id: Option<String>,
+}
+
+impl super::ToActionName for UnlinkDeveloperIdentityRequest {
+    fn to_action_name() -> &'static str {
+        UNLINK_DEVELOPER_IDENTITY_NAME
+    }
+
+    fn to_service() -> super::Service {
+        super::Service::IdentityPools
+    }
+}
+
+impl super::ToResponse for UnlinkDeveloperIdentityRequest {
+    type E = UnlinkDeveloperIdentityError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::unlink_developer_identity(
+                self.identity_pool_id.as_deref().unwrap_or_default(),
+                self.developer_provider_name.as_deref().unwrap_or_default(),
+                self.developer_user_identifier
+                    .as_deref()
+                    .unwrap_or_default(),
+                self.identity_id.as_deref().unwrap_or_default(),
+            )
+            .map_err(|message| {
+                (
+                    super::ResponseError::ActionError(
+                        UnlinkDeveloperIdentityError::ResourceConflictException,
+                    ),
+                    message,
+                )
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UnlinkDeveloperIdentityRequest {
+            developer_provider_name: Some("login.example.com".to_string()),
+            developer_user_identifier: Some("user01".to_string()),
+            identity_id: Some("us-east-1:11111111-1111-1111-1111-111111111111".to_string()),
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UnlinkDeveloperIdentityRequest {
+            developer_provider_name: Some("login.example.com".to_string()),
+            developer_user_identifier: Some("user01".to_string()),
+            identity_id: None,
+            identity_pool_id: Some("us-east-1:00000000-0000-0000-0000-000000000000".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = UnlinkDeveloperIdentityError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UnlinkDeveloperIdentityError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
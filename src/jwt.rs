@@ -0,0 +1,198 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use once_cell::sync::Lazy;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::traits::PublicKeyParts;
+use rsa::{Pkcs1v15Sign, RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Config section of the key and issuer which sign tokens.
+pub const CONFIG_TOKENS: &str = "Tokens";
+
+/// Algorithm of the token signatures.
+pub const SIGNING_ALGORITHM: &str = "RS256";
+
+/// Path of the OpenID Connect discovery document.
+pub const OPEN_ID_CONFIGURATION_PATH: &str = "openid-configuration";
+
+/// Path of the JSON web key set to verify tokens.
+pub const JWKS_PATH: &str = "jwks.json";
+
+const CONFIG_ISSUER: &str = "issuer";
+const CONFIG_KEY_ID: &str = "key_id";
+const CONFIG_PRIVATE_KEY: &str = "private_key";
+const DEFAULT_KEY_ID: &str = "fakey-cognito-1";
+const GENERATED_KEY_BITS: usize = 2048;
+
+static TOKEN_SIGNER: Lazy<TokenSigner> = Lazy::new(|| {
+    TokenSigner::from_config(
+        crate::configs::config()
+            .get(CONFIG_TOKENS)
+            .unwrap_or(&HashMap::new()),
+    )
+});
+
+/// RSA key which signs and verifies tokens.
+pub struct TokenSigner {
+    /// Base URL of the token issuers (e.g. `http://localhost:8080`).
+    pub issuer: String,
+    pub key_id: String,
+    private_key: RsaPrivateKey,
+}
+
+/// Returns the signer in the config file, or a signer with a key generated on startup.
+pub fn token_signer() -> &'static TokenSigner {
+    &TOKEN_SIGNER
+}
+
+/// Returns the claims of a token without verifying its signature.
+pub fn token_claims(token: &str) -> Option<serde_json::Map<String, serde_json::Value>> {
+    let payload = token.split('.').nth(1)?;
+    let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    match serde_json::from_slice(&bytes).ok()? {
+        serde_json::Value::Object(claims) => Some(claims),
+        _ => None,
+    }
+}
+
+impl TokenSigner {
+    fn from_config(config: &HashMap<String, String>) -> Self {
+        let get = |name: &str| config.get(name).filter(|v| !v.is_empty()).cloned();
+        let private_key = get(CONFIG_PRIVATE_KEY)
+            .and_then(|pem| {
+                let key = RsaPrivateKey::from_pkcs8_pem(&pem)
+                    .or_else(|_| RsaPrivateKey::from_pkcs1_pem(&pem));
+                if key.is_err() {
+                    log::error!("Invalid {} of {}.", CONFIG_PRIVATE_KEY, CONFIG_TOKENS);
+                }
+                key.ok()
+            })
+            .unwrap_or_else(|| {
+                RsaPrivateKey::new(&mut rand::thread_rng(), GENERATED_KEY_BITS)
+                    .expect("RSA key can be generated")
+            });
+        TokenSigner {
+            issuer: get(CONFIG_ISSUER).unwrap_or_else(|| {
+                format!(
+                    "http://localhost:{}",
+                    crate::opts::OPT
+                        .get()
+                        .and_then(|o| o.port)
+                        .unwrap_or(crate::opts::DEFAULT_PORT)
+                )
+            }),
+            key_id: get(CONFIG_KEY_ID).unwrap_or_else(|| DEFAULT_KEY_ID.to_string()),
+            private_key,
+        }
+    }
+
+    /// Returns a token of the claims signed with RSA SHA-256.
+    pub fn sign(&self, claims: &serde_json::Value) -> String {
+        let header = serde_json::json!({
+            "alg": SIGNING_ALGORITHM,
+            "kid": self.key_id,
+        });
+        let message = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+        let signature = self
+            .private_key
+            .sign(
+                Pkcs1v15Sign::new::<Sha256>(),
+                &Sha256::digest(message.as_bytes()),
+            )
+            .expect("RSA key can sign a digest");
+        format!("{}.{}", message, URL_SAFE_NO_PAD.encode(signature))
+    }
+
+    /// Returns the claims of a token if it is signed with the key.
+    pub fn verify(&self, token: &str) -> Option<serde_json::Map<String, serde_json::Value>> {
+        let (message, signature) = token.rsplit_once('.')?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+        RsaPublicKey::from(&self.private_key)
+            .verify(
+                Pkcs1v15Sign::new::<Sha256>(),
+                &Sha256::digest(message.as_bytes()),
+                &signature,
+            )
+            .ok()?;
+        token_claims(token)
+    }
+
+    /// Returns the OpenID Connect discovery document of an issuer.
+    pub fn discovery_document(&self, issuer: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id_token_signing_alg_values_supported": [SIGNING_ALGORITHM],
+            "issuer": issuer,
+            "jwks_uri": format!("{}/.well-known/{}", issuer, JWKS_PATH),
+            "response_types_supported": ["code", "token", "id_token"],
+            "subject_types_supported": ["public"],
+        })
+    }
+
+    /// Returns the JSON web key set which has the public key only.
+    pub fn jwks(&self) -> serde_json::Value {
+        let public_key = RsaPublicKey::from(&self.private_key);
+        serde_json::json!({
+            "keys": [{
+                "alg": SIGNING_ALGORITHM,
+                "e": URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be()),
+                "kid": self.key_id,
+                "kty": "RSA",
+                "n": URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be()),
+                "use": "sig",
+            }]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    static SIGNER: Lazy<TokenSigner> = Lazy::new(|| {
+        TokenSigner::from_config(&HashMap::from([(
+            CONFIG_ISSUER.to_string(),
+            "http://localhost:9229".to_string(),
+        )]))
+    });
+
+    #[test]
+    fn signer_can_read_from_config() {
+        assert_eq!("http://localhost:9229", SIGNER.issuer);
+        assert_eq!(DEFAULT_KEY_ID, SIGNER.key_id);
+    }
+
+    #[test]
+    fn signed_token_can_be_verified_with_public_key() {
+        let token = SIGNER.sign(&serde_json::json!({ "sub": "user" }));
+        assert_eq!("user", SIGNER.verify(&token).unwrap()["sub"]);
+        assert_eq!("user", token_claims(&token).unwrap()["sub"]);
+
+        let parts = token.split('.').collect::<Vec<_>>();
+        let tampered = format!(
+            "{}.{}.{}",
+            parts[0],
+            URL_SAFE_NO_PAD.encode(r#"{"sub":"admin"}"#),
+            parts[2]
+        );
+        assert_eq!(None, SIGNER.verify(&tampered));
+        assert_eq!(None, SIGNER.verify("token"));
+        assert_eq!(None, token_claims("token"));
+    }
+
+    #[test]
+    fn jwks_has_no_private_key() {
+        let jwks = SIGNER.jwks();
+        let key = jwks["keys"][0].as_object().unwrap();
+        assert_eq!("RSA", key["kty"]);
+        assert_eq!(SIGNING_ALGORITHM, key["alg"]);
+        assert_eq!("AQAB", key["e"]);
+        assert!(!key.contains_key("d"));
+        assert!(!key.contains_key("k"));
+    }
+}
@@ -1,8 +1,17 @@
 pub mod common;
+pub mod configs;
+pub mod errors;
 pub mod hooks;
 pub mod http;
+pub mod identity_pools;
+pub mod jwt;
+pub mod mock_idp;
 pub mod opts;
+pub mod pagination;
+pub mod responses;
 pub mod routes;
+pub mod services;
+pub mod signatures;
 pub mod templates;
 pub mod user_pools;
 pub mod validator;
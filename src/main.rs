@@ -1,4 +1,5 @@
 use fakey_cognito::*;
+use warp::Filter;
 
 const DEFAULT_LOG_LEVEL: &str = "info";
 const LOG_LEVEL_KEY: &str = "RUST_LOG";
@@ -18,13 +19,19 @@ async fn main() {
     setup_logger();
     let templates_opt = opts::get_opt_templates();
     tokio::join!(
-        user_pools::init_config(opts::get_opt_config()),
+        configs::init_config(opts::get_opt_config()),
         templates::init_template(templates_opt.map(String::as_str)),
         templates::init_default_template()
     );
 
-    let port = opts::get_opt_port().unwrap_or(8080);
-    warp::serve(routes::user_pools_routes())
-        .run(([0, 0, 0, 0], port))
-        .await;
+    let port = opts::get_opt_port().unwrap_or(opts::DEFAULT_PORT);
+    warp::serve(
+        routes::user_pools_routes()
+            .or(routes::well_known_routes())
+            .or(routes::user_import_routes())
+            .or(routes::hosted_ui_routes())
+            .or(routes::mock_idp_routes()),
+    )
+    .run(([0, 0, 0, 0], port))
+    .await;
 }
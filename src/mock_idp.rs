@@ -0,0 +1,191 @@
+use crate::{common, jwt};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Path prefix of the built-in OpenID Connect identity provider.
+pub const MOCK_IDP_PATH: &str = "mock-idp";
+
+/// Path of the authorization endpoint of the mock identity provider.
+pub const AUTHORIZE_PATH: &str = "authorize";
+
+/// Path of the token endpoint of the mock identity provider.
+pub const TOKEN_PATH: &str = "token";
+
+const ACCESS_TOKEN_LENGTH: usize = 32;
+const TOKEN_EXPIRES_IN: i64 = 3600;
+
+static CODES: Lazy<RwLock<HashMap<String, IssuedCode>>> = Lazy::new(Default::default);
+
+/// Authorization code issued to a client for the claims of a signed-in test user.
+#[derive(Debug, Clone)]
+struct IssuedCode {
+    client_id: String,
+    redirect_uri: String,
+    claims: serde_json::Value,
+}
+
+/// Query parameters of the authorization endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct AuthorizeQuery {
+    pub client_id: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+}
+
+/// Test user posted by the sign-in page of the authorization endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct SignInForm {
+    pub sub: Option<String>,
+    pub email: Option<String>,
+    pub name: Option<String>,
+}
+
+/// Form parameters of the token endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct TokenForm {
+    pub grant_type: Option<String>,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub client_id: Option<String>,
+}
+
+/// Returns the issuer of the ID tokens of the mock identity provider.
+pub fn issuer() -> String {
+    format!("{}/{}", jwt::token_signer().issuer, MOCK_IDP_PATH)
+}
+
+/// Returns the sign-in page which asks the claims of a test user.
+pub fn sign_in_page() -> String {
+    r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Mock identity provider</title></head>
+<body>
+<form method="post">
+<label for="sub">sub</label> <input id="sub" name="sub" type="text"><br>
+<label for="email">email</label> <input id="email" name="email" type="text"><br>
+<label for="name">name</label> <input id="name" name="name" type="text"><br>
+<input type="submit" value="Sign in">
+</form>
+</body>
+</html>
+"#
+    .to_string()
+}
+
+/// Issues a code for the test user and returns `redirect_uri` with the code and the state.
+pub fn sign_in(query: &AuthorizeQuery, form: &SignInForm) -> Result<String, String> {
+    let redirect_uri = query
+        .redirect_uri
+        .as_deref()
+        .filter(|uri| !uri.is_empty())
+        .ok_or_else(|| "redirect_uri is required.".to_string())?;
+    let mut claims = serde_json::json!({
+        "sub": form.sub.clone().filter(|s| !s.is_empty()).unwrap_or_else(common::new_uuid),
+    });
+    if let Some(email) = form.email.as_deref().filter(|e| !e.is_empty()) {
+        claims["email"] = email.into();
+        claims["email_verified"] = true.into();
+    }
+    if let Some(name) = form.name.as_deref().filter(|n| !n.is_empty()) {
+        claims["name"] = name.into();
+    }
+    if let Some(nonce) = query.nonce.as_deref() {
+        claims["nonce"] = nonce.into();
+    }
+    let code = common::new_uuid();
+    CODES.write().unwrap().insert(
+        code.clone(),
+        IssuedCode {
+            client_id: query.client_id.clone().unwrap_or_default(),
+            redirect_uri: redirect_uri.to_string(),
+            claims,
+        },
+    );
+    let mut location = format!(
+        "{}{}code={}",
+        redirect_uri,
+        if redirect_uri.contains('?') { "&" } else { "?" },
+        code
+    );
+    if let Some(state) = query.state.as_deref() {
+        location.push_str(&format!("&state={}", common::uri_encode(state)));
+    }
+    Ok(location)
+}
+
+/// Exchanges a code for the tokens, or returns an OAuth 2.0 error response.
+pub fn token(form: &TokenForm) -> Result<serde_json::Value, serde_json::Value> {
+    let error = |error: &str| serde_json::json!({ "error": error });
+    if form.grant_type.as_deref() != Some("authorization_code") {
+        return Err(error("unsupported_grant_type"));
+    }
+    let issued = CODES
+        .write()
+        .unwrap()
+        .remove(form.code.as_deref().unwrap_or_default())
+        .ok_or_else(|| error("invalid_grant"))?;
+    if form.redirect_uri.as_deref() != Some(issued.redirect_uri.as_str())
+        || form.client_id.as_deref() != Some(issued.client_id.as_str())
+    {
+        return Err(error("invalid_grant"));
+    }
+    let now = chrono::Utc::now().timestamp();
+    let mut claims = issued.claims;
+    claims["aud"] = issued.client_id.into();
+    claims["exp"] = (now + TOKEN_EXPIRES_IN).into();
+    claims["iat"] = now.into();
+    claims["iss"] = issuer().into();
+    Ok(serde_json::json!({
+        "access_token": common::random_string(common::ALPHANUMERIC, ACCESS_TOKEN_LENGTH),
+        "expires_in": TOKEN_EXPIRES_IN,
+        "id_token": jwt::token_signer().sign(&claims),
+        "token_type": "Bearer",
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn code_is_exchanged_once_for_id_token() {
+        let query = AuthorizeQuery {
+            client_id: Some("client".to_string()),
+            redirect_uri: Some("http://localhost/callback".to_string()),
+            state: Some("xyz".to_string()),
+            nonce: None,
+        };
+        let form = SignInForm {
+            sub: Some("1234".to_string()),
+            email: Some("user@example.com".to_string()),
+            name: None,
+        };
+        let location = sign_in(&query, &form).unwrap();
+        assert!(location.starts_with("http://localhost/callback?code="));
+        assert!(location.ends_with("&state=xyz"));
+        let code = location["http://localhost/callback?code=".len()..]
+            .split('&')
+            .next()
+            .unwrap();
+
+        let token_form = |client_id: &str| TokenForm {
+            grant_type: Some("authorization_code".to_string()),
+            code: Some(code.to_string()),
+            redirect_uri: Some("http://localhost/callback".to_string()),
+            client_id: Some(client_id.to_string()),
+        };
+        let tokens = token(&token_form("client")).unwrap();
+        let claims = jwt::token_claims(tokens["id_token"].as_str().unwrap()).unwrap();
+        assert_eq!("1234", claims["sub"]);
+        assert_eq!("user@example.com", claims["email"]);
+        assert_eq!("client", claims["aud"]);
+        assert_eq!(
+            serde_json::json!({ "error": "invalid_grant" }),
+            token(&token_form("client")).unwrap_err()
+        );
+    }
+}
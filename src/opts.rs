@@ -4,6 +4,9 @@ use tokio::sync::OnceCell;
 
 pub static OPT: OnceCell<Opt> = OnceCell::const_new();
 
+/// Port number when the option does not specify it.
+pub const DEFAULT_PORT: u16 = 8080;
+
 #[derive(StructOpt, Debug)]
 #[structopt(name = "fakey-cognito", about = "Fake cognito api server.")]
 pub struct Opt {
@@ -0,0 +1,329 @@
+use crate::errors::{CommonError, ResponseError};
+use crate::responses::{ToActionName, ToResponse};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use sha2::{Digest, Sha256};
+
+const CHECKSUM_LENGTH: usize = 16;
+
+/// Request of a list action returning a collection page by page.
+pub trait ToPage {
+    /// Returns the key of the collection in the response (e.g. `Users`).
+    fn items_key() -> &'static str;
+    /// Returns the key of the field which identifies an item of the collection (e.g. `Username`).
+    fn item_key() -> &'static str;
+    /// Returns the key of the pagination token in the request and the response.
+    fn token_key() -> &'static str;
+    /// Returns the default and maximum number of items in a page of the action.
+    fn max_page_size() -> u8;
+    fn page_limit(&self) -> Option<u8>;
+    fn page_token(&self) -> Option<&str>;
+}
+
+/// Items of a page and the token to get the next page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_token: Option<String>,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum PaginationError {
+    #[error("Invalid pagination token.")]
+    InvalidToken,
+}
+
+/// Returns a page of items following the item of the given token.
+///
+/// Tokens hold the key of the last returned item and are bound to `scope`, so a token issued
+/// for another collection is rejected. If that item was deleted, the page starts at the first
+/// later key, which keeps the place in collections listed in the order of their keys.
+/// Requests validate the range of the limit, so a larger limit is capped to `max_page_size`.
+pub fn paginate<T, F>(
+    scope: &str,
+    items: Vec<T>,
+    key: F,
+    limit: Option<u8>,
+    max_page_size: u8,
+    token: Option<&str>,
+) -> Result<Page<T>, PaginationError>
+where
+    F: Fn(&T) -> String,
+{
+    let limit = match limit {
+        Some(limit) if limit > 0 => usize::from(limit.min(max_page_size)),
+        _ => usize::from(max_page_size),
+    };
+    let start = match token {
+        Some(token) => {
+            let last_key = decode_token(scope, token)?;
+            items
+                .iter()
+                .position(|item| key(item) == last_key)
+                .map(|position| position + 1)
+                .or_else(|| items.iter().position(|item| key(item) > last_key))
+                .unwrap_or(items.len())
+        }
+        None => 0,
+    };
+    let end = start.saturating_add(limit).min(items.len());
+    let next_token =
+        (end < items.len() && end > 0).then(|| encode_token(scope, &key(&items[end - 1])));
+    let items = items.into_iter().skip(start).take(end - start).collect();
+    Ok(Page { items, next_token })
+}
+
+/// Replaces the collection in a rendered response with the requested page of it.
+pub fn to_page<R>(
+    request: &R,
+    mut value: serde_json::Value,
+) -> Result<serde_json::Value, (ResponseError<R::E>, String)>
+where
+    R: ToActionName + ToResponse + ToPage,
+{
+    let items = match value.get(R::items_key()).and_then(|i| i.as_array()) {
+        Some(items) => items.clone(),
+        None => return Ok(value),
+    };
+    let page = paginate(
+        R::to_action_name(),
+        items,
+        |item| match &item[R::item_key()] {
+            serde_json::Value::String(key) => key.clone(),
+            _ => item.to_string(),
+        },
+        request.page_limit(),
+        R::max_page_size(),
+        request.page_token(),
+    )
+    .map_err(|e| (invalid_parameter_error::<R>(), e.to_string()))?;
+    value[R::items_key()] = serde_json::Value::Array(page.items);
+    match page.next_token {
+        Some(token) => value[R::token_key()] = serde_json::Value::String(token),
+        None => {
+            if let Some(object) = value.as_object_mut() {
+                object.remove(R::token_key());
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// Returns `InvalidParameterException` of the action, or `InvalidParameterValue` if it has not.
+fn invalid_parameter_error<R: ToResponse>() -> ResponseError<R::E> {
+    use std::str::FromStr;
+    R::E::from_str("InvalidParameterException")
+        .map(ResponseError::ActionError)
+        .unwrap_or(ResponseError::CommonError(
+            CommonError::InvalidParameterValue,
+        ))
+}
+
+fn checksum(scope: &str, key: &str) -> String {
+    let digest = Sha256::digest(format!("{}:{}", scope, key));
+    digest
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>()[..CHECKSUM_LENGTH]
+        .to_string()
+}
+
+fn encode_token(scope: &str, key: &str) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}:{}", checksum(scope, key), key))
+}
+
+fn decode_token(scope: &str, token: &str) -> Result<String, PaginationError> {
+    let decoded = URL_SAFE_NO_PAD
+        .decode(token)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or(PaginationError::InvalidToken)?;
+    let (sum, key) = decoded
+        .split_once(':')
+        .ok_or(PaginationError::InvalidToken)?;
+    if sum != checksum(scope, key) {
+        return Err(PaginationError::InvalidToken);
+    }
+    Ok(key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::{Response, ToStatusCode};
+    use pretty_assertions::assert_eq;
+    use strum_macros::{Display, EnumString};
+
+    const MAX_PAGE_SIZE: u8 = 60;
+
+    #[derive(Display, EnumString)]
+    enum TestError {}
+    impl ToStatusCode for TestError {
+        fn to_status_code(&self) -> hyper::StatusCode {
+            crate::http::status_code(400)
+        }
+    }
+
+    #[derive(Default)]
+    struct TestRequest {
+        next_token: Option<String>,
+    }
+    impl ToActionName for TestRequest {
+        fn to_action_name() -> &'static str {
+            "ListTests"
+        }
+    }
+    impl ToResponse for TestRequest {
+        type E = TestError;
+        fn to_response(&self) -> Response {
+            let value = serde_json::json!({ "Tests": ["a", "b"] });
+            match to_page(self, value) {
+                Ok(value) => Response::new(crate::responses::json_body(&value.to_string())),
+                Err((error, message)) => crate::responses::error_response(error, Some(&message)),
+            }
+        }
+    }
+    impl ToPage for TestRequest {
+        fn items_key() -> &'static str {
+            "Tests"
+        }
+        fn item_key() -> &'static str {
+            "Name"
+        }
+        fn token_key() -> &'static str {
+            "NextToken"
+        }
+        fn max_page_size() -> u8 {
+            MAX_PAGE_SIZE
+        }
+        fn page_limit(&self) -> Option<u8> {
+            Some(1)
+        }
+        fn page_token(&self) -> Option<&str> {
+            self.next_token.as_deref()
+        }
+    }
+
+    fn page(items: &[u8], limit: Option<u8>, token: Option<&str>) -> Page<u8> {
+        paginate(
+            "scope",
+            items.to_vec(),
+            |item| format!("{:03}", item),
+            limit,
+            MAX_PAGE_SIZE,
+            token,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn paginate_returns_all_pages() {
+        let first = page(&[1, 2, 3], Some(2), None);
+        assert_eq!(vec![1, 2], first.items);
+        assert!(first.next_token.is_some());
+
+        let second = page(&[1, 2, 3], Some(2), first.next_token.as_deref());
+        assert_eq!(vec![3], second.items);
+        assert_eq!(None, second.next_token);
+    }
+
+    #[test]
+    fn paginate_continues_after_last_key_when_items_change() {
+        let first = page(&[1, 2, 3, 4], Some(2), None);
+        assert_eq!(vec![1, 2], first.items);
+
+        let inserted = page(&[0, 1, 2, 3, 4], Some(2), first.next_token.as_deref());
+        assert_eq!(vec![3, 4], inserted.items);
+
+        let deleted = page(&[3, 4], Some(2), first.next_token.as_deref());
+        assert_eq!(vec![3, 4], deleted.items);
+
+        let last_deleted = page(&[1, 3, 4], Some(2), first.next_token.as_deref());
+        assert_eq!(vec![3, 4], last_deleted.items);
+    }
+
+    #[test]
+    fn paginate_uses_max_page_size_by_default() {
+        let items: Vec<u8> = (0..=MAX_PAGE_SIZE).collect();
+        let first = page(&items, Some(0), None);
+        assert_eq!(usize::from(MAX_PAGE_SIZE), first.items.len());
+
+        let second = page(&items, None, first.next_token.as_deref());
+        assert_eq!(vec![MAX_PAGE_SIZE], second.items);
+    }
+
+    #[test]
+    fn paginate_rejects_tampered_token() {
+        assert_eq!(
+            Err(PaginationError::InvalidToken),
+            paginate(
+                "scope",
+                vec![1, 2, 3],
+                |i| i.to_string(),
+                Some(2),
+                60,
+                Some("token")
+            )
+        );
+
+        let token = URL_SAFE_NO_PAD.encode(format!("{}:1", checksum("scope", "2")));
+        assert_eq!(
+            Err(PaginationError::InvalidToken),
+            paginate(
+                "scope",
+                vec![1, 2, 3],
+                |i| i.to_string(),
+                Some(2),
+                60,
+                Some(&token)
+            )
+        );
+
+        let first = page(&[1, 2, 3], Some(2), None);
+        assert_eq!(
+            Err(PaginationError::InvalidToken),
+            paginate(
+                "other",
+                vec![1, 2, 3],
+                |i| i.to_string(),
+                Some(2),
+                60,
+                first.next_token.as_deref()
+            )
+        );
+    }
+
+    #[test]
+    fn paginate_caps_too_large_limit() {
+        let items: Vec<u8> = (0..=50).collect();
+        let page = paginate("scope", items, |i| format!("{:03}", i), Some(51), 50, None).unwrap();
+        assert_eq!(50, page.items.len());
+        assert!(page.next_token.is_some());
+    }
+
+    #[test]
+    fn to_page_returns_invalid_parameter_value_without_action_error() {
+        let res = TestRequest::default().to_response();
+        assert_eq!(200, res.status());
+
+        let value = serde_json::json!({ "Tests": [{ "Name": "a" }, { "Name": "b" }] });
+        let page = to_page(&TestRequest::default(), value.clone())
+            .ok()
+            .unwrap();
+        assert_eq!(serde_json::json!([{ "Name": "a" }]), page["Tests"]);
+
+        let request = TestRequest {
+            next_token: page["NextToken"].as_str().map(str::to_string),
+        };
+        let next = to_page(&request, value.clone()).ok().unwrap();
+        assert_eq!(serde_json::json!([{ "Name": "b" }]), next["Tests"]);
+        assert!(next.get("NextToken").is_none());
+
+        let request = TestRequest {
+            next_token: Some("token".to_string()),
+        };
+        let res = request.to_response();
+        assert_eq!(400, res.status());
+        let (error, _) = to_page(&request, value).err().unwrap();
+        assert_eq!("InvalidParameterValue", error.to_string());
+    }
+}
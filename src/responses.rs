@@ -0,0 +1,250 @@
+use crate::errors::{CommonError, ResponseError};
+use crate::http;
+use crate::pagination::{to_page, ToPage};
+use crate::services::Service;
+use crate::templates;
+use bytes::Bytes;
+use hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use validator::Validate;
+
+const AWS_CONTENT_TYPE_HEADER_VALUE: &str = "application/x-amz-json-1.1";
+const AWS_ERROR_TYPE_HEADER: &str = "x-amzn-ErrorType";
+const AWS_ERROR_MESSAGE_HEADER: &str = "x-amzn-ErrorMessage";
+
+pub type Response = warp::http::Response<warp::hyper::Body>;
+pub type ResponseResult = std::result::Result<Response, Infallible>;
+
+pub trait ToActionName {
+    fn to_action_name() -> &'static str;
+
+    /// Returns the service of the action.
+    fn to_service() -> Service {
+        Service::UserPools
+    }
+}
+
+pub trait ToResponse {
+    type E: std::str::FromStr + std::fmt::Display + ToStatusCode;
+    fn to_response(&self) -> Response;
+
+    /// Returns an action error and its message when the request breaks action specific rules.
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        None
+    }
+}
+
+pub trait ToStatusCode {
+    fn to_status_code(&self) -> StatusCode;
+}
+
+/// Returns empty body.
+pub fn empty_body() -> warp::hyper::Body {
+    warp::hyper::Body::empty()
+}
+
+/// Returns body with json serialized value.
+pub fn json_body(value: &str) -> warp::hyper::Body {
+    warp::hyper::Body::from(value.to_string())
+}
+
+pub fn response<'a, T>(body: &'a Bytes) -> ResponseResult
+where
+    T: Serialize + for<'de> Deserialize<'de> + ToResponse,
+{
+    match serde_json::from_slice::<T>(body) {
+        Ok(req) => Ok(req.to_response()),
+        Err(_) => Ok(error_response(
+            CommonError::InternalFailure,
+            Some("Cannot deserialize json body."),
+        )),
+    }
+}
+
+/// Generates error response for aws.
+pub fn error_response<T>(error: T, message: Option<&str>) -> Response
+where
+    T: std::fmt::Display + ToStatusCode,
+{
+    warp::http::Response::builder()
+        .status(warp::http::StatusCode::from_u16(error.to_status_code().as_u16()).unwrap())
+        .header(AWS_ERROR_TYPE_HEADER, format!("{}", error))
+        .header(AWS_ERROR_MESSAGE_HEADER, "DUMMY ERROR MESSAGE")
+        .header("Content-Type", AWS_CONTENT_TYPE_HEADER_VALUE)
+        .body(json_body(&format!(
+            "{{\"__type\": \"{}\",\"message\":\"{}\"}}",
+            error,
+            message.unwrap_or("DUMMY ERROR MESSAGE")
+        )))
+        .unwrap()
+}
+
+pub fn config_response<R>() -> Option<Response>
+where
+    R: ToActionName + ToResponse,
+{
+    use std::str::FromStr;
+    let error_type = R::to_service()
+        .config()
+        .get(R::to_action_name())
+        .and_then(|c| c.get(crate::configs::CONFIG_ERROR_TYPE));
+    match error_type {
+        Some(name) => ResponseError::<R::E>::from_str(name.as_str()).map_or(None, |e| {
+            Some(error_response(e, Some("force response from config.")))
+        }),
+        _ => None,
+    }
+}
+
+pub fn to_json_response<R>(request: &R, template_name: &str) -> Response
+where
+    R: ToActionName + ToResponse + serde::Serialize + Validate,
+{
+    to_rendered_response(request, template_name, |json| json_response(&json))
+}
+
+/// Returns json response after converting a value rendered from the template.
+pub fn to_converted_json_response<R, F>(request: &R, template_name: &str, convert: F) -> Response
+where
+    R: ToActionName + ToResponse + serde::Serialize + Validate,
+    F: FnOnce(serde_json::Value) -> Result<serde_json::Value, (ResponseError<R::E>, String)>,
+{
+    to_rendered_response(request, template_name, |json| {
+        let value = match serde_json::from_str::<serde_json::Value>(&json) {
+            Ok(value) => value,
+            Err(_) => {
+                return error_response(
+                    CommonError::InternalFailure,
+                    Some("Rendered response template is not valid json."),
+                )
+            }
+        };
+        match convert(value) {
+            Ok(value) => json_response(&value.to_string()),
+            Err((error, message)) => error_response(error, Some(&message)),
+        }
+    })
+}
+
+/// Returns json response with the requested page of the collection rendered from the template.
+pub fn to_paginated_json_response<R>(request: &R, template_name: &str) -> Response
+where
+    R: ToActionName + ToResponse + ToPage + serde::Serialize + Validate,
+{
+    to_converted_json_response(request, template_name, |value| to_page(request, value))
+}
+
+fn to_rendered_response<R, F>(request: &R, template_name: &str, to_response: F) -> Response
+where
+    R: ToActionName + ToResponse + serde::Serialize + Validate,
+    F: FnOnce(String) -> Response,
+{
+    if let Some(response) = config_response::<R>() {
+        return response;
+    };
+    if request.validate().is_err() {
+        let error = ResponseError::<R::E>::CommonError(CommonError::InvalidParameterValue);
+        return error_response(error, Some("Parameters validation error."));
+    }
+    if let Some((error, message)) = request.action_error() {
+        return error_response(error, Some(&message));
+    }
+
+    let hook_result = crate::hooks::call_request_hook(
+        template_name,
+        &request,
+        crate::opts::get_opt_hooks().map(|o| o.as_ref()),
+    );
+    let opt_json = templates::render_template(
+        R::to_service().template_dir(),
+        template_name,
+        &request,
+        hook_result.unwrap_or_else(|e| {
+            log::warn!("hook script error: {}", e);
+            "{}".to_string()
+        }),
+    );
+    match opt_json {
+        Some(json) => to_response(json),
+        _ => error_response(
+            CommonError::InternalFailure,
+            Some("Error during rendering response template."),
+        ),
+    }
+}
+
+fn json_response(json: &str) -> Response {
+    warp::http::Response::builder()
+        .status(warp::http::StatusCode::from_u16(http::status_code(200).as_u16()).unwrap())
+        .body(json_body(json))
+        .unwrap()
+}
+
+pub fn to_empty_response<R>(request: &R) -> Response
+where
+    R: ToActionName + ToResponse + serde::Serialize + Validate,
+{
+    to_applied_empty_response(request, || Ok(()))
+}
+
+/// Returns empty response after applying a valid request.
+pub fn to_applied_empty_response<R, F>(request: &R, apply: F) -> Response
+where
+    R: ToActionName + ToResponse + serde::Serialize + Validate,
+    F: FnOnce() -> Result<(), (ResponseError<R::E>, String)>,
+{
+    if let Some(response) = config_response::<R>() {
+        return response;
+    };
+    if request.validate().is_err() {
+        let error = ResponseError::<R::E>::CommonError(CommonError::InvalidParameterValue);
+        return error_response(error, Some("Parameters validation error."));
+    }
+    if let Some((error, message)) = request.action_error() {
+        return error_response(error, Some(&message));
+    }
+    if let Err((error, message)) = apply() {
+        return error_response(error, Some(&message));
+    }
+
+    warp::http::Response::builder()
+        .status(warp::http::StatusCode::from_u16(http::status_code(200).as_u16()).unwrap())
+        .body(empty_body())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http;
+    use pretty_assertions::assert_eq;
+    use strum_macros::{Display, EnumString};
+
+    #[derive(Display, EnumString)]
+    enum TestError {}
+    impl ToStatusCode for TestError {
+        fn to_status_code(&self) -> StatusCode {
+            http::status_code(400)
+        }
+    }
+
+    #[test]
+    fn error_response_has_status_code() {
+        let response = error_response(CommonError::AccessDeniedException, None);
+        assert_eq!(400, response.status().as_u16());
+    }
+
+    #[test]
+    fn error_response_has_error_header() {
+        let response = error_response::<ResponseError<TestError>>(
+            ResponseError::CommonError(CommonError::AccessDeniedException),
+            None,
+        );
+        assert!(response.headers().contains_key(AWS_ERROR_TYPE_HEADER));
+        assert_eq!(
+            "AccessDeniedException",
+            response.headers()[AWS_ERROR_TYPE_HEADER]
+        );
+    }
+}
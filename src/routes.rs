@@ -1,12 +1,22 @@
 use crate::common;
+use crate::errors::CommonError;
+use crate::identity_pools;
+use crate::jwt;
+use crate::mock_idp;
+use crate::responses::{self, ResponseResult};
+use crate::services::Service;
+use crate::signatures;
 use crate::user_pools;
-use crate::user_pools::UserPoolsResponseResult;
 use bytes::Bytes;
 use std::collections::HashMap;
+use warp::http::{HeaderMap, StatusCode};
 use warp::Filter;
 
 const AWS_ACTION_TARGET_HEADER: &str = "x-amz-target";
 const AWS_ACTION_TAREGET_KEY: &str = "Action";
+const WELL_KNOWN_PATH: &str = ".well-known";
+const HOST_HEADER: &str = "host";
+const AUTHORIZATION_HEADER: &str = "authorization";
 
 #[derive(thiserror::Error, Debug, Clone)]
 struct MissingActionError;
@@ -16,12 +26,12 @@ impl std::fmt::Display for MissingActionError {
     }
 }
 
-/// Returns action.
+/// Returns action and its service by the action target prefix.
 fn take_action(
     action_header: Option<String>,
     body: &Bytes,
     queries: HashMap<String, String>,
-) -> anyhow::Result<String> {
+) -> anyhow::Result<(Service, String)> {
     let json: serde_json::Value = serde_json::from_slice(body)?;
     let body_action = match json {
         serde_json::Value::Object(map) => {
@@ -47,24 +57,23 @@ fn take_action(
     } else {
         queries[AWS_ACTION_TAREGET_KEY].clone()
     };
-
-    Ok(action)
+    Ok((Service::from_target(&action), action))
 }
 
 // creating match expression helper macro for post action routing
 macro_rules! post_action_routes_match {
     ($action:expr,$body:expr,$($k:pat => $v:ty),* $(,)?) => {{
         match $action {
-            $($k => { user_pools::response::<$v>($body) })*
-            _ => Ok(user_pools::error_response(
-                user_pools::CommonError::InvalidAction,
+            $($k => { responses::response::<$v>($body) })*
+            _ => Ok(responses::error_response(
+                CommonError::InvalidAction,
                 Some(&format!("Unknown action name '{}'.", $action)),
             ))
         }
     }}
 }
 
-fn post_action_routes(action: &str, body: &Bytes) -> UserPoolsResponseResult {
+fn user_pools_action_routes(action: &str, body: &Bytes) -> ResponseResult {
     post_action_routes_match!(action, body,
         user_pools::ADD_CUSTOM_ATTRIBUTES_ACTION_NAME => user_pools::AddCustomAttributesRequest,
         user_pools::ADMIN_ADD_USER_TO_GROUP_ACTION_NAME => user_pools::AdminAddUserToGroupRequest,
@@ -104,6 +113,64 @@ fn post_action_routes(action: &str, body: &Bytes) -> UserPoolsResponseResult {
         user_pools::CREATE_USER_IMPORT_JOB_ACTION_NAME => user_pools::CreateUserImportJobRequest,
         user_pools::CREATE_USER_POOL_ACTION_NAME => user_pools::CreateUserPoolRequest,
         user_pools::CREATE_USER_POOL_CLIENT_ACTION_NAME => user_pools::CreateUserPoolClientRequest,
+        user_pools::CREATE_USER_POOL_DOMAIN_ACTION_NAME => user_pools::CreateUserPoolDomainRequest,
+        user_pools::DELETE_IDENTITY_PROVIDER_ACTION_NAME => user_pools::DeleteIdentityProviderRequest,
+        user_pools::DELETE_RESOURCE_SERVER_ACTION_NAME => user_pools::DeleteResourceServerRequest,
+        user_pools::DELETE_USER_ACTION_NAME => user_pools::DeleteUserRequest,
+        user_pools::DELETE_USER_ATTRIBUTES_ACTION_NAME => user_pools::DeleteUserAttributesRequest,
+        user_pools::DELETE_USER_POOL_DOMAIN_ACTION_NAME => user_pools::DeleteUserPoolDomainRequest,
+        user_pools::DESCRIBE_IDENTITY_PROVIDER_ACTION_NAME => user_pools::DescribeIdentityProviderRequest,
+        user_pools::DESCRIBE_RESOURCE_SERVER_ACTION_NAME => user_pools::DescribeResourceServerRequest,
+        user_pools::DESCRIBE_RISK_CONFIGURATION_ACTION_NAME => user_pools::DescribeRiskConfigurationRequest,
+        user_pools::DESCRIBE_USER_IMPORT_JOB_ACTION_NAME => user_pools::DescribeUserImportJobRequest,
+        user_pools::DESCRIBE_USER_POOL_ACTION_NAME => user_pools::DescribeUserPoolRequest,
+        user_pools::DESCRIBE_USER_POOL_DOMAIN_ACTION_NAME => user_pools::DescribeUserPoolDomainRequest,
+        user_pools::FORGET_DEVICE_ACTION_NAME => user_pools::ForgetDeviceRequest,
+        user_pools::GET_CSV_HEADER_ACTION_NAME => user_pools::GetCSVHeaderRequest,
+        user_pools::GET_DEVICE_ACTION_NAME => user_pools::GetDeviceRequest,
+        user_pools::GET_IDENTITY_PROVIDER_BY_IDENTIFIER_ACTION_NAME => user_pools::GetIdentityProviderByIdentifierRequest,
+        user_pools::GET_UI_CUSTOMIZATION_ACTION_NAME => user_pools::GetUICustomizationRequest,
+        user_pools::GET_USER_ACTION_NAME => user_pools::GetUserRequest,
+        user_pools::GET_USER_ATTRIBUTE_VERIFICATION_CODE_ACTION_NAME => user_pools::GetUserAttributeVerificationCodeRequest,
+        user_pools::GLOBAL_SIGN_OUT_ACTION_NAME => user_pools::GlobalSignOutRequest,
+        user_pools::INITIATE_AUTH_ACTION_NAME => user_pools::InitiateAuthRequest,
+        user_pools::LIST_DEVICES_ACTION_NAME => user_pools::ListDevicesRequest,
+        user_pools::LIST_IDENTITY_PROVIDERS_ACTION_NAME => user_pools::ListIdentityProvidersRequest,
+        user_pools::LIST_RESOURCE_SERVERS_ACTION_NAME => user_pools::ListResourceServersRequest,
+        user_pools::LIST_TAGS_FOR_RESOURCE_ACTION_NAME => user_pools::ListTagsForResourceRequest,
+        user_pools::LIST_USERS_ACTION_NAME => user_pools::ListUsersRequest,
+        user_pools::LIST_USER_IMPORT_JOBS_ACTION_NAME => user_pools::ListUserImportJobsRequest,
+        user_pools::RESPOND_TO_AUTH_CHALLENGE_ACTION_NAME => user_pools::RespondToAuthChallengeRequest,
+        user_pools::REVOKE_TOKEN_ACTION_NAME => user_pools::RevokeTokenRequest,
+        user_pools::SET_RISK_CONFIGURATION_ACTION_NAME => user_pools::SetRiskConfigurationRequest,
+        user_pools::SET_UI_CUSTOMIZATION_ACTION_NAME => user_pools::SetUICustomizationRequest,
+        user_pools::SIGN_UP_ACTION_NAME => user_pools::SignUpRequest,
+        user_pools::START_USER_IMPORT_JOB_ACTION_NAME => user_pools::StartUserImportJobRequest,
+        user_pools::STOP_USER_IMPORT_JOB_ACTION_NAME => user_pools::StopUserImportJobRequest,
+        user_pools::TAG_RESOURCE_ACTION_NAME => user_pools::TagResourceRequest,
+        user_pools::UNTAG_RESOURCE_ACTION_NAME => user_pools::UntagResourceRequest,
+        user_pools::UPDATE_DEVICE_STATUS_ACTION_NAME => user_pools::UpdateDeviceStatusRequest,
+        user_pools::UPDATE_IDENTITY_PROVIDER_ACTION_NAME => user_pools::UpdateIdentityProviderRequest,
+        user_pools::UPDATE_RESOURCE_SERVER_ACTION_NAME => user_pools::UpdateResourceServerRequest,
+        user_pools::UPDATE_USER_ATTRIBUTES_ACTION_NAME => user_pools::UpdateUserAttributesRequest,
+        user_pools::UPDATE_USER_POOL_DOMAIN_ACTION_NAME => user_pools::UpdateUserPoolDomainRequest,
+        user_pools::VERIFY_USER_ATTRIBUTE_ACTION_NAME => user_pools::VerifyUserAttributeRequest,
+    )
+}
+
+fn identity_pools_action_routes(action: &str, body: &Bytes) -> ResponseResult {
+    post_action_routes_match!(action, body,
+        identity_pools::CREATE_IDENTITY_POOL_ACTION_NAME => identity_pools::CreateIdentityPoolRequest,
+        identity_pools::DESCRIBE_IDENTITY_POOL_ACTION_NAME => identity_pools::DescribeIdentityPoolRequest,
+        identity_pools::GET_CREDENTIALS_FOR_IDENTITY_ACTION_NAME => identity_pools::GetCredentialsForIdentityRequest,
+        identity_pools::GET_IDENTITY_POOL_ROLES_ACTION_NAME => identity_pools::GetIdentityPoolRolesRequest,
+        identity_pools::GET_ID_ACTION_NAME => identity_pools::GetIdRequest,
+        identity_pools::GET_OPEN_ID_TOKEN_ACTION_NAME => identity_pools::GetOpenIdTokenRequest,
+        identity_pools::GET_OPEN_ID_TOKEN_FOR_DEVELOPER_IDENTITY_ACTION_NAME => identity_pools::GetOpenIdTokenForDeveloperIdentityRequest,
+        identity_pools::LOOKUP_DEVELOPER_IDENTITY_ACTION_NAME => identity_pools::LookupDeveloperIdentityRequest,
+        identity_pools::MERGE_DEVELOPER_IDENTITIES_ACTION_NAME => identity_pools::MergeDeveloperIdentitiesRequest,
+        identity_pools::SET_IDENTITY_POOL_ROLES_ACTION_NAME => identity_pools::SetIdentityPoolRolesRequest,
+        identity_pools::UNLINK_DEVELOPER_IDENTITY_ACTION_NAME => identity_pools::UnlinkDeveloperIdentityRequest,
     )
 }
 
@@ -112,25 +179,38 @@ fn post_routes(
     action_header: Option<String>,
     body: &Bytes,
     queries: HashMap<String, String>,
-) -> user_pools::UserPoolsResponseResult {
-    let target = match take_action(action_header, body, queries) {
+    headers: &HeaderMap,
+) -> ResponseResult {
+    let (service, target) = match take_action(action_header, body, queries.clone()) {
         Ok(action) => action,
         Err(e) => {
             if e.downcast_ref::<MissingActionError>().is_some() {
-                return Ok(user_pools::error_response(
-                    user_pools::CommonError::MissingAction,
+                return Ok(responses::error_response(
+                    CommonError::MissingAction,
                     Some("No specified action."),
                 ));
             } else {
-                return Ok(user_pools::error_response(
-                    user_pools::CommonError::InternalFailure,
+                return Ok(responses::error_response(
+                    CommonError::InternalFailure,
                     Some(&format!("Error: {:?}", e)),
                 ));
             }
         }
     };
 
-    post_action_routes(target.as_ref(), body)
+    if !signatures::is_public_action(&target) {
+        if let Some(verifier) = signatures::SignatureVerifier::configured() {
+            if let Err((e, message)) = verifier.verify(headers, &queries, body, chrono::Utc::now())
+            {
+                return Ok(responses::error_response(e, Some(&message)));
+            }
+        }
+    }
+
+    match service {
+        Service::UserPools => user_pools_action_routes(target.as_ref(), body),
+        Service::IdentityPools => identity_pools_action_routes(target.as_ref(), body),
+    }
 }
 
 pub fn user_pools_routes(
@@ -141,10 +221,10 @@ pub fn user_pools_routes(
         .and(warp::header::optional::<String>(AWS_ACTION_TARGET_HEADER))
         .and(warp::query::<HashMap<String, String>>())
         .and(warp::header::headers_cloned())
-        .map(move |bytes: Bytes, target, queries, headers| {
+        .map(move |bytes: Bytes, target, queries, headers: HeaderMap| {
             log::debug!("incoming headers: {:?}", &headers);
             log::debug!("incoming body: {:?}", &bytes);
-            let result = post_routes(target, &bytes, queries).unwrap();
+            let result = post_routes(target, &bytes, queries, &headers).unwrap();
             log::debug!("response status: {:?}", &result.status());
             log::debug!("response headers: {:?}", &result.headers());
             log::debug!("response body: {:?}", &result.body());
@@ -152,3 +232,253 @@ pub fn user_pools_routes(
         })
         .with(warp::log("info"))
 }
+
+/// GET routes of the OpenID Connect discovery documents and keys for identity and user pool tokens.
+pub fn well_known_routes(
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let well_known = warp::get().and(warp::path(WELL_KNOWN_PATH));
+    let configuration = well_known
+        .and(warp::path(jwt::OPEN_ID_CONFIGURATION_PATH))
+        .and(warp::path::end())
+        .map(|| {
+            warp::reply::json(
+                &jwt::token_signer().discovery_document(&identity_pools::open_id_token_issuer()),
+            )
+        });
+    let jwks = well_known
+        .and(warp::path(jwt::JWKS_PATH))
+        .and(warp::path::end())
+        .map(|| warp::reply::json(&jwt::token_signer().jwks()));
+    let user_pool_well_known = warp::get()
+        .and(warp::path::param::<String>())
+        .and(warp::path(WELL_KNOWN_PATH));
+    let user_pool_configuration = user_pool_well_known
+        .and(warp::path(jwt::OPEN_ID_CONFIGURATION_PATH))
+        .and(warp::path::end())
+        .map(|user_pool_id: String| {
+            warp::reply::json(
+                &jwt::token_signer()
+                    .discovery_document(&user_pools::user_pool_issuer(&user_pool_id)),
+            )
+        });
+    let user_pool_jwks = user_pool_well_known
+        .and(warp::path(jwt::JWKS_PATH))
+        .and(warp::path::end())
+        .map(|_| warp::reply::json(&jwt::token_signer().jwks()));
+    configuration
+        .or(jwks)
+        .or(user_pool_configuration)
+        .or(user_pool_jwks)
+        .with(warp::log("info"))
+}
+
+/// Routes of the OAuth 2.0 endpoints and the login and logout pages of the user pool domains, found
+/// by the `Host` header, and of the logo images of the UI customizations.
+pub fn hosted_ui_routes(
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let user_pool_id = warp::header::<String>(HOST_HEADER).and_then(|host: String| async move {
+        user_pools::host_user_pool_id(&host).ok_or_else(warp::reject::not_found)
+    });
+    let user_info = user_pool_id
+        .and(warp::get().or(warp::post()).unify())
+        .and(warp::path(user_pools::OAUTH2_PATH))
+        .and(warp::path(user_pools::USER_INFO_PATH))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>(AUTHORIZATION_HEADER))
+        .map(|user_pool_id: String, authorization: Option<String>| {
+            match user_pools::user_info(&user_pool_id, authorization.as_deref()) {
+                Ok(claims) => warp::reply::with_status(warp::reply::json(&claims), StatusCode::OK),
+                Err((_, message)) => warp::reply::with_status(
+                    warp::reply::json(&serde_json::json!({
+                        "error": "invalid_token",
+                        "error_description": message,
+                    })),
+                    StatusCode::UNAUTHORIZED,
+                ),
+            }
+        });
+    let authorize = user_pool_id
+        .and(warp::get())
+        .and(warp::path(user_pools::OAUTH2_PATH))
+        .and(warp::path(user_pools::AUTHORIZE_PATH))
+        .and(warp::path::end())
+        .and(warp::header::<String>(HOST_HEADER))
+        .and(warp::query::<user_pools::LoginQuery>())
+        .and(warp::cookie::optional::<String>(user_pools::SESSION_COOKIE))
+        .map(
+            |user_pool_id: String,
+             host: String,
+             query: user_pools::LoginQuery,
+             session: Option<String>| {
+                user_pools::authorize(&user_pool_id, &host, &query, session.as_deref())
+            },
+        );
+    let idp_response = user_pool_id
+        .and(warp::get())
+        .and(warp::path(user_pools::OAUTH2_PATH))
+        .and(warp::path(user_pools::IDP_RESPONSE_PATH))
+        .and(warp::path::end())
+        .and(warp::query::<user_pools::IdpResponseQuery>())
+        .and_then(
+            |user_pool_id: String, query: user_pools::IdpResponseQuery| async move {
+                Ok::<_, warp::Rejection>(user_pools::idp_response(&user_pool_id, &query).await)
+            },
+        );
+    let login_page = user_pool_id
+        .and(warp::get())
+        .and(warp::path(user_pools::LOGIN_PATH))
+        .and(warp::path::end())
+        .and(warp::query::<user_pools::LoginQuery>())
+        .map(|user_pool_id: String, query: user_pools::LoginQuery| {
+            user_pools::login_page(&user_pool_id, &query)
+        });
+    let login = user_pool_id
+        .and(warp::post())
+        .and(warp::path(user_pools::LOGIN_PATH))
+        .and(warp::path::end())
+        .and(warp::query::<user_pools::LoginQuery>())
+        .and(warp::body::form::<user_pools::LoginForm>())
+        .map(
+            |user_pool_id: String, query: user_pools::LoginQuery, form: user_pools::LoginForm| {
+                user_pools::login(&user_pool_id, &query, &form)
+            },
+        );
+    let logout = user_pool_id
+        .and(warp::get())
+        .and(warp::path(user_pools::LOGOUT_PATH))
+        .and(warp::path::end())
+        .and(warp::query::<user_pools::LogoutQuery>())
+        .and(warp::cookie::optional::<String>(user_pools::SESSION_COOKIE))
+        .map(
+            |user_pool_id: String, query: user_pools::LogoutQuery, session: Option<String>| {
+                user_pools::logout(&user_pool_id, &query, session.as_deref())
+            },
+        );
+    let ui_image = warp::get()
+        .and(warp::path::param::<String>())
+        .and(warp::path(user_pools::UI_CUSTOMIZATION_PATH))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .map(|user_pool_id: String, client_id: String| {
+            match user_pools::ui_image(&user_pool_id, &client_id) {
+                Some((image, content_type)) => warp::http::Response::builder()
+                    .header("Content-Type", content_type)
+                    .body(warp::hyper::Body::from(image)),
+                None => warp::http::Response::builder()
+                    .status(StatusCode::NOT_FOUND)
+                    .body(warp::hyper::Body::empty()),
+            }
+            .unwrap()
+        });
+    user_info
+        .or(authorize)
+        .or(idp_response)
+        .or(login_page)
+        .or(login)
+        .or(logout)
+        .or(ui_image)
+        .with(warp::log("info"))
+}
+
+/// Routes of the built-in OpenID Connect identity provider, which signs in any test user.
+pub fn mock_idp_routes(
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let sign_in_page = warp::get()
+        .and(warp::path(mock_idp::MOCK_IDP_PATH))
+        .and(warp::path(mock_idp::AUTHORIZE_PATH))
+        .and(warp::path::end())
+        .map(|| warp::reply::html(mock_idp::sign_in_page()));
+    let sign_in = warp::post()
+        .and(warp::path(mock_idp::MOCK_IDP_PATH))
+        .and(warp::path(mock_idp::AUTHORIZE_PATH))
+        .and(warp::path::end())
+        .and(warp::query::<mock_idp::AuthorizeQuery>())
+        .and(warp::body::form::<mock_idp::SignInForm>())
+        .map(
+            |query: mock_idp::AuthorizeQuery, form: mock_idp::SignInForm| {
+                match mock_idp::sign_in(&query, &form) {
+                    Ok(location) => warp::http::Response::builder()
+                        .status(StatusCode::FOUND)
+                        .header("Location", location)
+                        .body(warp::hyper::Body::empty()),
+                    Err(message) => warp::http::Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(warp::hyper::Body::from(message)),
+                }
+                .unwrap()
+            },
+        );
+    let token = warp::post()
+        .and(warp::path(mock_idp::MOCK_IDP_PATH))
+        .and(warp::path(mock_idp::TOKEN_PATH))
+        .and(warp::path::end())
+        .and(warp::body::form::<mock_idp::TokenForm>())
+        .map(|form: mock_idp::TokenForm| match mock_idp::token(&form) {
+            Ok(tokens) => warp::reply::with_status(warp::reply::json(&tokens), StatusCode::OK),
+            Err(error) => {
+                warp::reply::with_status(warp::reply::json(&error), StatusCode::BAD_REQUEST)
+            }
+        });
+    sign_in_page.or(sign_in).or(token).with(warp::log("info"))
+}
+
+/// PUT routes of the pre-signed URLs which take the CSV files of user import jobs.
+pub fn user_import_routes(
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::put()
+        .and(warp::path::param::<String>())
+        .and(warp::path(user_pools::IMPORT_JOB_PATH))
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::body::bytes())
+        .map(|user_pool_id: String, job_id: String, bytes: Bytes| {
+            let csv = String::from_utf8_lossy(&bytes).into_owned();
+            let status = match user_pools::with_user_pool(&user_pool_id, |user_pool| {
+                user_pool.upload_import_csv(&job_id, csv)
+            }) {
+                Ok(()) => StatusCode::OK,
+                Err((user_pools::StoreError::ResourceNotFoundException, _)) => {
+                    StatusCode::NOT_FOUND
+                }
+                Err(_) => StatusCode::FORBIDDEN,
+            };
+            warp::reply::with_status(warp::reply(), status)
+        })
+        .with(warp::log("info"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn take_action_by_target_prefix() {
+        let action = |target: &str| {
+            take_action(Some(target.to_string()), &Bytes::from("{}"), HashMap::new()).unwrap()
+        };
+        assert_eq!(
+            (
+                Service::UserPools,
+                "AWSCognitoIdentityProviderService.AdminGetUser".to_string()
+            ),
+            action("AWSCognitoIdentityProviderService.AdminGetUser")
+        );
+        assert_eq!(
+            (
+                Service::IdentityPools,
+                "AWSCognitoIdentityService.GetId".to_string()
+            ),
+            action("AWSCognitoIdentityService.GetId")
+        );
+    }
+
+    #[test]
+    fn failure_to_take_action() {
+        let result = take_action(None, &Bytes::from("{}"), HashMap::new());
+        assert!(result
+            .unwrap_err()
+            .downcast_ref::<MissingActionError>()
+            .is_some());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::configs::{self, Config};
+use crate::templates;
+
+const USER_POOLS_TARGET_PREFIX: &str = "AWSCognitoIdentityProviderService.";
+const IDENTITY_POOLS_TARGET_PREFIX: &str = "AWSCognitoIdentityService.";
+
+/// Cognito service which handles actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Service {
+    UserPools,
+    IdentityPools,
+}
+
+impl Service {
+    /// Returns the service of an action target by its prefix.
+    pub fn from_target(target: &str) -> Self {
+        if target.starts_with(IDENTITY_POOLS_TARGET_PREFIX) {
+            Service::IdentityPools
+        } else {
+            Service::UserPools
+        }
+    }
+
+    /// Returns the prefix of the action targets (e.g. `AWSCognitoIdentityService.`).
+    pub fn target_prefix(&self) -> &'static str {
+        match self {
+            Service::UserPools => USER_POOLS_TARGET_PREFIX,
+            Service::IdentityPools => IDENTITY_POOLS_TARGET_PREFIX,
+        }
+    }
+
+    /// Returns the templates directory of the actions.
+    pub fn template_dir(&self) -> &'static str {
+        match self {
+            Service::UserPools => templates::USER_POOLS_TEMPLATE_DIR,
+            Service::IdentityPools => templates::IDENTITY_POOLS_TEMPLATE_DIR,
+        }
+    }
+
+    /// Returns the config sections of the service.
+    pub fn config(&self) -> &'static Config {
+        match self {
+            Service::UserPools => configs::config(),
+            Service::IdentityPools => configs::identity_pools_config(),
+        }
+    }
+}
@@ -0,0 +1,443 @@
+use crate::common;
+use crate::configs;
+use crate::errors::CommonError;
+use crate::services::Service;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use warp::http::HeaderMap;
+
+/// Config section of the credentials which verify request signatures.
+pub const CONFIG_REQUEST_SIGNATURE: &str = "RequestSignature";
+
+/// Maximum difference in seconds between `x-amz-date` and the server time by default.
+pub const DEFAULT_MAX_SKEW_SECONDS: i64 = 900;
+
+const CONFIG_ACCESS_KEY_ID: &str = "access_key_id";
+const CONFIG_SECRET_ACCESS_KEY: &str = "secret_access_key";
+const CONFIG_MAX_SKEW_SECONDS: &str = "max_skew_seconds";
+
+const SIGNING_ALGORITHM: &str = "AWS4-HMAC-SHA256";
+const AUTHORIZATION_HEADER: &str = "authorization";
+const AMZ_DATE_HEADER: &str = "x-amz-date";
+const AMZ_DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const SCOPE_TERMINATOR: &str = "aws4_request";
+
+/// User pool actions which clients call without IAM credentials.
+const USER_POOLS_PUBLIC_ACTIONS: [&str; 25] = [
+    "AssociateSoftwareToken",
+    "ChangePassword",
+    "ConfirmDevice",
+    "ConfirmForgotPassword",
+    "ConfirmSignUp",
+    "DeleteUser",
+    "DeleteUserAttributes",
+    "ForgetDevice",
+    "ForgotPassword",
+    "GetDevice",
+    "GetUser",
+    "GetUserAttributeVerificationCode",
+    "GlobalSignOut",
+    "InitiateAuth",
+    "ListDevices",
+    "ResendConfirmationCode",
+    "RespondToAuthChallenge",
+    "RevokeToken",
+    "SetUserMFAPreference",
+    "SetUserSettings",
+    "SignUp",
+    "UpdateDeviceStatus",
+    "UpdateUserAttributes",
+    "VerifySoftwareToken",
+    "VerifyUserAttribute",
+];
+
+/// Identity pool actions which clients call without IAM credentials.
+const IDENTITY_POOLS_PUBLIC_ACTIONS: [&str; 4] = [
+    "GetCredentialsForIdentity",
+    "GetId",
+    "GetOpenIdToken",
+    "UnlinkIdentity",
+];
+
+/// Returns true if an action target does not need a request signature.
+pub fn is_public_action(target: &str) -> bool {
+    let service = Service::from_target(target);
+    let public_actions: &[&str] = match service {
+        Service::UserPools => &USER_POOLS_PUBLIC_ACTIONS,
+        Service::IdentityPools => &IDENTITY_POOLS_PUBLIC_ACTIONS,
+    };
+    matches!(
+        target.strip_prefix(service.target_prefix()),
+        Some(name) if public_actions.contains(&name)
+    )
+}
+
+/// Credentials which verify `AWS4-HMAC-SHA256` request signatures.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SignatureVerifier {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub max_skew_seconds: i64,
+}
+
+/// Parsed `Authorization` header.
+struct Authorization<'a> {
+    access_key_id: &'a str,
+    scope: Vec<&'a str>,
+    signed_headers: Vec<&'a str>,
+    signature: &'a str,
+}
+
+impl SignatureVerifier {
+    /// Returns the verifier in the config file, or None if signatures are not checked.
+    pub fn configured() -> Option<Self> {
+        configs::config()
+            .get(CONFIG_REQUEST_SIGNATURE)
+            .and_then(Self::from_config)
+    }
+
+    fn from_config(config: &HashMap<String, String>) -> Option<Self> {
+        let access_key_id = config.get(CONFIG_ACCESS_KEY_ID).filter(|v| !v.is_empty())?;
+        Some(SignatureVerifier {
+            access_key_id: access_key_id.clone(),
+            secret_access_key: config
+                .get(CONFIG_SECRET_ACCESS_KEY)
+                .cloned()
+                .unwrap_or_default(),
+            max_skew_seconds: config
+                .get(CONFIG_MAX_SKEW_SECONDS)
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(DEFAULT_MAX_SKEW_SECONDS),
+        })
+    }
+
+    /// Verifies the signature of a POST request to the root path.
+    pub fn verify(
+        &self,
+        headers: &HeaderMap,
+        queries: &HashMap<String, String>,
+        body: &[u8],
+        now: DateTime<Utc>,
+    ) -> Result<(), (CommonError, String)> {
+        let authorization = match header_value(headers, AUTHORIZATION_HEADER) {
+            Some(value) => value,
+            None => {
+                return Err((
+                    CommonError::MissingAuthenticationToken,
+                    "Request is missing Authentication Token".to_string(),
+                ))
+            }
+        };
+        let authorization = parse_authorization(&authorization).ok_or_else(|| {
+            (
+                CommonError::IncompleteSignature,
+                format!(
+                    "Authorization header requires Credential, SignedHeaders and Signature with {}.",
+                    SIGNING_ALGORITHM
+                ),
+            )
+        })?;
+        let amz_date = header_value(headers, AMZ_DATE_HEADER)
+            .filter(|_| authorization.signed_headers.contains(&AMZ_DATE_HEADER))
+            .ok_or_else(|| {
+                (
+                    CommonError::IncompleteSignature,
+                    format!("Signed header {} is required.", AMZ_DATE_HEADER),
+                )
+            })?;
+        let timestamp = NaiveDateTime::parse_from_str(&amz_date, AMZ_DATE_FORMAT)
+            .ok()
+            .filter(|t| authorization.scope[0] == t.format("%Y%m%d").to_string())
+            .ok_or_else(|| {
+                (
+                    CommonError::IncompleteSignature,
+                    format!("Invalid {} or credential scope date.", AMZ_DATE_HEADER),
+                )
+            })?;
+
+        if authorization.access_key_id != self.access_key_id {
+            return Err((
+                CommonError::InvalidClientTokenId,
+                "The security token included in the request is invalid.".to_string(),
+            ));
+        }
+        if (now.timestamp() - timestamp.and_utc().timestamp()).abs() > self.max_skew_seconds {
+            return Err((
+                CommonError::RequestExpired,
+                format!(
+                    "Signature expired: {} is not within {} seconds of {}.",
+                    amz_date,
+                    self.max_skew_seconds,
+                    now.format(AMZ_DATE_FORMAT)
+                ),
+            ));
+        }
+
+        let canonical_request = canonical_request(headers, queries, body, &authorization);
+        let expected = self.signature(&amz_date, &authorization.scope, &canonical_request);
+        if expected != authorization.signature {
+            return Err((
+                CommonError::InvalidSignatureException,
+                "The request signature we calculated does not match the signature you provided. Check your AWS Secret Access Key and signing method. Consult the service documentation for details."
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn signature(&self, amz_date: &str, scope: &[&str], canonical_request: &str) -> String {
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            SIGNING_ALGORITHM,
+            amz_date,
+            scope.join("/"),
+            hex(&Sha256::digest(canonical_request))
+        );
+        let key = scope.iter().fold(
+            format!("AWS4{}", self.secret_access_key).into_bytes(),
+            |key, part| hmac_sha256(&key, part.as_bytes()),
+        );
+        hex(&hmac_sha256(&key, string_to_sign.as_bytes()))
+    }
+}
+
+/// Parses `AWS4-HMAC-SHA256 Credential=<key>/<date>/<region>/<service>/aws4_request,
+/// SignedHeaders=<headers>, Signature=<signature>`.
+fn parse_authorization(value: &str) -> Option<Authorization<'_>> {
+    let params = value.strip_prefix(SIGNING_ALGORITHM)?;
+    let params = params
+        .split(',')
+        .filter_map(|param| param.trim().split_once('='))
+        .collect::<HashMap<_, _>>();
+    let mut credential = params.get("Credential")?.split('/');
+    let access_key_id = credential.next().filter(|s| !s.is_empty())?;
+    let scope = credential.collect::<Vec<_>>();
+    if scope.len() != 4 || scope[3] != SCOPE_TERMINATOR {
+        return None;
+    }
+    Some(Authorization {
+        access_key_id,
+        scope,
+        signed_headers: params.get("SignedHeaders")?.split(';').collect(),
+        signature: params.get("Signature").filter(|s| !s.is_empty())?,
+    })
+}
+
+fn canonical_request(
+    headers: &HeaderMap,
+    queries: &HashMap<String, String>,
+    body: &[u8],
+    authorization: &Authorization,
+) -> String {
+    let mut query = queries
+        .iter()
+        .map(|(k, v)| format!("{}={}", common::uri_encode(k), common::uri_encode(v)))
+        .collect::<Vec<_>>();
+    query.sort_unstable();
+    let canonical_headers = authorization
+        .signed_headers
+        .iter()
+        .map(|name| {
+            let values = headers
+                .get_all(*name)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .map(|v| v.split_whitespace().collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>();
+            format!("{}:{}\n", name, values.join(","))
+        })
+        .collect::<String>();
+    format!(
+        "POST\n/\n{}\n{}\n{}\n{}",
+        query.join("&"),
+        canonical_headers,
+        authorization.signed_headers.join(";"),
+        hex(&Sha256::digest(body))
+    )
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    const BODY: &str = r#"{"UserPoolId":"user_pool_id","Username":"user"}"#;
+
+    fn verifier() -> SignatureVerifier {
+        SignatureVerifier::from_config(&HashMap::from([
+            (CONFIG_ACCESS_KEY_ID.to_string(), "AKIDEXAMPLE".to_string()),
+            (
+                CONFIG_SECRET_ACCESS_KEY.to_string(),
+                "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            ),
+        ]))
+        .unwrap()
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap()
+    }
+
+    fn signed_headers(verifier: &SignatureVerifier, access_key_id: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "host",
+            "cognito-idp.us-east-1.amazonaws.com".parse().unwrap(),
+        );
+        headers.insert(AMZ_DATE_HEADER, "20150830T123600Z".parse().unwrap());
+        headers.insert(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetUser"
+                .parse()
+                .unwrap(),
+        );
+        let authorization = Authorization {
+            access_key_id,
+            scope: vec!["20150830", "us-east-1", "cognito-idp", SCOPE_TERMINATOR],
+            signed_headers: vec!["host", AMZ_DATE_HEADER, "x-amz-target"],
+            signature: "",
+        };
+        let canonical_request =
+            canonical_request(&headers, &HashMap::new(), BODY.as_bytes(), &authorization);
+        let signature =
+            verifier.signature("20150830T123600Z", &authorization.scope, &canonical_request);
+        headers.insert(
+            AUTHORIZATION_HEADER,
+            format!(
+                "{} Credential={}/20150830/us-east-1/cognito-idp/aws4_request, SignedHeaders=host;x-amz-date;x-amz-target, Signature={}",
+                SIGNING_ALGORITHM, access_key_id, signature
+            )
+            .parse()
+            .unwrap(),
+        );
+        headers
+    }
+
+    fn verify(headers: &HeaderMap, now: DateTime<Utc>) -> Result<(), String> {
+        verifier()
+            .verify(headers, &HashMap::new(), BODY.as_bytes(), now)
+            .map_err(|(e, _)| e.to_string())
+    }
+
+    #[test]
+    fn verifier_can_read_from_config() {
+        let verifier = verifier();
+        assert_eq!("AKIDEXAMPLE", verifier.access_key_id);
+        assert_eq!(DEFAULT_MAX_SKEW_SECONDS, verifier.max_skew_seconds);
+        assert_eq!(None, SignatureVerifier::from_config(&HashMap::new()));
+    }
+
+    #[test]
+    fn signature_is_derived_from_secret_access_key() {
+        // Example from the AWS Signature Version 4 documentation.
+        let verifier = SignatureVerifier {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            max_skew_seconds: DEFAULT_MAX_SKEW_SECONDS,
+        };
+        let canonical_request = "GET\n/\nAction=ListUsers&Version=2010-05-08\ncontent-type:application/x-www-form-urlencoded; charset=utf-8\nhost:iam.amazonaws.com\nx-amz-date:20150830T123600Z\n\ncontent-type;host;x-amz-date\ne3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert_eq!(
+            "5d672d79c15b13162d9279b0855cfba6789a8edb4c82c400e06b5924a6f2b5d7",
+            verifier.signature(
+                "20150830T123600Z",
+                &["20150830", "us-east-1", "iam", SCOPE_TERMINATOR],
+                canonical_request
+            )
+        );
+    }
+
+    #[test]
+    fn success_to_verify_signature() {
+        let headers = signed_headers(&verifier(), "AKIDEXAMPLE");
+        assert!(verify(&headers, now()).is_ok());
+        assert!(verify(
+            &headers,
+            now() + chrono::Duration::seconds(DEFAULT_MAX_SKEW_SECONDS)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn failure_to_verify_signature() {
+        let headers = signed_headers(&verifier(), "AKIDEXAMPLE");
+        assert_eq!(
+            Err("RequestExpired".to_string()),
+            verify(
+                &headers,
+                now() + chrono::Duration::seconds(DEFAULT_MAX_SKEW_SECONDS + 1)
+            )
+        );
+
+        let mut unsigned = headers.clone();
+        unsigned.remove(AUTHORIZATION_HEADER);
+        assert_eq!(
+            Err("MissingAuthenticationToken".to_string()),
+            verify(&unsigned, now())
+        );
+
+        let mut incomplete = headers.clone();
+        incomplete.insert(
+            AUTHORIZATION_HEADER,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/cognito-idp/aws4_request"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            Err("IncompleteSignature".to_string()),
+            verify(&incomplete, now())
+        );
+
+        let mut tampered = headers.clone();
+        tampered.insert(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminDeleteUser"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            Err("InvalidSignatureException".to_string()),
+            verify(&tampered, now())
+        );
+
+        let unknown = signed_headers(&verifier(), "AKIDUNKNOWN");
+        assert_eq!(
+            Err("InvalidClientTokenId".to_string()),
+            verify(&unknown, now())
+        );
+    }
+
+    #[test]
+    fn public_actions_are_exempt() {
+        assert!(is_public_action(
+            "AWSCognitoIdentityProviderService.InitiateAuth"
+        ));
+        assert!(is_public_action("AWSCognitoIdentityService.GetId"));
+        assert!(!is_public_action(
+            "AWSCognitoIdentityProviderService.AdminInitiateAuth"
+        ));
+        assert!(!is_public_action("InitiateAuth"));
+        assert!(!is_public_action("AWSCognitoIdentityProviderService.GetId"));
+        assert!(!is_public_action("AWSCognitoIdentityService.InitiateAuth"));
+        assert!(!is_public_action("Foo.AWSCognitoIdentityService.GetId"));
+    }
+}
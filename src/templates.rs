@@ -2,6 +2,11 @@ use serde::Serialize;
 use tera::{Context, Tera};
 use tokio::sync::OnceCell;
 
+/// Templates directory of user pools actions.
+pub const USER_POOLS_TEMPLATE_DIR: &str = "user_pools";
+/// Templates directory of identity pools actions.
+pub const IDENTITY_POOLS_TEMPLATE_DIR: &str = "identity_pools";
+
 const TEMPLATE_PATH: &str = "templates";
 const DEFAULT_TEMPLATE_PATH: &str = "resources/default_templates";
 static TEMPLATES: OnceCell<Option<Tera>> = OnceCell::const_new();
@@ -38,6 +43,7 @@ pub async fn init_default_template() {
 }
 
 pub fn render_template<T>(
+    template_dir: &str,
     action_name: &str,
     context_value: &T,
     additional_values: String,
@@ -45,7 +51,7 @@ pub fn render_template<T>(
 where
     T: Serialize,
 {
-    let file_name = format!("user_pools/{}.json", action_name);
+    let file_name = format!("{}/{}.json", template_dir, action_name);
     let r = serde_json::to_string(&context_value).unwrap();
     let map: std::collections::HashMap<String, serde_json::Value> =
         serde_json::from_str(&r).unwrap();
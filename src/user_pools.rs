@@ -25,9 +25,12 @@ mod admin_update_auth_event_feedback;
 mod admin_update_device_status;
 mod admin_update_user_attributes;
 mod admin_user_global_sign_out;
+mod aliases;
 mod associate_software_token;
+mod attributes;
+mod auth_events;
+mod authentication;
 mod change_password;
-mod configs;
 mod confirm_device;
 mod confirm_forgot_password;
 mod confirm_sign_up;
@@ -37,9 +40,66 @@ mod create_resource_server;
 mod create_user_import_job;
 mod create_user_pool;
 mod create_user_pool_client;
+mod create_user_pool_domain;
 mod data_types;
-mod errors;
-mod responses;
+mod delete_identity_provider;
+mod delete_resource_server;
+mod delete_user;
+mod delete_user_attributes;
+mod delete_user_pool_domain;
+mod describe_identity_provider;
+mod describe_resource_server;
+mod describe_risk_configuration;
+mod describe_user_import_job;
+mod describe_user_pool;
+mod describe_user_pool_domain;
+mod devices;
+mod domains;
+mod federation;
+mod forget_device;
+mod get_csv_header;
+mod get_device;
+mod get_identity_provider_by_identifier;
+mod get_ui_customization;
+mod get_user;
+mod get_user_attribute_verification_code;
+mod global_sign_out;
+mod groups;
+mod hosted_ui;
+mod identity_providers;
+mod import_jobs;
+mod initiate_auth;
+mod list_devices;
+mod list_identity_providers;
+mod list_resource_servers;
+mod list_tags_for_resource;
+mod list_user_import_jobs;
+mod list_users;
+mod oauth_scopes;
+mod password_policy;
+mod provider_links;
+mod respond_to_auth_challenge;
+mod revoke_token;
+mod risk;
+mod set_risk_configuration;
+mod set_ui_customization;
+mod sign_up;
+pub mod srp;
+mod start_user_import_job;
+mod stop_user_import_job;
+mod store;
+mod tag_resource;
+mod tags;
+mod tokens;
+mod ui_customization;
+mod untag_resource;
+mod update_device_status;
+mod update_identity_provider;
+mod update_resource_server;
+mod update_user_attributes;
+mod update_user_pool_domain;
+mod verification;
+mod verify_user_attribute;
 
 pub use self::add_custom_attributes::*;
 pub use self::admin_add_user_to_group::*;
@@ -79,8 +139,71 @@ pub use self::create_resource_server::*;
 pub use self::create_user_import_job::*;
 pub use self::create_user_pool::*;
 pub use self::create_user_pool_client::*;
+pub use self::create_user_pool_domain::*;
+pub use self::delete_identity_provider::*;
+pub use self::delete_resource_server::*;
+pub use self::delete_user::*;
+pub use self::delete_user_attributes::*;
+pub use self::delete_user_pool_domain::*;
+pub use self::describe_identity_provider::*;
+pub use self::describe_resource_server::*;
+pub use self::describe_risk_configuration::*;
+pub use self::describe_user_import_job::*;
+pub use self::describe_user_pool::*;
+pub use self::describe_user_pool_domain::*;
+pub use self::forget_device::*;
+pub use self::get_csv_header::*;
+pub use self::get_device::*;
+pub use self::get_identity_provider_by_identifier::*;
+pub use self::get_ui_customization::*;
+pub use self::get_user::*;
+pub use self::get_user_attribute_verification_code::*;
+pub use self::global_sign_out::*;
+pub use self::initiate_auth::*;
+pub use self::list_devices::*;
+pub use self::list_identity_providers::*;
+pub use self::list_resource_servers::*;
+pub use self::list_tags_for_resource::*;
+pub use self::list_user_import_jobs::*;
+pub use self::list_users::*;
+pub use self::respond_to_auth_challenge::*;
+pub use self::revoke_token::*;
+pub use self::set_risk_configuration::*;
+pub use self::set_ui_customization::*;
+pub use self::sign_up::*;
+pub use self::start_user_import_job::*;
+pub use self::stop_user_import_job::*;
+pub use self::tag_resource::*;
+pub use self::untag_resource::*;
+pub use self::update_device_status::*;
+pub use self::update_identity_provider::*;
+pub use self::update_resource_server::*;
+pub use self::update_user_attributes::*;
+pub use self::update_user_pool_domain::*;
+pub use self::verify_user_attribute::*;
 
-pub use self::configs::*;
+pub use self::aliases::*;
+pub use self::attributes::*;
+pub use self::auth_events::*;
+pub use self::authentication::*;
 pub use self::data_types::*;
-pub use self::errors::*;
-pub use self::responses::*;
+pub use self::devices::*;
+pub use self::domains::*;
+pub use self::federation::*;
+pub use self::groups::*;
+pub use self::hosted_ui::*;
+pub use self::import_jobs::*;
+pub use self::oauth_scopes::*;
+pub use self::password_policy::*;
+pub use self::provider_links::*;
+pub use self::risk::*;
+pub use self::store::*;
+pub use self::tags::*;
+pub use self::tokens::*;
+pub use self::ui_customization::*;
+pub use self::verification::*;
+
+use crate::configs::*;
+use crate::errors::*;
+use crate::pagination::*;
+use crate::responses::*;
@@ -40,7 +40,23 @@ impl super::ToActionName for AddCustomAttributesRequest {
 impl super::ToResponse for AddCustomAttributesRequest {
     type E = AddCustomAttributesError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool
+                    .add_custom_attributes(self.custom_attributes.as_deref().unwrap_or_default())
+            })
+            .map_err(super::to_response_error)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let attributes = self.custom_attributes.as_ref()?;
+        super::check_custom_attribute_names(attributes.iter().filter_map(|a| a.name.as_deref()), 0)
+            .map(|message| (AddCustomAttributesError::InvalidParameterException, message))
     }
 }
 
@@ -69,7 +85,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AddCustomAttributesError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
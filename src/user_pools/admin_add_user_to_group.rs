@@ -44,7 +44,19 @@ impl super::ToActionName for AdminAddUserToGroupRequest {
 impl super::ToResponse for AdminAddUserToGroupRequest {
     type E = AdminAddUserToGroupError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.add_user_to_group(
+                    self.username.as_deref().unwrap_or_default(),
+                    self.group_name.as_deref().unwrap_or_default(),
+                )
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -75,7 +87,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminAddUserToGroupError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
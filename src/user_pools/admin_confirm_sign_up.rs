@@ -45,7 +45,26 @@ impl super::ToActionName for AdminConfirmSignUpRequest {
 impl super::ToResponse for AdminConfirmSignUpRequest {
     type E = AdminConfirmSignUpError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                let user = user_pool.user_mut(self.username.as_deref().unwrap_or_default())?;
+                if user.status != super::UserStatus::Unconfirmed {
+                    return Err((
+                        super::StoreError::NotAuthorizedException,
+                        "User cannot be confirmed. Current status is CONFIRMED".to_string(),
+                    ));
+                }
+                user.status = super::UserStatus::Confirmed;
+                user.confirmation_code = None;
+                user.last_modified_at = chrono::Utc::now();
+                Ok(())
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -76,7 +95,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminConfirmSignUpError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
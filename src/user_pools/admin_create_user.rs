@@ -24,7 +24,8 @@ fn validate_message_action(value: &str) -> Result<(), ValidationError> {
 
 super::gen_response_err!(
     AdminCreateUserError,
-    CodeDeliveryFailureException
+    AliasExistsException
+    | CodeDeliveryFailureException
     | InvalidLambdaResponseException
     | InvalidParameterException
     | InvalidPasswordException
@@ -75,7 +76,83 @@ impl super::ToActionName for AdminCreateUserRequest {
 impl super::ToResponse for AdminCreateUserRequest {
     type E = AdminCreateUserError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_CREATE_USER_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, ADMIN_CREATE_USER_NAME);
+        }
+        let mut temporary_password = None;
+        let response =
+            super::to_converted_json_response(self, ADMIN_CREATE_USER_NAME, |mut value| {
+                let (user, password) =
+                    super::with_user_pool(user_pool_id, |user_pool| self.create_user(user_pool))
+                        .map_err(super::to_response_error)?;
+                value["User"] = user;
+                temporary_password = Some(password);
+                Ok(value)
+            });
+        super::with_code_header(response, temporary_password)
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let attribute_error = self.user_attributes.as_ref().and_then(|attributes| {
+            super::check_writable_attributes(
+                attributes
+                    .iter()
+                    .filter_map(|a| a.get("Name").map(String::as_str)),
+            )
+        });
+        if let Some(message) = attribute_error {
+            return Some((AdminCreateUserError::InvalidParameterException, message));
+        }
+        super::check_password(self.temporary_password.as_deref()?)
+            .map(|message| (AdminCreateUserError::InvalidPasswordException, message))
+    }
+}
+
+impl AdminCreateUserRequest {
+    /// Creates a user (or resets the temporary password to resend) and returns it with the password.
+    fn create_user(
+        &self,
+        user_pool: &mut super::UserPoolState,
+    ) -> super::StoreResult<(serde_json::Value, String)> {
+        let username = self.username.as_deref().unwrap_or_default();
+        let password = match self.temporary_password.clone() {
+            Some(password) => {
+                user_pool.check_password(&password)?;
+                password
+            }
+            None => user_pool.new_temporary_password(),
+        };
+        let expires_at = user_pool.temporary_password_expires_at()?;
+        let user = if self.message_action.as_deref() == Some("RESEND") {
+            let user = user_pool.user_mut(username)?;
+            if user.status != super::UserStatus::ForceChangePassword {
+                return Err((
+                    super::StoreError::UnsupportedUserStateException,
+                    "Resend not possible. User is already confirmed.".to_string(),
+                ));
+            }
+            user
+        } else {
+            let attributes = self
+                .user_attributes
+                .iter()
+                .flatten()
+                .filter_map(|a| Some((a.get("Name")?.clone(), a.get("Value")?.clone())))
+                .collect::<Vec<_>>();
+            user_pool.check_user_attributes(
+                &attributes,
+                super::AttributeWrite::Create { admin: true },
+            )?;
+            let mut user = super::UserState::new(username, super::UserStatus::ForceChangePassword);
+            for (name, value) in attributes.iter() {
+                user.set_attribute(name, value);
+            }
+            user_pool.add_user_with_aliases(user, self.force_alias_creation.unwrap_or_default())?
+        };
+        user.password = Some(password.clone());
+        user.temporary_password_expires_at = Some(expires_at);
+        Ok((user.to_value("Attributes"), password))
     }
 }
 
@@ -107,7 +184,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminCreateUserError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
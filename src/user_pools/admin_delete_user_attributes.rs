@@ -65,7 +65,6 @@ mod tests {
             user_attribute_names: Some(vec!["foo".to_string()]),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -76,14 +75,13 @@ mod tests {
             user_attribute_names: Some(vec!["foo".to_string()]),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminDeleteUserAttributesError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
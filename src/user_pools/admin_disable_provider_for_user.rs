@@ -38,7 +38,16 @@ impl super::ToActionName for AdminDisableProviderForUserRequest {
 impl super::ToResponse for AdminDisableProviderForUserRequest {
     type E = AdminDisableProviderForUserError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.disable_provider(self.user.as_ref().unwrap_or(&Default::default()))
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -52,7 +61,6 @@ mod tests {
         let request = AdminDisableProviderForUserRequest {
             user: Some(Default::default()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -62,14 +70,13 @@ mod tests {
         let request = AdminDisableProviderForUserRequest {
             user: Some(Default::default()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminDisableProviderForUserError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -40,7 +40,19 @@ impl super::ToActionName for AdminDisableUserRequest {
 impl super::ToResponse for AdminDisableUserRequest {
     type E = AdminDisableUserError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                let username = self.username.as_deref().unwrap_or_default();
+                user_pool.user_mut(username)?.enabled = false;
+                user_pool.revoke_user_sessions(username);
+                Ok(())
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -54,7 +66,6 @@ mod tests {
         let request = AdminDisableUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -64,14 +75,13 @@ mod tests {
         let request = AdminDisableUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminDisableUserError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -39,7 +39,19 @@ impl super::ToActionName for AdminEnableUserRequest {
 impl super::ToResponse for AdminEnableUserRequest {
     type E = AdminEnableUserError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool
+                    .user_mut(self.username.as_deref().unwrap_or_default())?
+                    .enabled = true;
+                Ok(())
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -53,7 +65,6 @@ mod tests {
         let request = AdminEnableUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -63,14 +74,13 @@ mod tests {
         let request = AdminEnableUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminEnableUserError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
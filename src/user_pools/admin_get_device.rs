@@ -13,7 +13,8 @@ super::gen_response_err!(
     | InvalidUserPoolConfigurationException
     | NotAuthorizedException
     | ResourceNotFoundException
-    | TooManyRequestsException => http::status_code(400),
+    | TooManyRequestsException
+    | UserNotFoundException => http::status_code(400),
     InternalErrorException => http::status_code(500)
 );
 
@@ -43,7 +44,22 @@ impl super::ToActionName for AdminGetDeviceRequest {
 impl super::ToResponse for AdminGetDeviceRequest {
     type E = AdminGetDeviceError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_GET_DEVICE_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, ADMIN_GET_DEVICE_NAME);
+        }
+        super::to_converted_json_response(self, ADMIN_GET_DEVICE_NAME, |mut value| {
+            value["Device"] = super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool
+                    .device(
+                        self.username.as_deref().unwrap_or_default(),
+                        self.device_key.as_deref().unwrap_or_default(),
+                    )
+                    .map(super::DeviceState::to_value)
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
     }
 }
 
@@ -58,7 +74,6 @@ mod tests {
             device_key: Some("device_key".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -69,14 +84,13 @@ mod tests {
             device_key: Some("device_key".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminGetDeviceError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -39,7 +39,18 @@ impl super::ToActionName for AdminGetUserRequest {
 impl super::ToResponse for AdminGetUserRequest {
     type E = AdminGetUserError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_GET_USER_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, ADMIN_GET_USER_NAME);
+        }
+        super::to_converted_json_response(self, ADMIN_GET_USER_NAME, |_| {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .user(self.username.as_deref().unwrap_or_default())?
+                    .to_value("UserAttributes"))
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -53,7 +64,6 @@ mod tests {
         let request = AdminGetUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -63,14 +73,13 @@ mod tests {
         let request = AdminGetUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminGetUserError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
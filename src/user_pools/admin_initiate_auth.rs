@@ -8,6 +8,11 @@ pub const ADMIN_INITIATE_AUTH_NAME: &str = "AdminInitiateAuth";
 pub const ADMIN_INITIATE_AUTH_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.AdminInitiateAuth";
 
+const REFRESH_TOKEN_AUTH_FLOWS: [&str; 2] = ["REFRESH_TOKEN_AUTH", "REFRESH_TOKEN"];
+const REFRESH_TOKEN_PARAMETER: &str = "REFRESH_TOKEN";
+const DEVICE_KEY_PARAMETER: &str = "DEVICE_KEY";
+const PASSWORD_PARAMETER: &str = "PASSWORD";
+
 fn validate_auth_flow(value: &str) -> Result<(), ValidationError> {
     includes(
         value,
@@ -73,7 +78,71 @@ impl super::ToActionName for AdminInitiateAuthRequest {
 impl super::ToResponse for AdminInitiateAuthRequest {
     type E = AdminInitiateAuthError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_INITIATE_AUTH_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, ADMIN_INITIATE_AUTH_NAME);
+        }
+        let mut code = None;
+        let response = super::to_converted_json_response(self, ADMIN_INITIATE_AUTH_NAME, |value| {
+            let outcome = super::with_user_pool(user_pool_id, |user_pool| {
+                let outcome = user_pool.initiate_auth(
+                    self.client_id.as_deref().unwrap_or_default(),
+                    self.auth_flow.as_deref().unwrap_or_default(),
+                    self.auth_parameters.as_ref().unwrap_or(&Default::default()),
+                    true,
+                    super::EventContext::from_context_data(self.context_data.as_ref()),
+                )?;
+                code = user_pool.challenge_code(&outcome);
+                Ok(outcome)
+            })
+            .map_err(super::to_response_error)?;
+            Ok(outcome.to_value(value))
+        });
+        super::with_code_header(response, code)
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let auth_flow = self.auth_flow.as_deref().unwrap_or_default();
+        let has_refresh_token = self
+            .auth_parameter(REFRESH_TOKEN_PARAMETER)
+            .is_some_and(|t| !t.is_empty());
+        if REFRESH_TOKEN_AUTH_FLOWS.contains(&auth_flow) && !has_refresh_token {
+            return Some((
+                AdminInitiateAuthError::InvalidParameterException,
+                format!("Missing required parameter {}", REFRESH_TOKEN_PARAMETER),
+            ));
+        }
+        // Sign-ins of stored user pools are evaluated when they are authenticated.
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id)
+            && super::evaluate_risk(&self.risk_context()) == super::RiskDecision::Block
+        {
+            return Some((
+                AdminInitiateAuthError::NotAuthorizedException,
+                super::BLOCKED_BY_RISK_MESSAGE.to_string(),
+            ));
+        }
+        None
+    }
+}
+
+impl AdminInitiateAuthRequest {
+    fn auth_parameter(&self, name: &str) -> Option<&str> {
+        self.auth_parameters
+            .as_ref()
+            .and_then(|p| p.get(name))
+            .map(String::as_str)
+    }
+
+    fn risk_context(&self) -> super::RiskContext<'_> {
+        super::RiskContext {
+            ip_address: self
+                .context_data
+                .as_ref()
+                .and_then(|c| c.ip_address.as_deref()),
+            device_key: self.auth_parameter(DEVICE_KEY_PARAMETER),
+            password: self.auth_parameter(PASSWORD_PARAMETER),
+        }
     }
 }
 
@@ -104,9 +173,28 @@ mod tests {
         assert!(request.validate().is_err());
     }
 
+    #[test]
+    fn refresh_token_flow_requires_refresh_token() {
+        use crate::responses::ToResponse;
+
+        let mut request = AdminInitiateAuthRequest {
+            auth_flow: Some("REFRESH_TOKEN_AUTH".to_string()),
+            client_id: Some("client_id".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.action_error().is_some());
+
+        request.auth_parameters = Some(std::collections::HashMap::from([(
+            "REFRESH_TOKEN".to_string(),
+            "refresh_token".to_string(),
+        )]));
+        assert!(request.action_error().is_none());
+    }
+
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminInitiateAuthError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
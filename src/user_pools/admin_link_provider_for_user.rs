@@ -22,8 +22,10 @@ super::gen_response_err!(
 #[derive(Serialize, Deserialize, Debug, Default, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct AdminLinkProviderForUserRequest {
+    #[validate(required)]
     #[validate(nested)]
     pub destination_user: Option<super::data_types::ProviderUserIdentifierType>,
+    #[validate(required)]
     #[validate(nested)]
     pub source_user: Option<super::data_types::ProviderUserIdentifierType>,
     #[validate(required)]
@@ -40,7 +42,45 @@ impl super::ToActionName for AdminLinkProviderForUserRequest {
 impl super::ToResponse for AdminLinkProviderForUserRequest {
     type E = AdminLinkProviderForUserError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            let destination_user = self.destination_user.as_ref();
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.link_provider(
+                    destination_user
+                        .and_then(|u| u.provider_attribute_value.as_deref())
+                        .unwrap_or_default(),
+                    self.source_user.as_ref().unwrap_or(&Default::default()),
+                )
+            })
+            .map_err(super::to_response_error)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let destination_user = self.destination_user.as_ref()?;
+        let source_user = self.source_user.as_ref()?;
+        let message =
+            if destination_user.provider_name.as_deref() != Some(super::COGNITO_PROVIDER_NAME) {
+                "DestinationUser ProviderName must be Cognito."
+            } else if destination_user
+                .provider_attribute_value
+                .as_deref()
+                .is_none_or(str::is_empty)
+            {
+                "DestinationUser ProviderAttributeValue must be the username."
+            } else if source_user.provider_name.as_deref() == Some(super::COGNITO_PROVIDER_NAME) {
+                "SourceUser ProviderName cannot be Cognito."
+            } else {
+                return None;
+            };
+        Some((
+            AdminLinkProviderForUserError::InvalidParameterException,
+            message.to_string(),
+        ))
     }
 }
 
@@ -55,7 +95,6 @@ mod tests {
             destination_user: Some(Default::default()),
             source_user: Some(Default::default()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -66,14 +105,39 @@ mod tests {
             destination_user: Some(Default::default()),
             source_user: Some(Default::default()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
+    #[test]
+    fn action_error_for_invalid_provider_names() {
+        use crate::user_pools::{ProviderUserIdentifierType, ToResponse};
+
+        let user = |provider_name: &str| {
+            Some(ProviderUserIdentifierType {
+                provider_attribute_name: Some("Cognito_Subject".to_string()),
+                provider_attribute_value: Some("value".to_string()),
+                provider_name: Some(provider_name.to_string()),
+            })
+        };
+        let mut request = AdminLinkProviderForUserRequest {
+            destination_user: user("Cognito"),
+            source_user: user("Google"),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.action_error().is_none());
+
+        request.source_user = user("Cognito");
+        assert!(request.action_error().is_some());
+
+        request.destination_user = user("Google");
+        request.source_user = user("Google");
+        assert!(request.action_error().is_some());
+    }
+
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminLinkProviderForUserError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
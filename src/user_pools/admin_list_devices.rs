@@ -18,7 +18,8 @@ super::gen_response_err!(
     | InvalidUserPoolConfigurationException
     | NotAuthorizedException
     | ResourceNotFoundException
-    | TooManyRequestsException => http::status_code(400),
+    | TooManyRequestsException
+    | UserNotFoundException => http::status_code(400),
     InternalErrorException => http::status_code(500)
 );
 
@@ -49,7 +50,48 @@ impl super::ToActionName for AdminListDevicesRequest {
 impl super::ToResponse for AdminListDevicesRequest {
     type E = AdminListDevicesError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_LIST_DEVICES_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_paginated_json_response(self, ADMIN_LIST_DEVICES_NAME);
+        }
+        super::to_converted_json_response(self, ADMIN_LIST_DEVICES_NAME, |mut value| {
+            value["Devices"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .user(self.username.as_deref().unwrap_or_default())?
+                    .devices
+                    .values()
+                    .map(super::DeviceState::to_value)
+                    .collect())
+            })
+            .map_err(super::to_response_error)?;
+            super::to_page(self, value)
+        })
+    }
+}
+
+impl super::ToPage for AdminListDevicesRequest {
+    fn items_key() -> &'static str {
+        "Devices"
+    }
+
+    fn item_key() -> &'static str {
+        "DeviceKey"
+    }
+
+    fn token_key() -> &'static str {
+        "PaginationToken"
+    }
+
+    fn max_page_size() -> u8 {
+        60
+    }
+
+    fn page_limit(&self) -> Option<u8> {
+        self.limit
+    }
+
+    fn page_token(&self) -> Option<&str> {
+        self.pagination_token.as_deref()
     }
 }
 
@@ -80,7 +122,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminListDevicesError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -49,7 +49,47 @@ impl super::ToActionName for AdminListGroupsForUserRequest {
 impl super::ToResponse for AdminListGroupsForUserRequest {
     type E = AdminListGroupsForUserError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_LIST_GROUPS_FOR_USER_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_paginated_json_response(self, ADMIN_LIST_GROUPS_FOR_USER_NAME);
+        }
+        super::to_converted_json_response(self, ADMIN_LIST_GROUPS_FOR_USER_NAME, |mut value| {
+            value["Groups"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .user_groups(self.username.as_deref().unwrap_or_default())?
+                    .into_iter()
+                    .cloned()
+                    .collect())
+            })
+            .map_err(super::to_response_error)?;
+            super::to_page(self, value)
+        })
+    }
+}
+
+impl super::ToPage for AdminListGroupsForUserRequest {
+    fn items_key() -> &'static str {
+        "Groups"
+    }
+
+    fn item_key() -> &'static str {
+        "GroupName"
+    }
+
+    fn token_key() -> &'static str {
+        "NextToken"
+    }
+
+    fn max_page_size() -> u8 {
+        60
+    }
+
+    fn page_limit(&self) -> Option<u8> {
+        self.limit
+    }
+
+    fn page_token(&self) -> Option<&str> {
+        self.next_token.as_deref()
     }
 }
 
@@ -80,7 +120,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminListGroupsForUserError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -50,7 +50,48 @@ impl super::ToActionName for AdminListUserAuthEventsRequest {
 impl super::ToResponse for AdminListUserAuthEventsRequest {
     type E = AdminListUserAuthEventsError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_LIST_USER_AUTH_EVENTS_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_paginated_json_response(self, ADMIN_LIST_USER_AUTH_EVENTS_NAME);
+        }
+        super::to_converted_json_response(self, ADMIN_LIST_USER_AUTH_EVENTS_NAME, |mut value| {
+            value["AuthEvents"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .user(self.username.as_deref().unwrap_or_default())?
+                    .auth_events
+                    .iter()
+                    .map(super::AuthEventState::to_value)
+                    .collect())
+            })
+            .map_err(super::to_response_error)?;
+            super::to_page(self, value)
+        })
+    }
+}
+
+impl super::ToPage for AdminListUserAuthEventsRequest {
+    fn items_key() -> &'static str {
+        "AuthEvents"
+    }
+
+    fn item_key() -> &'static str {
+        "EventId"
+    }
+
+    fn token_key() -> &'static str {
+        "NextToken"
+    }
+
+    fn max_page_size() -> u8 {
+        60
+    }
+
+    fn page_limit(&self) -> Option<u8> {
+        self.max_results
+    }
+
+    fn page_token(&self) -> Option<&str> {
+        self.next_token.as_deref()
     }
 }
 
@@ -81,7 +122,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminListUserAuthEventsError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
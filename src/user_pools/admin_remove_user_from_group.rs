@@ -44,7 +44,19 @@ impl super::ToActionName for AdminRemoveUserFromGroupRequest {
 impl super::ToResponse for AdminRemoveUserFromGroupRequest {
     type E = AdminRemoveUserFromGroupError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.remove_user_from_group(
+                    self.username.as_deref().unwrap_or_default(),
+                    self.group_name.as_deref().unwrap_or_default(),
+                )
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -59,7 +71,6 @@ mod tests {
             group_name: Some("group_name".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -70,14 +81,13 @@ mod tests {
             group_name: Some("group_name".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminRemoveUserFromGroupError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
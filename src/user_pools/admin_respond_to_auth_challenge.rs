@@ -58,7 +58,7 @@ pub struct AdminRespondToAuthChallengeRequest {
     #[validate(length(min = 1))]
     #[validate(custom(function = validate_challenge_name))]
     pub challenge_name: Option<String>,
-    pub challenge_response: Option<std::collections::HashMap<String, String>>,
+    pub challenge_responses: Option<std::collections::HashMap<String, String>>,
     #[validate(required)]
     #[validate(length(min = 1, max = 128))]
     #[validate(regex(path = *CLIENT_ID_REGEX))]
@@ -82,7 +82,49 @@ impl super::ToActionName for AdminRespondToAuthChallengeRequest {
 impl super::ToResponse for AdminRespondToAuthChallengeRequest {
     type E = AdminRespondToAuthChallengeError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_RESPOND_TO_AUTH_CHALLENGE_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, ADMIN_RESPOND_TO_AUTH_CHALLENGE_NAME);
+        }
+        super::to_converted_json_response(self, ADMIN_RESPOND_TO_AUTH_CHALLENGE_NAME, |value| {
+            let outcome = super::with_user_pool(user_pool_id, |user_pool| {
+                let outcome = user_pool.respond_to_auth_challenge(
+                    self.client_id.as_deref().unwrap_or_default(),
+                    self.challenge_name.as_deref().unwrap_or_default(),
+                    self.session.as_deref(),
+                    self.challenge_responses
+                        .as_ref()
+                        .unwrap_or(&Default::default()),
+                );
+                if let Some(username) = self
+                    .challenge_responses
+                    .as_ref()
+                    .and_then(|r| r.get("USERNAME"))
+                {
+                    user_pool.record_challenge_response(
+                        username,
+                        self.challenge_name.as_deref().unwrap_or_default(),
+                        &outcome,
+                    );
+                }
+                outcome
+            })
+            .map_err(super::to_response_error)?;
+            Ok(outcome.to_value(value))
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        if self.challenge_name.as_deref() != Some(super::NEW_PASSWORD_REQUIRED_CHALLENGE) {
+            return None;
+        }
+        let new_password = self.challenge_responses.as_ref()?.get("NEW_PASSWORD")?;
+        super::check_password(new_password).map(|message| {
+            (
+                AdminRespondToAuthChallengeError::InvalidPasswordException,
+                message,
+            )
+        })
     }
 }
 
@@ -115,7 +157,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminRespondToAuthChallengeError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -4,8 +4,6 @@ use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 use validator::Validate;
 
-use super::AdminAddUserToGroupError;
-
 pub const ADMIN_SET_USER_PASSWORD_NAME: &str = "AdminSetUserPassword";
 pub const ADMIN_SET_USER_PASSWORD_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.AdminSetUserPassword";
@@ -46,9 +44,35 @@ impl super::ToActionName for AdminSetUserPasswordRequest {
 }
 
 impl super::ToResponse for AdminSetUserPasswordRequest {
-    type E = AdminAddUserToGroupError;
+    type E = AdminSetUserPasswordError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.check_password(self.password.as_deref().unwrap_or_default())?;
+                let expires_at = user_pool.temporary_password_expires_at()?;
+                let user = user_pool.user_mut(self.username.as_deref().unwrap_or_default())?;
+                user.password = self.password.clone();
+                if self.permanent.unwrap_or_default() {
+                    user.status = super::UserStatus::Confirmed;
+                    user.temporary_password_expires_at = None;
+                } else {
+                    user.status = super::UserStatus::ForceChangePassword;
+                    user.temporary_password_expires_at = Some(expires_at);
+                }
+                user.last_modified_at = chrono::Utc::now();
+                Ok(())
+            })
+            .map_err(super::to_response_error)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_password(self.password.as_deref()?)
+            .map(|message| (AdminSetUserPasswordError::InvalidPasswordException, message))
     }
 }
 
@@ -81,7 +105,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminSetUserPasswordError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -67,7 +67,6 @@ mod tests {
             mfa_options: Some(Default::default()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -78,14 +77,13 @@ mod tests {
             mfa_options: Some(Default::default()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminSetUserSettingsError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -53,7 +53,20 @@ impl super::ToActionName for AdminUpdateAuthEventFeedbackRequest {
 impl super::ToResponse for AdminUpdateAuthEventFeedbackRequest {
     type E = AdminUpdateAuthEventFeedbackError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.update_auth_event_feedback(
+                    self.username.as_deref().unwrap_or_default(),
+                    self.event_id.as_deref().unwrap_or_default(),
+                    self.feedback_value.as_deref().unwrap_or_default(),
+                )
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -69,7 +82,6 @@ mod tests {
             feedback_value: Some("Valid".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -81,14 +93,13 @@ mod tests {
             feedback_value: Some("feedback_value".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminUpdateAuthEventFeedbackError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -52,7 +52,20 @@ impl super::ToActionName for AdminUpdateDeviceStatusRequest {
 impl super::ToResponse for AdminUpdateDeviceStatusRequest {
     type E = AdminUpdateDeviceStatusError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.update_device_status(
+                    self.username.as_deref().unwrap_or_default(),
+                    self.device_key.as_deref().unwrap_or_default(),
+                    self.device_remembered_status.as_deref(),
+                )
+            })
+            .map_err(super::to_response_error)
+        })
     }
 }
 
@@ -85,7 +98,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminUpdateDeviceStatusError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -52,7 +52,45 @@ impl super::ToActionName for AdminUpdateUserAttributesRequest {
 impl super::ToResponse for AdminUpdateUserAttributesRequest {
     type E = AdminUpdateUserAttributesError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        let mut codes = None;
+        let response = super::to_applied_empty_response(self, || {
+            let deliveries = super::with_user_pool(user_pool_id, |user_pool| {
+                let attributes = self
+                    .user_attributes
+                    .iter()
+                    .flatten()
+                    .filter_map(|a| Some((a.name.clone()?, a.value.clone()?)))
+                    .collect::<Vec<_>>();
+                user_pool.check_user_attributes(
+                    &attributes,
+                    super::AttributeWrite::Update { admin: true },
+                )?;
+                user_pool.update_user_attributes(
+                    self.username.as_deref().unwrap_or_default(),
+                    &attributes,
+                )
+            })
+            .map_err(super::to_response_error)?;
+            codes = super::delivery_codes(&deliveries);
+            Ok(())
+        });
+        super::with_code_header(response, codes)
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let attributes = self.user_attributes.as_ref()?;
+        super::check_writable_attributes(attributes.iter().filter_map(|a| a.name.as_deref())).map(
+            |message| {
+                (
+                    AdminUpdateUserAttributesError::InvalidParameterException,
+                    message,
+                )
+            },
+        )
     }
 }
 
@@ -88,7 +126,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AdminUpdateUserAttributesError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -0,0 +1,258 @@
+use super::{StoreError, StoreResult, UserPoolState, UserState};
+
+/// Attributes which can be aliases of usernames.
+pub const ALIAS_ATTRIBUTES: [&str; 3] = ["email", "phone_number", "preferred_username"];
+
+/// Returns whether an alias attribute of a user is active, i.e. verified unless it is `preferred_username`.
+fn is_active_alias(attributes: &[(String, String)], name: &str) -> bool {
+    name == "preferred_username"
+        || attributes
+            .iter()
+            .any(|(n, v)| *n == format!("{}_verified", name) && v == "true")
+}
+
+fn alias_exists(name: &str) -> (StoreError, String) {
+    let attribute = match name {
+        "phone_number" => "phone number",
+        "preferred_username" => "preferred username",
+        _ => "email",
+    };
+    (
+        StoreError::AliasExistsException,
+        format!("An account with the given {} already exists.", attribute),
+    )
+}
+
+impl UserPoolState {
+    /// Returns `UsernameAttributes` of the user pool, with which users sign in instead of usernames.
+    pub fn username_attributes(&self) -> Vec<String> {
+        self.described_attributes("/UsernameAttributes")
+    }
+
+    /// Returns `AliasAttributes` of the user pool, with which users sign in besides usernames.
+    pub fn alias_attributes(&self) -> Vec<String> {
+        self.described_attributes("/AliasAttributes")
+    }
+
+    /// Returns `UsernameConfiguration.CaseSensitive` of the user pool (default `true`).
+    pub fn case_sensitive_usernames(&self) -> bool {
+        self.description
+            .pointer("/UsernameConfiguration/CaseSensitive")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true)
+    }
+
+    fn same_name(&self, a: &str, b: &str) -> bool {
+        if self.case_sensitive_usernames() {
+            a == b
+        } else {
+            a.to_lowercase() == b.to_lowercase()
+        }
+    }
+
+    /// Returns the username of a user signed in by the username, an username attribute, an alias
+    /// or a linked federated identity.
+    pub fn resolve_username(&self, name: &str) -> Option<String> {
+        if self.users.contains_key(name) {
+            return Some(name.to_string());
+        }
+        if let Some(username) = self.users.keys().find(|u| self.same_name(u, name)) {
+            return Some(username.clone());
+        }
+        let username_attributes = self.username_attributes();
+        let alias_attributes = self.alias_attributes();
+        self.users
+            .values()
+            .find(|user| {
+                user.attributes.iter().any(|(n, v)| {
+                    self.same_name(v, name)
+                        && (username_attributes.contains(n)
+                            || (alias_attributes.contains(n)
+                                && is_active_alias(&user.attributes, n)))
+                })
+            })
+            .map(|user| user.username.clone())
+            .or_else(|| self.linked_username(name))
+    }
+
+    /// Gives a new user an username attribute and a generated username if the user pool signs in by them.
+    ///
+    /// Federated users keep their usernames (e.g. `Google_1234`).
+    pub fn assign_username(&self, user: &mut UserState) -> StoreResult<()> {
+        let username_attributes = self.username_attributes();
+        if username_attributes.is_empty() || user.status == super::UserStatus::ExternalProvider {
+            if self.users.keys().any(|u| self.same_name(u, &user.username)) {
+                return Err((
+                    StoreError::UsernameExistsException,
+                    "User account already exists".to_string(),
+                ));
+            }
+            return Ok(());
+        }
+        let name = if user.username.contains('@') {
+            "email"
+        } else {
+            "phone_number"
+        };
+        if !username_attributes.iter().any(|a| a == name) {
+            return Err((
+                StoreError::InvalidParameterException,
+                format!(
+                    "Username should be {}.",
+                    username_attributes
+                        .iter()
+                        .map(|a| if a == "email" {
+                            "an email"
+                        } else {
+                            "a phone number"
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+            ));
+        }
+        if self.resolve_username(&user.username).is_some() {
+            return Err((
+                StoreError::UsernameExistsException,
+                format!(
+                    "An account with the given {} already exists.",
+                    if name == "email" {
+                        "email"
+                    } else {
+                        "phone_number"
+                    }
+                ),
+            ));
+        }
+        let value = user.username.clone();
+        if user.attribute(name).is_none() {
+            user.set_attribute(name, &value);
+        }
+        user.username = user.sub.clone();
+        Ok(())
+    }
+
+    /// Takes the active aliases among attributes of a user from other users.
+    ///
+    /// Fails with `AliasExistsException` if another user has one of them, unless `force` is given.
+    pub fn claim_aliases(
+        &mut self,
+        username: &str,
+        attributes: &[(String, String)],
+        force: bool,
+    ) -> StoreResult<()> {
+        let alias_attributes = self.alias_attributes();
+        let aliases = attributes
+            .iter()
+            .filter(|(n, _)| alias_attributes.contains(n) && is_active_alias(attributes, n))
+            .collect::<Vec<_>>();
+        let mut taken = vec![];
+        for (name, value) in aliases {
+            for user in self.users.values().filter(|u| u.username != username) {
+                let used = user
+                    .attribute(name)
+                    .is_some_and(|v| self.same_name(v, value))
+                    && is_active_alias(&user.attributes, name);
+                if used && !force {
+                    return Err(alias_exists(name));
+                }
+                if used {
+                    taken.push((user.username.clone(), name.clone()));
+                }
+            }
+        }
+        for (other, name) in taken {
+            if let Some(user) = self.users.get_mut(&other) {
+                if name == "preferred_username" {
+                    user.remove_attribute(&name);
+                } else {
+                    user.set_attribute(&format!("{}_verified", name), "false");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::UserStatus;
+    use pretty_assertions::assert_eq;
+
+    fn user_pool(description: serde_json::Value) -> UserPoolState {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool.description = description;
+        user_pool
+    }
+
+    fn attributes(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(n, v)| (n.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn users_are_resolved_by_username_attributes() {
+        let mut user_pool = user_pool(serde_json::json!({
+            "UsernameAttributes": ["email"],
+            "UsernameConfiguration": { "CaseSensitive": false }
+        }));
+        let user = user_pool
+            .add_user(UserState::new("User@example.com", UserStatus::Confirmed))
+            .unwrap();
+        assert_eq!(user.sub, user.username);
+        assert_eq!(Some("User@example.com"), user.attribute("email"));
+        let username = user.username.clone();
+
+        assert_eq!(
+            Some(username),
+            user_pool.resolve_username("user@example.com")
+        );
+        let mut other = UserState::new("user@example.com", UserStatus::Confirmed);
+        assert_eq!(
+            Some(StoreError::UsernameExistsException),
+            user_pool.assign_username(&mut other).err().map(|(e, _)| e)
+        );
+        let mut other = UserState::new("user", UserStatus::Confirmed);
+        assert_eq!(
+            Some(StoreError::InvalidParameterException),
+            user_pool.assign_username(&mut other).err().map(|(e, _)| e)
+        );
+    }
+
+    #[test]
+    fn users_are_resolved_by_verified_aliases() {
+        let mut user_pool = user_pool(serde_json::json!({ "AliasAttributes": ["email"] }));
+        let mut user = UserState::new("user", UserStatus::Confirmed);
+        user.set_attribute("email", "user@example.com");
+        user_pool.add_user(user).unwrap();
+        assert_eq!(None, user_pool.resolve_username("user@example.com"));
+
+        let verified = attributes(&[("email", "user@example.com"), ("email_verified", "true")]);
+        user_pool.claim_aliases("user", &verified, false).unwrap();
+        user_pool
+            .user_mut("user")
+            .unwrap()
+            .set_attribute("email_verified", "true");
+        assert_eq!(
+            Some("user".to_string()),
+            user_pool.resolve_username("user@example.com")
+        );
+        assert_eq!(None, user_pool.resolve_username("USER"));
+
+        assert_eq!(
+            Some(StoreError::AliasExistsException),
+            user_pool
+                .claim_aliases("other", &verified, false)
+                .err()
+                .map(|(e, _)| e)
+        );
+        user_pool.claim_aliases("other", &verified, true).unwrap();
+        assert_eq!(
+            Some("false"),
+            user_pool.user("user").unwrap().attribute("email_verified")
+        );
+    }
+}
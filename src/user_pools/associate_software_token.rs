@@ -54,7 +54,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = AssociateSoftwareTokenError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
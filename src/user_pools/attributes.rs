@@ -0,0 +1,438 @@
+use super::{SchemaAttributeType, StoreError, StoreResult, UserPoolState};
+
+/// Standard attributes in every user pool schema.
+pub const STANDARD_ATTRIBUTES: [&str; 20] = [
+    "address",
+    "birthdate",
+    "email",
+    "email_verified",
+    "family_name",
+    "gender",
+    "given_name",
+    "locale",
+    "middle_name",
+    "name",
+    "nickname",
+    "phone_number",
+    "phone_number_verified",
+    "picture",
+    "preferred_username",
+    "profile",
+    "sub",
+    "updated_at",
+    "website",
+    "zoneinfo",
+];
+
+/// Prefix of custom attribute names.
+pub const CUSTOM_ATTRIBUTE_PREFIX: &str = "custom:";
+
+/// Prefix of developer only attribute names.
+pub const DEVELOPER_ONLY_ATTRIBUTE_PREFIX: &str = "dev:custom:";
+
+/// Maximum number of custom attributes in a user pool.
+pub const MAX_CUSTOM_ATTRIBUTES: usize = 50;
+
+const IMMUTABLE_STANDARD_ATTRIBUTES: [&str; 1] = ["sub"];
+const CSV_HEADER_COGNITO_COLUMNS: [&str; 2] = ["cognito:mfa_enabled", "cognito:username"];
+
+/// Returns the columns of a user import CSV file for the standard schema.
+pub fn csv_header() -> Vec<String> {
+    STANDARD_ATTRIBUTES
+        .iter()
+        .filter(|name| !IMMUTABLE_STANDARD_ATTRIBUTES.contains(name))
+        .chain(CSV_HEADER_COGNITO_COLUMNS.iter())
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Returns a schema violation message for the first attribute which cannot be written.
+pub fn check_writable_attributes<'a, I>(names: I) -> Option<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    names.into_iter().find_map(|name| {
+        if IMMUTABLE_STANDARD_ATTRIBUTES.contains(&name) {
+            Some(schema_error(
+                name,
+                "Attribute cannot be updated. (changing an immutable attribute)",
+            ))
+        } else if !is_known_attribute(name) {
+            Some(schema_error(
+                name,
+                "Attribute does not exist in the schema.",
+            ))
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns a message for the first duplicated name or custom attributes over the limit.
+pub fn check_custom_attribute_names<'a, I>(names: I, existing_count: usize) -> Option<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut custom_names: Vec<&str> = vec![];
+    for name in names {
+        if custom_names.contains(&name) {
+            return Some(format!(
+                "Existing attribute already has name {}{}.",
+                CUSTOM_ATTRIBUTE_PREFIX, name
+            ));
+        }
+        custom_names.push(name);
+    }
+    if existing_count + custom_names.len() > MAX_CUSTOM_ATTRIBUTES {
+        return Some(format!(
+            "User pool cannot have more than {} custom attributes.",
+            MAX_CUSTOM_ATTRIBUTES
+        ));
+    }
+    None
+}
+
+/// Returns the name of an attribute in a user pool schema (e.g. `custom:tenant`).
+pub fn schema_attribute_name(attribute: &SchemaAttributeType) -> String {
+    let name = attribute.name.as_deref().unwrap_or_default();
+    if STANDARD_ATTRIBUTES.contains(&name) || is_prefixed_custom_attribute(name) {
+        name.to_string()
+    } else if attribute.developer_only_attribute == Some(true) {
+        format!("{}{}", DEVELOPER_ONLY_ATTRIBUTE_PREFIX, name)
+    } else {
+        format!("{}{}", CUSTOM_ATTRIBUTE_PREFIX, name)
+    }
+}
+
+/// Write of user attributes checked against a user pool schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeWrite {
+    /// Attributes of a new user, by an administrator or not.
+    Create { admin: bool },
+    /// Attributes of an existing user, by an administrator or not.
+    Update { admin: bool },
+}
+
+impl UserPoolState {
+    /// Returns the schema attributes given by CreateUserPool and AddCustomAttributes.
+    pub fn schema_attributes(&self) -> Vec<SchemaAttributeType> {
+        self.description
+            .get("SchemaAttributes")
+            .and_then(|schema| serde_json::from_value(schema.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns the columns of a user import CSV file for the schema of the user pool.
+    pub fn csv_header(&self) -> Vec<String> {
+        let custom_attributes = self
+            .schema_attributes()
+            .into_iter()
+            .filter_map(|a| a.name)
+            .filter(|name| !STANDARD_ATTRIBUTES.contains(&name.as_str()));
+        let mut header = csv_header();
+        let cognito_columns = header.split_off(header.len() - CSV_HEADER_COGNITO_COLUMNS.len());
+        header.extend(custom_attributes);
+        header.extend(cognito_columns);
+        header
+    }
+
+    fn schema_attribute(&self, name: &str) -> Option<SchemaAttributeType> {
+        self.schema_attributes()
+            .into_iter()
+            .find(|a| a.name.as_deref() == Some(name))
+    }
+
+    /// Adds custom attributes to the schema of the user pool.
+    pub fn add_custom_attributes(&mut self, attributes: &[SchemaAttributeType]) -> StoreResult<()> {
+        let existing = self.schema_attributes();
+        let custom_names = existing
+            .iter()
+            .chain(attributes.iter())
+            .filter_map(|a| a.name.as_deref())
+            .filter(|name| {
+                is_prefixed_custom_attribute(name) || !STANDARD_ATTRIBUTES.contains(name)
+            })
+            .map(|name| {
+                name.trim_start_matches(DEVELOPER_ONLY_ATTRIBUTE_PREFIX)
+                    .trim_start_matches(CUSTOM_ATTRIBUTE_PREFIX)
+            })
+            .collect::<Vec<_>>();
+        if let Some(message) = check_custom_attribute_names(custom_names, 0) {
+            return Err((StoreError::InvalidParameterException, message));
+        }
+        let added = attributes.iter().map(|a| {
+            super::request_value(&SchemaAttributeType {
+                name: Some(schema_attribute_name(a)),
+                ..a.clone()
+            })
+        });
+        match self.description.get_mut("SchemaAttributes") {
+            Some(serde_json::Value::Array(schema)) => schema.extend(added),
+            _ => self.description["SchemaAttributes"] = added.collect(),
+        }
+        Ok(())
+    }
+
+    /// Returns an error if attributes written to a user do not conform to the schema.
+    pub fn check_user_attributes(
+        &self,
+        attributes: &[(String, String)],
+        write: AttributeWrite,
+    ) -> StoreResult<()> {
+        let schema = self.schema_attributes();
+        let invalid = |name: &str, reason: &str| {
+            Err((
+                StoreError::InvalidParameterException,
+                schema_error(name, reason),
+            ))
+        };
+        for (name, value) in attributes {
+            let attribute = schema.iter().find(|a| a.name.as_deref() == Some(name));
+            if attribute.is_none() && !STANDARD_ATTRIBUTES.contains(&name.as_str()) {
+                return invalid(name, "Attribute does not exist in the schema.");
+            }
+            let admin = match write {
+                AttributeWrite::Create { admin } | AttributeWrite::Update { admin } => admin,
+            };
+            if !admin && name.starts_with(DEVELOPER_ONLY_ATTRIBUTE_PREFIX) {
+                return invalid(name, "A client attempted to write unauthorized attribute");
+            }
+            let Some(attribute) = attribute else {
+                continue;
+            };
+            if matches!(write, AttributeWrite::Update { .. }) && attribute.mutable == Some(false) {
+                return invalid(
+                    name,
+                    "Attribute cannot be updated. (changing an immutable attribute)",
+                );
+            }
+            if let Some(reason) = constraint_violation(attribute, value) {
+                return invalid(name, &reason);
+            }
+        }
+        if matches!(write, AttributeWrite::Create { .. }) {
+            let missing = schema
+                .iter()
+                .filter(|a| a.required == Some(true))
+                .find_map(|a| {
+                    a.name
+                        .as_deref()
+                        .filter(|name| !attributes.iter().any(|(n, _)| n == name))
+                });
+            if let Some(name) = missing {
+                return invalid(name, "The attribute is required");
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if attributes removed from a user are required or immutable.
+    pub fn check_removed_user_attributes<'a, I>(&self, names: I) -> StoreResult<()>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        for name in names {
+            match self.schema_attribute(name) {
+                Some(a) if a.required == Some(true) => {
+                    return Err((
+                        StoreError::InvalidParameterException,
+                        schema_error(name, "The attribute is required"),
+                    ))
+                }
+                Some(a) if a.mutable == Some(false) => {
+                    return Err((
+                        StoreError::InvalidParameterException,
+                        schema_error(
+                            name,
+                            "Attribute cannot be updated. (changing an immutable attribute)",
+                        ),
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn constraint_violation(attribute: &SchemaAttributeType, value: &str) -> Option<String> {
+    let bound = |bound: &Option<String>| bound.as_deref().and_then(|b| b.parse::<i64>().ok());
+    match attribute.attribute_data_type.as_deref() {
+        Some("Number") => {
+            let constraints = attribute.number_attribute_constraints.as_ref();
+            let number = match value.parse::<i64>() {
+                Ok(number) => number,
+                Err(_) => return Some("Number must be a valid integer".to_string()),
+            };
+            if let Some(min) = constraints.and_then(|c| bound(&c.min_value)) {
+                if number < min {
+                    return Some(format!("Number must be no less than {}", min));
+                }
+            }
+            if let Some(max) = constraints.and_then(|c| bound(&c.max_value)) {
+                if number > max {
+                    return Some(format!("Number must be no greater than {}", max));
+                }
+            }
+            None
+        }
+        Some("Boolean") if value != "true" && value != "false" => {
+            Some("Boolean must be true or false".to_string())
+        }
+        _ => {
+            let constraints = attribute.string_attribute_constraints.as_ref();
+            let length = value.chars().count() as i64;
+            if let Some(min) = constraints.and_then(|c| bound(&c.min_length)) {
+                if length < min {
+                    return Some(format!("String must be no shorter than {} characters", min));
+                }
+            }
+            if let Some(max) = constraints.and_then(|c| bound(&c.max_length)) {
+                if length > max {
+                    return Some(format!("String must be no longer than {} characters", max));
+                }
+            }
+            None
+        }
+    }
+}
+
+fn is_prefixed_custom_attribute(name: &str) -> bool {
+    name.starts_with(CUSTOM_ATTRIBUTE_PREFIX) || name.starts_with(DEVELOPER_ONLY_ATTRIBUTE_PREFIX)
+}
+
+fn is_known_attribute(name: &str) -> bool {
+    STANDARD_ATTRIBUTES.contains(&name) || is_prefixed_custom_attribute(name)
+}
+
+fn schema_error(name: &str, reason: &str) -> String {
+    format!(
+        "Attributes did not conform to the schema: {}: {}",
+        name, reason
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn writable_attributes() {
+        assert_eq!(
+            None,
+            check_writable_attributes(["email", "custom:tenant", "dev:custom:flag"])
+        );
+    }
+
+    #[test]
+    fn unknown_or_immutable_attributes_are_not_writable() {
+        assert_eq!(
+            Some(
+                "Attributes did not conform to the schema: tenant: Attribute does not exist in the schema."
+                    .to_string()
+            ),
+            check_writable_attributes(["email", "tenant"])
+        );
+        assert!(check_writable_attributes(["sub"]).is_some());
+    }
+
+    #[test]
+    fn csv_header_has_writable_attributes_and_username() {
+        let header = csv_header();
+        assert_eq!(Some(&"address".to_string()), header.first());
+        assert_eq!(Some(&"cognito:username".to_string()), header.last());
+        assert!(!header.contains(&"sub".to_string()));
+        assert_eq!(STANDARD_ATTRIBUTES.len() + 1, header.len());
+    }
+
+    fn user_pool() -> UserPoolState {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool.description = serde_json::json!({
+            "SchemaAttributes": [
+                { "Name": "email", "Required": true },
+                { "Name": "custom:tenant", "Mutable": false,
+                  "StringAttributeConstraints": { "MaxLength": "5" } },
+                { "Name": "custom:age", "AttributeDataType": "Number",
+                  "NumberAttributeConstraints": { "MinValue": "18" } },
+            ]
+        });
+        user_pool
+    }
+
+    fn attributes(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(n, v)| (n.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn csv_header_has_custom_attributes_of_user_pool() {
+        let header = user_pool().csv_header();
+        assert_eq!(
+            vec![
+                "custom:tenant",
+                "custom:age",
+                "cognito:mfa_enabled",
+                "cognito:username"
+            ],
+            header[header.len() - 4..]
+        );
+    }
+
+    #[test]
+    fn user_attributes_conform_to_the_schema() {
+        let user_pool = user_pool();
+        let create = AttributeWrite::Create { admin: false };
+        assert_eq!(
+            Ok(()),
+            user_pool.check_user_attributes(
+                &attributes(&[("email", "a@example.com"), ("custom:tenant", "acme")]),
+                create
+            )
+        );
+        let errors = [
+            (vec![("custom:tenant", "acme")], create),
+            (vec![("email", "a"), ("custom:unknown", "a")], create),
+            (vec![("email", "a"), ("custom:tenant", "acme-corp")], create),
+            (vec![("email", "a"), ("custom:age", "17")], create),
+            (
+                vec![("custom:tenant", "acme")],
+                AttributeWrite::Update { admin: true },
+            ),
+        ];
+        for (pairs, write) in errors {
+            assert_eq!(
+                Some(StoreError::InvalidParameterException),
+                user_pool
+                    .check_user_attributes(&attributes(&pairs), write)
+                    .err()
+                    .map(|(e, _)| e),
+                "{:?}",
+                pairs
+            );
+        }
+    }
+
+    #[test]
+    fn custom_attributes_are_added_once() {
+        let mut user_pool = user_pool();
+        let attribute = SchemaAttributeType {
+            name: Some("plan".to_string()),
+            ..Default::default()
+        };
+        user_pool
+            .add_custom_attributes(std::slice::from_ref(&attribute))
+            .unwrap();
+        assert!(user_pool.schema_attribute("custom:plan").is_some());
+        assert!(user_pool.add_custom_attributes(&[attribute]).is_err());
+    }
+
+    #[test]
+    fn custom_attribute_names_are_unique_and_limited() {
+        assert_eq!(None, check_custom_attribute_names(["a", "b"], 0));
+        assert!(check_custom_attribute_names(["a", "a"], 0).is_some());
+        assert!(check_custom_attribute_names(["a"], MAX_CUSTOM_ATTRIBUTES).is_some());
+    }
+}
@@ -0,0 +1,304 @@
+use super::{AuthOutcome, RiskDecision, StoreError, StoreResult, UserPoolState};
+use crate::common;
+use chrono::{DateTime, Utc};
+use strum_macros::Display;
+
+const USER_AGENT_HEADER: &str = "user-agent";
+
+/// Type of an auth event.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+pub enum AuthEventType {
+    SignIn,
+    SignUp,
+    ForgotPassword,
+}
+
+/// Response of an auth event.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+pub enum EventResponse {
+    Pass,
+    Fail,
+    InProgress,
+}
+
+impl EventResponse {
+    fn of<T>(result: &StoreResult<T>) -> Self {
+        match result {
+            Ok(_) => EventResponse::Pass,
+            Err(_) => EventResponse::Fail,
+        }
+    }
+}
+
+/// Context of a request which caused an auth event (`EventContextData`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventContext {
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+}
+
+impl EventContext {
+    /// Returns the context of `ContextData` of admin actions.
+    pub fn from_context_data(context_data: Option<&super::ContextDataType>) -> Self {
+        let Some(context_data) = context_data else {
+            return EventContext::default();
+        };
+        let device_name = context_data
+            .http_headers
+            .iter()
+            .flatten()
+            .find(|h| {
+                h.header_name
+                    .as_deref()
+                    .is_some_and(|n| n.eq_ignore_ascii_case(USER_AGENT_HEADER))
+            })
+            .and_then(|h| h.header_value.clone());
+        EventContext {
+            ip_address: context_data.ip_address.clone(),
+            device_name,
+        }
+    }
+
+    /// Returns the context of `UserContextData` of public actions.
+    pub fn from_user_context_data(user_context_data: Option<&super::UserContextDataType>) -> Self {
+        EventContext {
+            ip_address: user_context_data.and_then(|c| c.ip_address.clone()),
+            device_name: None,
+        }
+    }
+}
+
+/// Auth event of a user listed by AdminListUserAuthEvents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthEventState {
+    pub event_id: String,
+    pub event_type: AuthEventType,
+    pub event_response: EventResponse,
+    pub created_at: DateTime<Utc>,
+    pub context: EventContext,
+    /// Results of the challenges answered in the event by the challenge names.
+    pub challenge_responses: Vec<(String, bool)>,
+    pub risk_decision: RiskDecision,
+    pub compromised_credentials_detected: bool,
+    /// `FeedbackValue` and the date of the feedback given by AdminUpdateAuthEventFeedback.
+    pub feedback: Option<(String, DateTime<Utc>)>,
+}
+
+impl AuthEventState {
+    pub fn new(event_type: AuthEventType, context: EventContext) -> Self {
+        AuthEventState {
+            event_id: common::new_uuid(),
+            event_type,
+            event_response: EventResponse::InProgress,
+            created_at: Utc::now(),
+            context,
+            challenge_responses: Vec::new(),
+            risk_decision: RiskDecision::NoRisk,
+            compromised_credentials_detected: false,
+            feedback: None,
+        }
+    }
+
+    /// Returns `AuthEventType` of the response of AdminListUserAuthEvents.
+    pub fn to_value(&self) -> serde_json::Value {
+        let mut context = serde_json::Map::new();
+        if let Some(ip_address) = self.context.ip_address.as_deref() {
+            context.insert("IpAddress".to_string(), ip_address.into());
+        }
+        if let Some(device_name) = self.context.device_name.as_deref() {
+            context.insert("DeviceName".to_string(), device_name.into());
+        }
+        let mut value = serde_json::json!({
+            "ChallengeResponses": self
+                .challenge_responses
+                .iter()
+                .map(|(name, success)| serde_json::json!({
+                    "ChallengeName": name,
+                    "ChallengeResponse": if *success { "Success" } else { "Failure" },
+                }))
+                .collect::<Vec<_>>(),
+            "CreationDate": self.created_at.timestamp(),
+            "EventContextData": context,
+            "EventId": self.event_id,
+            "EventResponse": self.event_response.to_string(),
+            "EventRisk": {
+                "CompromisedCredentialsDetected": self.compromised_credentials_detected,
+                "RiskDecision": self.risk_decision.to_string(),
+                "RiskLevel": self.risk_decision.risk_level(),
+            },
+            "EventType": self.event_type.to_string(),
+        });
+        if let Some((feedback_value, feedback_date)) = self.feedback.as_ref() {
+            value["EventFeedback"] = serde_json::json!({
+                "FeedbackDate": feedback_date.timestamp(),
+                "FeedbackValue": feedback_value,
+                "Provider": "Admin",
+            });
+        }
+        value
+    }
+}
+
+impl UserPoolState {
+    /// Records an auth event of a user, ignoring unknown users.
+    pub fn record_auth_event(&mut self, username: &str, event: AuthEventState) {
+        if let Ok(user) = self.user_mut(username) {
+            user.auth_events.insert(0, event);
+        }
+    }
+
+    /// Records a sign-in event with the outcome of its auth flow.
+    ///
+    /// Tokens withheld by the risk decision leave the event in progress.
+    pub fn record_sign_in(
+        &mut self,
+        username: &str,
+        outcome: &StoreResult<AuthOutcome>,
+        mut event: AuthEventState,
+    ) {
+        event.event_response = match outcome {
+            Ok(AuthOutcome::Authenticated(_)) if event.risk_decision == RiskDecision::NoRisk => {
+                EventResponse::Pass
+            }
+            Ok(_) => EventResponse::InProgress,
+            Err(_) => EventResponse::Fail,
+        };
+        self.record_auth_event(username, event);
+    }
+
+    /// Records the response to a challenge in the sign-in event in progress of a user.
+    pub fn record_challenge_response(
+        &mut self,
+        username: &str,
+        challenge_name: &str,
+        outcome: &StoreResult<AuthOutcome>,
+    ) {
+        let Ok(user) = self.user_mut(username) else {
+            return;
+        };
+        let event = user.auth_events.iter_mut().find(|e| {
+            e.event_type == AuthEventType::SignIn && e.event_response == EventResponse::InProgress
+        });
+        if let Some(event) = event {
+            event
+                .challenge_responses
+                .push((challenge_name.to_string(), outcome.is_ok()));
+            event.event_response = match outcome {
+                Ok(AuthOutcome::Authenticated(_)) => EventResponse::Pass,
+                Ok(AuthOutcome::Challenge { .. }) => EventResponse::InProgress,
+                Err(_) => EventResponse::Fail,
+            };
+        }
+    }
+
+    /// Records an event which passes or fails at once (e.g. `SignUp` and `ForgotPassword`).
+    pub fn record_completed_event<T>(
+        &mut self,
+        username: &str,
+        event_type: AuthEventType,
+        result: &StoreResult<T>,
+        context: EventContext,
+    ) {
+        let mut event = AuthEventState::new(event_type, context);
+        event.event_response = EventResponse::of(result);
+        self.record_auth_event(username, event);
+    }
+
+    /// Sets the feedback of an auth event (AdminUpdateAuthEventFeedback).
+    pub fn update_auth_event_feedback(
+        &mut self,
+        username: &str,
+        event_id: &str,
+        feedback_value: &str,
+    ) -> StoreResult<()> {
+        let event = self
+            .user_mut(username)?
+            .auth_events
+            .iter_mut()
+            .find(|e| e.event_id == event_id)
+            .ok_or_else(|| {
+                (
+                    StoreError::ResourceNotFoundException,
+                    "Auth event not found.".to_string(),
+                )
+            })?;
+        event.feedback = Some((feedback_value.to_string(), Utc::now()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{UserState, UserStatus};
+    use pretty_assertions::assert_eq;
+
+    fn user_pool() -> UserPoolState {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_user(UserState::new("user", UserStatus::Confirmed))
+            .unwrap();
+        user_pool
+    }
+
+    #[test]
+    fn sign_in_event_records_challenge_responses() {
+        let mut user_pool = user_pool();
+        let challenge = Ok(AuthOutcome::Challenge {
+            name: "NEW_PASSWORD_REQUIRED".to_string(),
+            parameters: serde_json::json!({}),
+            session: "session".to_string(),
+        });
+        let context = EventContext {
+            ip_address: Some("192.0.2.1".to_string()),
+            device_name: None,
+        };
+        let event = AuthEventState::new(AuthEventType::SignIn, context);
+        user_pool.record_sign_in("user", &challenge, event);
+        user_pool.record_challenge_response(
+            "user",
+            "NEW_PASSWORD_REQUIRED",
+            &Err((StoreError::InvalidPasswordException, String::new())),
+        );
+
+        let event = user_pool.user("user").unwrap().auth_events[0].to_value();
+        assert_eq!("SignIn", event["EventType"]);
+        assert_eq!("Fail", event["EventResponse"]);
+        assert_eq!("192.0.2.1", event["EventContextData"]["IpAddress"]);
+        assert_eq!(
+            serde_json::json!([{
+                "ChallengeName": "NEW_PASSWORD_REQUIRED",
+                "ChallengeResponse": "Failure"
+            }]),
+            event["ChallengeResponses"]
+        );
+        assert_eq!("Low", event["EventRisk"]["RiskLevel"]);
+    }
+
+    #[test]
+    fn feedback_updates_auth_event() {
+        let mut user_pool = user_pool();
+        user_pool.record_completed_event(
+            "user",
+            AuthEventType::SignUp,
+            &Ok(()),
+            EventContext::default(),
+        );
+        let event_id = user_pool.user("user").unwrap().auth_events[0]
+            .event_id
+            .clone();
+
+        assert_eq!(
+            Err(StoreError::ResourceNotFoundException),
+            user_pool
+                .update_auth_event_feedback("user", "unknown", "Valid")
+                .map_err(|(e, _)| e)
+        );
+        user_pool
+            .update_auth_event_feedback("user", &event_id, "Invalid")
+            .unwrap();
+        let event = user_pool.user("user").unwrap().auth_events[0].to_value();
+        assert_eq!("Pass", event["EventResponse"]);
+        assert_eq!("Invalid", event["EventFeedback"]["FeedbackValue"]);
+    }
+}
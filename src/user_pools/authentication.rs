@@ -0,0 +1,964 @@
+use super::{
+    srp, AuthEventState, AuthEventType, AuthenticationResult, EventContext, RiskContext,
+    RiskDecision, StoreError, StoreResult, UserPoolState, UserStatus, BLOCKED_BY_RISK_MESSAGE,
+    DEVICE_PASSWORD_VERIFIER_CHALLENGE, DEVICE_SRP_AUTH_CHALLENGE,
+};
+use crate::common::{self, ALPHANUMERIC};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::HashMap;
+
+/// Challenge of a user who signs in with a temporary password.
+pub const NEW_PASSWORD_REQUIRED_CHALLENGE: &str = "NEW_PASSWORD_REQUIRED";
+
+/// Challenge of a user whose sign-in is at risk of account takeover.
+pub const SMS_MFA_CHALLENGE: &str = "SMS_MFA";
+
+const USERNAME_PARAMETER: &str = "USERNAME";
+const PASSWORD_PARAMETER: &str = "PASSWORD";
+const REFRESH_TOKEN_PARAMETER: &str = "REFRESH_TOKEN";
+const SECRET_HASH_PARAMETER: &str = "SECRET_HASH";
+const NEW_PASSWORD_PARAMETER: &str = "NEW_PASSWORD";
+const DEVICE_KEY_PARAMETER: &str = "DEVICE_KEY";
+const SRP_A_PARAMETER: &str = "SRP_A";
+const SRP_B_VALUE: &str = "SRP_b";
+const SECRET_BLOCK_PARAMETER: &str = "SECRET_BLOCK";
+const PASSWORD_CLAIM_SECRET_BLOCK_PARAMETER: &str = "PASSWORD_CLAIM_SECRET_BLOCK";
+const PASSWORD_CLAIM_SIGNATURE_PARAMETER: &str = "PASSWORD_CLAIM_SIGNATURE";
+const TIMESTAMP_PARAMETER: &str = "TIMESTAMP";
+const SMS_MFA_CODE_PARAMETER: &str = "SMS_MFA_CODE";
+const CODE_VALUE: &str = "CODE";
+const MFA_REQUIRED_VALUE: &str = "MFA_REQUIRED";
+const USER_ATTRIBUTE_PREFIX: &str = "userAttributes.";
+const SESSION_LENGTH: usize = 256;
+const CHALLENGE_VALIDITY_MINUTES: i64 = 3;
+
+/// Auth flows enabled for app clients without `ExplicitAuthFlows`.
+const DEFAULT_EXPLICIT_AUTH_FLOWS: [&str; 3] = [
+    "ALLOW_CUSTOM_AUTH",
+    "ALLOW_REFRESH_TOKEN_AUTH",
+    "ALLOW_USER_SRP_AUTH",
+];
+
+/// Tokens or a challenge as the result of an authentication step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthOutcome {
+    Authenticated(AuthenticationResult),
+    Challenge {
+        name: String,
+        parameters: serde_json::Value,
+        session: String,
+    },
+}
+
+impl AuthOutcome {
+    /// Replaces the result and the challenge in a response rendered from the template.
+    pub fn to_value(&self, mut value: serde_json::Value) -> serde_json::Value {
+        if let Some(response) = value.as_object_mut() {
+            for key in [
+                "AuthenticationResult",
+                "ChallengeName",
+                "ChallengeParameters",
+                "Session",
+            ] {
+                response.remove(key);
+            }
+            match self {
+                AuthOutcome::Authenticated(result) => {
+                    response.insert("AuthenticationResult".to_string(), result.to_value());
+                    response.insert("ChallengeParameters".to_string(), serde_json::json!({}));
+                }
+                AuthOutcome::Challenge {
+                    name,
+                    parameters,
+                    session,
+                } => {
+                    response.insert("ChallengeName".to_string(), name.as_str().into());
+                    response.insert("ChallengeParameters".to_string(), parameters.clone());
+                    response.insert("Session".to_string(), session.as_str().into());
+                }
+            }
+        }
+        value
+    }
+}
+
+/// Returns `SECRET_HASH` of a user for an app client with a secret.
+pub fn secret_hash(client_secret: &str, username: &str, client_id: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(client_secret.as_bytes())
+        .expect("HMAC can take key of any size");
+    mac.update(format!("{}{}", username, client_id).as_bytes());
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+fn not_authorized(message: &str) -> (StoreError, String) {
+    (StoreError::NotAuthorizedException, message.to_string())
+}
+
+fn missing_parameter(name: &str) -> (StoreError, String) {
+    (
+        StoreError::InvalidParameterException,
+        format!("Missing required parameter {}", name),
+    )
+}
+
+fn parameter<'a>(parameters: &'a HashMap<String, String>, name: &str) -> StoreResult<&'a str> {
+    parameters
+        .get(name)
+        .map(String::as_str)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| missing_parameter(name))
+}
+
+impl super::ClientState {
+    /// Returns true if the app client allows an auth flow by `ExplicitAuthFlows`.
+    pub fn allows_auth_flow(&self, auth_flow: &str) -> bool {
+        let allowed = match auth_flow {
+            "ADMIN_NO_SRP_AUTH" | "ADMIN_USER_PASSWORD_AUTH" => {
+                ["ALLOW_ADMIN_USER_PASSWORD_AUTH", "ADMIN_NO_SRP_AUTH"].as_slice()
+            }
+            "USER_PASSWORD_AUTH" => ["ALLOW_USER_PASSWORD_AUTH", "USER_PASSWORD_AUTH"].as_slice(),
+            "USER_SRP_AUTH" => ["ALLOW_USER_SRP_AUTH"].as_slice(),
+            "CUSTOM_AUTH" => ["ALLOW_CUSTOM_AUTH", "CUSTOM_AUTH_FLOW_ONLY"].as_slice(),
+            "REFRESH_TOKEN_AUTH" | "REFRESH_TOKEN" => {
+                // Clients with the legacy flow names only can always refresh tokens.
+                if !self.explicit_auth_flows.is_empty()
+                    && !self
+                        .explicit_auth_flows
+                        .iter()
+                        .any(|f| f.starts_with("ALLOW_"))
+                {
+                    return true;
+                }
+                ["ALLOW_REFRESH_TOKEN_AUTH"].as_slice()
+            }
+            _ => return false,
+        };
+        if self.explicit_auth_flows.is_empty() {
+            return DEFAULT_EXPLICIT_AUTH_FLOWS
+                .iter()
+                .any(|f| allowed.contains(f));
+        }
+        self.explicit_auth_flows
+            .iter()
+            .any(|f| allowed.contains(&f.as_str()))
+    }
+
+    /// Returns an error if the app client has a secret and the secret hash does not match it.
+    pub fn check_secret_hash(&self, username: &str, hash: Option<&str>) -> StoreResult<()> {
+        match self.client_secret.as_ref() {
+            Some(secret) if hash != Some(&secret_hash(secret, username, &self.client_id)) => {
+                Err(not_authorized(&format!(
+                    "Unable to verify secret hash for client {}",
+                    self.client_id
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl UserPoolState {
+    /// Starts an auth flow (AdminInitiateAuth if `admin` is true, otherwise InitiateAuth).
+    ///
+    /// Sign-ins by passwords are evaluated by the risk rules and recorded with the context.
+    pub fn initiate_auth(
+        &mut self,
+        client_id: &str,
+        auth_flow: &str,
+        parameters: &HashMap<String, String>,
+        admin: bool,
+        context: EventContext,
+    ) -> StoreResult<AuthOutcome> {
+        let client = self.client(client_id)?.clone();
+        let supported = match auth_flow {
+            "ADMIN_NO_SRP_AUTH" | "ADMIN_USER_PASSWORD_AUTH" => admin,
+            "USER_PASSWORD_AUTH" => !admin,
+            _ => true,
+        };
+        if !supported || !client.allows_auth_flow(auth_flow) {
+            return Err((
+                StoreError::InvalidParameterException,
+                format!("{} flow not enabled for this client", auth_flow),
+            ));
+        }
+
+        match auth_flow {
+            "ADMIN_NO_SRP_AUTH" | "ADMIN_USER_PASSWORD_AUTH" | "USER_PASSWORD_AUTH" => {
+                let username = parameter(parameters, USERNAME_PARAMETER)?;
+                let password = parameter(parameters, PASSWORD_PARAMETER)?;
+                client.check_secret_hash(
+                    username,
+                    parameters.get(SECRET_HASH_PARAMETER).map(String::as_str),
+                )?;
+                let username = self
+                    .resolve_username(username)
+                    .unwrap_or_else(|| username.to_string());
+                let device_key = parameters.get(DEVICE_KEY_PARAMETER).map(String::as_str);
+                self.sign_in(client_id, &username, password, device_key, context)
+            }
+            "REFRESH_TOKEN_AUTH" | "REFRESH_TOKEN" => {
+                let refresh_token = parameter(parameters, REFRESH_TOKEN_PARAMETER)?;
+                if let Some(session) = self
+                    .sessions
+                    .values()
+                    .find(|s| s.refresh_token == refresh_token)
+                {
+                    // The secret hash of refresh tokens is computed from the user sub.
+                    let sub = self
+                        .users
+                        .get(&session.username)
+                        .map(|u| u.sub.clone())
+                        .unwrap_or_default();
+                    let hash = parameters.get(SECRET_HASH_PARAMETER).map(String::as_str);
+                    if client.check_secret_hash(&session.username, hash).is_err() {
+                        client.check_secret_hash(&sub, hash)?;
+                    }
+                }
+                self.refresh_session(client_id, refresh_token)
+                    .map(AuthOutcome::Authenticated)
+            }
+            _ => Err((
+                StoreError::InvalidParameterException,
+                format!(
+                    "{} flow is not supported by the emulated user pools",
+                    auth_flow
+                ),
+            )),
+        }
+    }
+
+    /// Responds to a challenge (AdminRespondToAuthChallenge or RespondToAuthChallenge).
+    pub fn respond_to_auth_challenge(
+        &mut self,
+        client_id: &str,
+        challenge_name: &str,
+        session: Option<&str>,
+        responses: &HashMap<String, String>,
+    ) -> StoreResult<AuthOutcome> {
+        let client = self.client(client_id)?.clone();
+        let username = parameter(responses, USERNAME_PARAMETER)?.to_string();
+        client.check_secret_hash(
+            &username,
+            responses.get(SECRET_HASH_PARAMETER).map(String::as_str),
+        )?;
+        let username = self.resolve_username(&username).unwrap_or(username);
+        let challenge = session
+            .and_then(|s| self.challenges.get(s))
+            .filter(|c| c.client_id == client_id && c.username == username)
+            .filter(|c| c.challenge_name == challenge_name)
+            .cloned()
+            .ok_or_else(|| not_authorized("Invalid session for the user."))?;
+        if challenge.expires_at <= Utc::now() {
+            return Err(not_authorized(
+                "Invalid session for the user, session is expired.",
+            ));
+        }
+
+        let outcome = match challenge_name {
+            NEW_PASSWORD_REQUIRED_CHALLENGE => {
+                let new_password = parameter(responses, NEW_PASSWORD_PARAMETER)?;
+                self.check_password(new_password)?;
+                let user = self.user_mut(&username)?;
+                for (name, value) in responses {
+                    if let Some(name) = name.strip_prefix(USER_ATTRIBUTE_PREFIX) {
+                        user.set_attribute(name, value);
+                    }
+                }
+                user.password = Some(new_password.to_string());
+                user.temporary_password_expires_at = None;
+                user.status = UserStatus::Confirmed;
+                user.last_modified_at = Utc::now();
+                if challenge.values.contains_key(MFA_REQUIRED_VALUE) {
+                    self.sms_mfa_challenge(client_id, &username)?
+                } else {
+                    self.authenticated(client_id, &username, None)?
+                }
+            }
+            SMS_MFA_CHALLENGE => {
+                let code = parameter(responses, SMS_MFA_CODE_PARAMETER)?;
+                if challenge.values.get(CODE_VALUE).map(String::as_str) != Some(code) {
+                    return Err((
+                        StoreError::CodeMismatchException,
+                        "Invalid code received for user".to_string(),
+                    ));
+                }
+                self.authenticated(client_id, &username, None)?
+            }
+            DEVICE_SRP_AUTH_CHALLENGE => self.device_srp_auth(client_id, &username, responses)?,
+            DEVICE_PASSWORD_VERIFIER_CHALLENGE => {
+                self.verify_device_password(client_id, &username, &challenge, responses)?
+            }
+            _ => {
+                return Err((
+                    StoreError::InvalidParameterException,
+                    format!(
+                        "{} challenge is not supported by the emulated user pools",
+                        challenge_name
+                    ),
+                ))
+            }
+        };
+        if let Some(session) = session {
+            self.challenges.remove(session);
+        }
+        Ok(outcome)
+    }
+
+    /// Returns tokens of a user, or `DEVICE_SRP_AUTH` if the user signs in on a confirmed device.
+    ///
+    /// User pools which track devices track a new device by the tokens otherwise.
+    fn authenticated(
+        &mut self,
+        client_id: &str,
+        username: &str,
+        device_key: Option<&str>,
+    ) -> StoreResult<AuthOutcome> {
+        if !self.tracks_devices() {
+            return self
+                .start_session(client_id, username)
+                .map(AuthOutcome::Authenticated);
+        }
+        let verifiable = device_key
+            .and_then(|key| self.device(username, key).ok())
+            .is_some_and(|device| device.is_verifiable());
+        if verifiable {
+            let parameters = serde_json::json!({ "USERNAME": username });
+            return Ok(self.challenge(
+                client_id,
+                username,
+                DEVICE_SRP_AUTH_CHALLENGE,
+                parameters,
+                HashMap::new(),
+            ));
+        }
+        let metadata = self.new_device(username)?;
+        let mut result =
+            self.start_device_session(client_id, username, Some(metadata.device_key.clone()))?;
+        result.new_device_metadata = Some(metadata);
+        Ok(AuthOutcome::Authenticated(result))
+    }
+
+    fn device_srp_auth(
+        &mut self,
+        client_id: &str,
+        username: &str,
+        responses: &HashMap<String, String>,
+    ) -> StoreResult<AuthOutcome> {
+        let device_key = parameter(responses, DEVICE_KEY_PARAMETER)?;
+        let public_a = parameter(responses, SRP_A_PARAMETER)?;
+        let device = self.device(username, device_key)?;
+        let (verifier, salt) = match (device.password_verifier.as_ref(), device.salt.as_ref()) {
+            (Some(verifier), Some(salt)) if device.confirmed => (verifier.clone(), salt.clone()),
+            _ => return Err(not_authorized("Device is not confirmed.")),
+        };
+        if !srp::is_valid_public_value(public_a) {
+            return Err((
+                StoreError::InvalidParameterException,
+                "SRP_A is invalid.".to_string(),
+            ));
+        }
+        let (b, public_b) = srp::server_values(&verifier)
+            .ok_or_else(|| not_authorized("Device is not confirmed."))?;
+        let secret_block = srp::new_secret_block();
+        let parameters = serde_json::json!({
+            "DEVICE_KEY": device_key,
+            "SALT": salt,
+            "SECRET_BLOCK": secret_block,
+            "SRP_B": public_b,
+            "USERNAME": username,
+        });
+        let values = HashMap::from([
+            (DEVICE_KEY_PARAMETER.to_string(), device_key.to_string()),
+            (SRP_A_PARAMETER.to_string(), public_a.to_string()),
+            (SRP_B_VALUE.to_string(), b),
+            (SECRET_BLOCK_PARAMETER.to_string(), secret_block),
+        ]);
+        Ok(self.challenge(
+            client_id,
+            username,
+            DEVICE_PASSWORD_VERIFIER_CHALLENGE,
+            parameters,
+            values,
+        ))
+    }
+
+    fn verify_device_password(
+        &mut self,
+        client_id: &str,
+        username: &str,
+        challenge: &super::ChallengeState,
+        responses: &HashMap<String, String>,
+    ) -> StoreResult<AuthOutcome> {
+        let incorrect = || not_authorized("Incorrect username or password.");
+        let value = |name: &str| {
+            challenge
+                .values
+                .get(name)
+                .map(String::as_str)
+                .unwrap_or_default()
+        };
+        let device_key = parameter(responses, DEVICE_KEY_PARAMETER)?;
+        let secret_block = parameter(responses, PASSWORD_CLAIM_SECRET_BLOCK_PARAMETER)?;
+        let signature = parameter(responses, PASSWORD_CLAIM_SIGNATURE_PARAMETER)?;
+        let timestamp = parameter(responses, TIMESTAMP_PARAMETER)?;
+        if device_key != value(DEVICE_KEY_PARAMETER)
+            || secret_block != value(SECRET_BLOCK_PARAMETER)
+        {
+            return Err(incorrect());
+        }
+        let device = self.device(username, device_key)?;
+        let claim = srp::PasswordClaim {
+            device_group_key: &device.device_group_key,
+            device_key,
+            secret_block,
+            timestamp,
+        };
+        let expected = srp::server_signature(
+            &claim,
+            device.password_verifier.as_deref().unwrap_or_default(),
+            value(SRP_A_PARAMETER),
+            value(SRP_B_VALUE),
+        );
+        if expected.as_deref() != Some(signature) {
+            return Err(incorrect());
+        }
+        self.authenticate_device(username, device_key)?;
+        self.start_device_session(client_id, username, Some(device_key.to_string()))
+            .map(AuthOutcome::Authenticated)
+    }
+
+    /// Signs in a user by the password after evaluating the risk rules of the app client.
+    ///
+    /// Blocked sign-ins fail, and sign-ins at risk of account takeover get `SMS_MFA` before any
+    /// session starts. The sign-in is recorded as an auth event with the risk decision.
+    pub(super) fn sign_in(
+        &mut self,
+        client_id: &str,
+        username: &str,
+        password: &str,
+        device_key: Option<&str>,
+        context: EventContext,
+    ) -> StoreResult<AuthOutcome> {
+        let rules = self.risk_rules(Some(client_id));
+        self.sign_in_with(&rules, client_id, username, password, device_key, context)
+    }
+
+    fn sign_in_with(
+        &mut self,
+        rules: &HashMap<String, String>,
+        client_id: &str,
+        username: &str,
+        password: &str,
+        device_key: Option<&str>,
+        context: EventContext,
+    ) -> StoreResult<AuthOutcome> {
+        let decision = super::evaluate_risk_with(
+            rules,
+            &RiskContext {
+                ip_address: context.ip_address.as_deref(),
+                device_key,
+                password: Some(password),
+            },
+        );
+        let mut event = AuthEventState::new(AuthEventType::SignIn, context);
+        event.risk_decision = decision;
+        event.compromised_credentials_detected = super::is_compromised_password(rules, password);
+        let outcome = if decision == RiskDecision::Block {
+            Err(not_authorized(BLOCKED_BY_RISK_MESSAGE))
+        } else {
+            self.password_auth(client_id, username, password, device_key, decision)
+        };
+        self.record_sign_in(username, &outcome, event);
+        outcome
+    }
+
+    /// Returns the code sent by a `SMS_MFA` challenge for the debug header.
+    pub fn challenge_code(&self, outcome: &AuthOutcome) -> Option<String> {
+        match outcome {
+            AuthOutcome::Challenge { session, .. } => self
+                .challenges
+                .get(session)
+                .and_then(|c| c.values.get(CODE_VALUE))
+                .cloned(),
+            AuthOutcome::Authenticated(_) => None,
+        }
+    }
+
+    fn password_auth(
+        &mut self,
+        client_id: &str,
+        username: &str,
+        password: &str,
+        device_key: Option<&str>,
+        decision: RiskDecision,
+    ) -> StoreResult<AuthOutcome> {
+        let user = self.user(username)?;
+        user.check_enabled()?;
+        if user.password.as_deref() != Some(password) {
+            return Err(not_authorized("Incorrect username or password."));
+        }
+        match user.status {
+            UserStatus::Unconfirmed => Err((
+                StoreError::UserNotConfirmedException,
+                "User is not confirmed.".to_string(),
+            )),
+            UserStatus::ResetRequired => Err((
+                StoreError::PasswordResetRequiredException,
+                "Password reset required for the user".to_string(),
+            )),
+            UserStatus::ForceChangePassword
+                if user
+                    .temporary_password_expires_at
+                    .is_some_and(|expires_at| expires_at <= Utc::now()) =>
+            {
+                Err(not_authorized(
+                    "Temporary password has expired and must be reset by an administrator.",
+                ))
+            }
+            UserStatus::ForceChangePassword => {
+                let parameters = serde_json::json!({
+                    "USER_ID_FOR_SRP": user.username,
+                    "requiredAttributes": "[]",
+                    "userAttributes": serde_json::Value::Object(
+                        user.attributes
+                            .iter()
+                            .map(|(n, v)| (n.clone(), v.as_str().into()))
+                            .collect()
+                    )
+                    .to_string(),
+                });
+                // The new password is followed by MFA the risk decision requires.
+                let mut values = HashMap::new();
+                if decision == RiskDecision::AccountTakeover {
+                    values.insert(MFA_REQUIRED_VALUE.to_string(), true.to_string());
+                }
+                Ok(self.challenge(
+                    client_id,
+                    username,
+                    NEW_PASSWORD_REQUIRED_CHALLENGE,
+                    parameters,
+                    values,
+                ))
+            }
+            UserStatus::ExternalProvider => Err(not_authorized("Incorrect username or password.")),
+            UserStatus::Confirmed if decision == RiskDecision::AccountTakeover => {
+                self.sms_mfa_challenge(client_id, username)
+            }
+            UserStatus::Confirmed => self.authenticated(client_id, username, device_key),
+        }
+    }
+
+    /// Returns `SMS_MFA` with a new code sent to the phone number of a user.
+    fn sms_mfa_challenge(&mut self, client_id: &str, username: &str) -> StoreResult<AuthOutcome> {
+        let destination = self
+            .user(username)?
+            .attribute("phone_number")
+            .unwrap_or_default()
+            .to_string();
+        let parameters = serde_json::json!({
+            "CODE_DELIVERY_DELIVERY_MEDIUM": "SMS",
+            "CODE_DELIVERY_DESTINATION": destination,
+            "USER_ID_FOR_SRP": username,
+        });
+        let values = HashMap::from([(CODE_VALUE.to_string(), super::new_code())]);
+        Ok(self.challenge(client_id, username, SMS_MFA_CHALLENGE, parameters, values))
+    }
+
+    fn challenge(
+        &mut self,
+        client_id: &str,
+        username: &str,
+        challenge_name: &str,
+        parameters: serde_json::Value,
+        values: HashMap<String, String>,
+    ) -> AuthOutcome {
+        let session = common::random_string(ALPHANUMERIC, SESSION_LENGTH);
+        self.challenges.insert(
+            session.clone(),
+            super::ChallengeState {
+                username: username.to_string(),
+                client_id: client_id.to_string(),
+                challenge_name: challenge_name.to_string(),
+                expires_at: Utc::now() + Duration::minutes(CHALLENGE_VALIDITY_MINUTES),
+                values,
+            },
+        );
+        AuthOutcome::Challenge {
+            name: challenge_name.to_string(),
+            parameters,
+            session,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{ClientState, EventResponse, UserState};
+    use pretty_assertions::assert_eq;
+
+    fn user_pool(explicit_auth_flows: &[&str]) -> (UserPoolState, String) {
+        let mut user_pool = UserPoolState::new("pool");
+        let mut client = ClientState::new("client", false);
+        client.explicit_auth_flows = explicit_auth_flows.iter().map(|f| f.to_string()).collect();
+        let client_id = client.client_id.clone();
+        user_pool.clients.insert(client_id.clone(), client);
+        let mut user = UserState::new("user", UserStatus::ForceChangePassword);
+        user.password = Some("Passw0rd!".to_string());
+        user_pool.add_user(user).unwrap();
+        (user_pool, client_id)
+    }
+
+    fn parameters(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn client_allows_explicit_auth_flows() {
+        let client = ClientState::new("client", false);
+        assert!(client.allows_auth_flow("USER_SRP_AUTH"));
+        assert!(client.allows_auth_flow("REFRESH_TOKEN_AUTH"));
+        assert!(!client.allows_auth_flow("USER_PASSWORD_AUTH"));
+
+        let client = ClientState {
+            explicit_auth_flows: vec!["ADMIN_NO_SRP_AUTH".to_string()],
+            ..client
+        };
+        assert!(client.allows_auth_flow("ADMIN_USER_PASSWORD_AUTH"));
+        assert!(client.allows_auth_flow("REFRESH_TOKEN"));
+        assert!(!client.allows_auth_flow("USER_SRP_AUTH"));
+    }
+
+    #[test]
+    fn secret_hash_is_checked_for_client_with_secret() {
+        let client = ClientState::new("client", true);
+        let hash = secret_hash(
+            client.client_secret.as_ref().unwrap(),
+            "user",
+            &client.client_id,
+        );
+        assert!(client.check_secret_hash("user", Some(&hash)).is_ok());
+        assert!(client.check_secret_hash("user", None).is_err());
+        assert!(ClientState::new("client", false)
+            .check_secret_hash("user", None)
+            .is_ok());
+    }
+
+    #[test]
+    fn temporary_password_requires_new_password() {
+        let (mut user_pool, client_id) = user_pool(&["ALLOW_ADMIN_USER_PASSWORD_AUTH"]);
+        let outcome = user_pool
+            .initiate_auth(
+                &client_id,
+                "ADMIN_USER_PASSWORD_AUTH",
+                &parameters(&[("USERNAME", "user"), ("PASSWORD", "Passw0rd!")]),
+                true,
+                EventContext::default(),
+            )
+            .unwrap();
+        let session = match outcome {
+            AuthOutcome::Challenge { name, session, .. } => {
+                assert_eq!(NEW_PASSWORD_REQUIRED_CHALLENGE, name);
+                session
+            }
+            _ => panic!("challenge is expected"),
+        };
+
+        let outcome = user_pool
+            .respond_to_auth_challenge(
+                &client_id,
+                NEW_PASSWORD_REQUIRED_CHALLENGE,
+                Some(&session),
+                &parameters(&[
+                    ("USERNAME", "user"),
+                    ("NEW_PASSWORD", "NewPassw0rd!"),
+                    ("userAttributes.name", "User"),
+                ]),
+            )
+            .unwrap();
+        assert!(matches!(outcome, AuthOutcome::Authenticated(_)));
+        let user = user_pool.user("user").unwrap();
+        assert_eq!(UserStatus::Confirmed, user.status);
+        assert_eq!(Some("User"), user.attribute("name"));
+        assert!(!user_pool.challenges.contains_key(&session));
+    }
+
+    #[test]
+    fn failure_to_initiate_auth() {
+        let (mut user_pool, client_id) =
+            user_pool(&["ALLOW_ADMIN_USER_PASSWORD_AUTH", "ALLOW_REFRESH_TOKEN_AUTH"]);
+        let error = |user_pool: &mut UserPoolState, flow: &str, pairs: &[(&str, &str)]| {
+            user_pool
+                .initiate_auth(
+                    &client_id,
+                    flow,
+                    &parameters(pairs),
+                    true,
+                    EventContext::default(),
+                )
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        };
+        assert_eq!(
+            Err(StoreError::NotAuthorizedException),
+            error(
+                &mut user_pool,
+                "ADMIN_USER_PASSWORD_AUTH",
+                &[("USERNAME", "user"), ("PASSWORD", "wrong")]
+            )
+        );
+        assert_eq!(
+            Err(StoreError::UserNotFoundException),
+            error(
+                &mut user_pool,
+                "ADMIN_USER_PASSWORD_AUTH",
+                &[("USERNAME", "nobody"), ("PASSWORD", "Passw0rd!")]
+            )
+        );
+        assert_eq!(
+            Err(StoreError::InvalidParameterException),
+            error(&mut user_pool, "USER_SRP_AUTH", &[("USERNAME", "user")])
+        );
+        assert_eq!(
+            Err(StoreError::NotAuthorizedException),
+            error(
+                &mut user_pool,
+                "REFRESH_TOKEN_AUTH",
+                &[("REFRESH_TOKEN", "unknown")]
+            )
+        );
+    }
+
+    #[test]
+    fn confirmed_device_is_authenticated_by_device_srp_auth() {
+        let (mut user_pool, client_id) = user_pool(&["ALLOW_ADMIN_USER_PASSWORD_AUTH"]);
+        user_pool.description["DeviceConfiguration"] = serde_json::json!({
+            "ChallengeRequiredOnNewDevice": false,
+            "DeviceOnlyRememberedOnUserPrompt": false
+        });
+        user_pool.user_mut("user").unwrap().status = UserStatus::Confirmed;
+        let sign_in = |user_pool: &mut UserPoolState, device_key: Option<&str>| {
+            let mut pairs = vec![("USERNAME", "user"), ("PASSWORD", "Passw0rd!")];
+            pairs.extend(device_key.map(|key| ("DEVICE_KEY", key)));
+            user_pool
+                .initiate_auth(
+                    &client_id,
+                    "ADMIN_USER_PASSWORD_AUTH",
+                    &parameters(&pairs),
+                    true,
+                    EventContext::default(),
+                )
+                .unwrap()
+        };
+
+        let metadata = match sign_in(&mut user_pool, None) {
+            AuthOutcome::Authenticated(result) => result.new_device_metadata.unwrap(),
+            _ => panic!("tokens are expected"),
+        };
+        let (verifier, salt) = srp::client::device_verifier(
+            &metadata.device_group_key,
+            &metadata.device_key,
+            "device password",
+        );
+        let config = crate::user_pools::DeviceSecretVerifierConfigType {
+            password_verifier: Some(verifier),
+            salt: Some(salt),
+        };
+        assert_eq!(
+            Ok(false),
+            user_pool.confirm_device("user", &metadata.device_key, None, Some(&config))
+        );
+
+        let session = match sign_in(&mut user_pool, Some(&metadata.device_key)) {
+            AuthOutcome::Challenge { name, session, .. } => {
+                assert_eq!(DEVICE_SRP_AUTH_CHALLENGE, name);
+                session
+            }
+            _ => panic!("challenge is expected"),
+        };
+        let (a, public_a) = srp::client::client_values();
+        let outcome = user_pool
+            .respond_to_auth_challenge(
+                &client_id,
+                DEVICE_SRP_AUTH_CHALLENGE,
+                Some(&session),
+                &parameters(&[
+                    ("USERNAME", "user"),
+                    ("DEVICE_KEY", &metadata.device_key),
+                    ("SRP_A", &public_a),
+                ]),
+            )
+            .unwrap();
+        let (challenge, session) = match outcome {
+            AuthOutcome::Challenge {
+                name,
+                parameters,
+                session,
+            } => {
+                assert_eq!(DEVICE_PASSWORD_VERIFIER_CHALLENGE, name);
+                (parameters, session)
+            }
+            _ => panic!("challenge is expected"),
+        };
+        let secret_block = challenge["SECRET_BLOCK"].as_str().unwrap();
+        let timestamp = "Mon Oct 19 00:00:00 UTC 2026";
+        let claim = srp::PasswordClaim {
+            device_group_key: &metadata.device_group_key,
+            device_key: &metadata.device_key,
+            secret_block,
+            timestamp,
+        };
+        let respond = |user_pool: &mut UserPoolState, password: &str| {
+            let signature = srp::client::password_claim_signature(
+                &claim,
+                password,
+                &a,
+                challenge["SRP_B"].as_str().unwrap(),
+                challenge["SALT"].as_str().unwrap(),
+            )
+            .unwrap();
+            user_pool.respond_to_auth_challenge(
+                &client_id,
+                DEVICE_PASSWORD_VERIFIER_CHALLENGE,
+                Some(&session),
+                &parameters(&[
+                    ("USERNAME", "user"),
+                    ("DEVICE_KEY", &metadata.device_key),
+                    ("PASSWORD_CLAIM_SECRET_BLOCK", secret_block),
+                    ("PASSWORD_CLAIM_SIGNATURE", &signature),
+                    ("TIMESTAMP", timestamp),
+                ]),
+            )
+        };
+
+        assert_eq!(
+            Some(StoreError::NotAuthorizedException),
+            respond(&mut user_pool, "wrong password")
+                .err()
+                .map(|(e, _)| e)
+        );
+        match respond(&mut user_pool, "device password").unwrap() {
+            AuthOutcome::Authenticated(result) => assert!(result.new_device_metadata.is_none()),
+            _ => panic!("tokens are expected"),
+        }
+    }
+
+    #[test]
+    fn risk_rules_block_sign_in_before_session() {
+        let (mut user_pool, client_id) = user_pool(&["ALLOW_ADMIN_USER_PASSWORD_AUTH"]);
+        user_pool.user_mut("user").unwrap().status = UserStatus::Confirmed;
+        let rules = HashMap::from([(
+            "compromised_passwords".to_string(),
+            "password, Passw0rd!".to_string(),
+        )]);
+        let result = user_pool.sign_in_with(
+            &rules,
+            &client_id,
+            "user",
+            "Passw0rd!",
+            None,
+            EventContext::default(),
+        );
+        assert_eq!(
+            Err((
+                StoreError::NotAuthorizedException,
+                BLOCKED_BY_RISK_MESSAGE.to_string()
+            )),
+            result
+        );
+        assert!(user_pool.sessions.is_empty());
+        let event = &user_pool.user("user").unwrap().auth_events[0];
+        assert_eq!(RiskDecision::Block, event.risk_decision);
+        assert!(event.compromised_credentials_detected);
+        assert_eq!(EventResponse::Fail, event.event_response);
+    }
+
+    #[test]
+    fn account_takeover_requires_sms_mfa_before_session() {
+        let (mut user_pool, client_id) = user_pool(&["ALLOW_ADMIN_USER_PASSWORD_AUTH"]);
+        let user = user_pool.user_mut("user").unwrap();
+        user.status = UserStatus::Confirmed;
+        user.set_attribute("phone_number", "+12065551212");
+        let rules = HashMap::from([("mfa_ip_ranges".to_string(), "203.0.113.0/24".to_string())]);
+        let context = EventContext {
+            ip_address: Some("203.0.113.1".to_string()),
+            ..Default::default()
+        };
+        let outcome = user_pool
+            .sign_in_with(&rules, &client_id, "user", "Passw0rd!", None, context)
+            .unwrap();
+        let code = user_pool.challenge_code(&outcome).unwrap();
+        let session = match outcome {
+            AuthOutcome::Challenge {
+                name,
+                parameters,
+                session,
+            } => {
+                assert_eq!(SMS_MFA_CHALLENGE, name);
+                assert_eq!("+12065551212", parameters["CODE_DELIVERY_DESTINATION"]);
+                session
+            }
+            _ => panic!("challenge is expected"),
+        };
+        assert!(user_pool.sessions.is_empty());
+
+        let respond = |user_pool: &mut UserPoolState, code: &str| {
+            user_pool.respond_to_auth_challenge(
+                &client_id,
+                SMS_MFA_CHALLENGE,
+                Some(&session),
+                &parameters(&[("USERNAME", "user"), ("SMS_MFA_CODE", code)]),
+            )
+        };
+        assert_eq!(
+            Some(StoreError::CodeMismatchException),
+            respond(&mut user_pool, "wrong").err().map(|(e, _)| e)
+        );
+        assert!(matches!(
+            respond(&mut user_pool, &code).unwrap(),
+            AuthOutcome::Authenticated(_)
+        ));
+        assert_eq!(1, user_pool.sessions.len());
+    }
+
+    #[test]
+    fn account_takeover_requires_sms_mfa_after_new_password() {
+        let (mut user_pool, client_id) = user_pool(&["ALLOW_ADMIN_USER_PASSWORD_AUTH"]);
+        let rules = HashMap::from([("mfa_device_keys".to_string(), "device".to_string())]);
+        let outcome = user_pool
+            .sign_in_with(
+                &rules,
+                &client_id,
+                "user",
+                "Passw0rd!",
+                Some("device"),
+                EventContext::default(),
+            )
+            .unwrap();
+        let session = match outcome {
+            AuthOutcome::Challenge { name, session, .. } => {
+                assert_eq!(NEW_PASSWORD_REQUIRED_CHALLENGE, name);
+                session
+            }
+            _ => panic!("challenge is expected"),
+        };
+        let outcome = user_pool
+            .respond_to_auth_challenge(
+                &client_id,
+                NEW_PASSWORD_REQUIRED_CHALLENGE,
+                Some(&session),
+                &parameters(&[("USERNAME", "user"), ("NEW_PASSWORD", "NewPassw0rd!")]),
+            )
+            .unwrap();
+        assert!(
+            matches!(outcome, AuthOutcome::Challenge { ref name, .. } if name == SMS_MFA_CHALLENGE)
+        );
+        assert!(user_pool.challenge_code(&outcome).is_some());
+    }
+}
@@ -47,7 +47,31 @@ impl super::ToActionName for ChangePasswordRequest {
 impl super::ToResponse for ChangePasswordRequest {
     type E = ChangePasswordError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_applied_empty_response(self, || {
+            super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    user_pool
+                        .check_password(self.proposed_password.as_deref().unwrap_or_default())?;
+                    let user = user_pool.user_mut(&user.username)?;
+                    if user.password != self.previous_password {
+                        return Err((
+                            super::StoreError::NotAuthorizedException,
+                            "Incorrect username or password.".to_string(),
+                        ));
+                    }
+                    user.password = self.proposed_password.clone();
+                    user.last_modified_at = chrono::Utc::now();
+                    Ok(())
+                },
+            )
+            .map_err(super::to_response_error)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_password(self.proposed_password.as_deref()?)
+            .map(|message| (ChangePasswordError::InvalidPasswordException, message))
     }
 }
 
@@ -76,7 +100,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = ChangePasswordError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
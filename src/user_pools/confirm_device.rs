@@ -48,7 +48,22 @@ impl super::ToActionName for ConfirmDeviceRequest {
 impl super::ToResponse for ConfirmDeviceRequest {
     type E = ConfirmDeviceError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CONFIRM_DEVICE_NAME)
+        super::to_converted_json_response(self, CONFIRM_DEVICE_NAME, |mut value| {
+            let necessary = super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    user_pool.confirm_device(
+                        &user.username,
+                        self.device_key.as_deref().unwrap_or_default(),
+                        self.device_name.as_deref(),
+                        self.device_secret_verifier_config.as_ref(),
+                    )
+                },
+            )
+            .map_err(super::to_response_error)?;
+            value["UserConfirmationNecessary"] = necessary.into();
+            Ok(value)
+        })
     }
 }
 
@@ -79,7 +94,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = ConfirmDeviceError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -62,7 +62,32 @@ impl super::ToActionName for ConfirmForgotPasswordRequest {
 impl super::ToResponse for ConfirmForgotPasswordRequest {
     type E = ConfirmForgotPasswordError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let client_id = self.client_id.as_deref().unwrap_or_default();
+        if super::client_user_pool_id(client_id).is_none() {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_client_user_pool(client_id, |user_pool| {
+                let result = user_pool.check_password(self.password.as_deref().unwrap_or_default());
+                user_pool.record_completed_event(
+                    self.username.as_deref().unwrap_or_default(),
+                    super::AuthEventType::ForgotPassword,
+                    &result,
+                    super::EventContext::from_user_context_data(self.user_context_data.as_ref()),
+                );
+                result
+            })
+            .map_err(super::to_response_error)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_password(self.password.as_deref()?).map(|message| {
+            (
+                ConfirmForgotPasswordError::InvalidPasswordException,
+                message,
+            )
+        })
     }
 }
 
@@ -96,7 +121,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = ConfirmForgotPasswordError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -58,7 +58,49 @@ impl super::ToActionName for ConfirmSignUpRequest {
 impl super::ToResponse for ConfirmSignUpRequest {
     type E = ConfirmSignUpError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        let client_id = self.client_id.as_deref().unwrap_or_default();
+        if super::client_user_pool_id(client_id).is_none() {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_client_user_pool(client_id, |user_pool| {
+                self.confirm_sign_up(client_id, user_pool)
+            })
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+impl ConfirmSignUpRequest {
+    fn confirm_sign_up(
+        &self,
+        client_id: &str,
+        user_pool: &mut super::UserPoolState,
+    ) -> super::StoreResult<()> {
+        let username = self.username.as_deref().unwrap_or_default();
+        user_pool
+            .client(client_id)?
+            .check_secret_hash(username, self.secret_hash.as_deref())?;
+        let user = user_pool.user_mut(username)?;
+        if user.status != super::UserStatus::Unconfirmed {
+            return Err((
+                super::StoreError::NotAuthorizedException,
+                "User cannot be confirmed. Current status is CONFIRMED".to_string(),
+            ));
+        }
+        if user.confirmation_code.is_none() || user.confirmation_code != self.confirmation_code {
+            return Err((
+                super::StoreError::CodeMismatchException,
+                "Invalid verification code provided, please try again.".to_string(),
+            ));
+        }
+        user.status = super::UserStatus::Confirmed;
+        user.confirmation_code = None;
+        user.last_modified_at = chrono::Utc::now();
+        user_pool.verify_auto_verified_attributes(
+            username,
+            self.force_alias_creation.unwrap_or_default(),
+        )
     }
 }
 
@@ -91,7 +133,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = ConfirmSignUpError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
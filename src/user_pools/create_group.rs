@@ -46,7 +46,23 @@ impl super::ToActionName for CreateGroupRequest {
 impl super::ToResponse for CreateGroupRequest {
     type E = CreateGroupError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_GROUP_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, CREATE_GROUP_NAME);
+        }
+        super::to_converted_json_response(self, CREATE_GROUP_NAME, |mut value| {
+            let mut group = value["Group"].take();
+            group["Description"] = self.description.clone().into();
+            group["GroupName"] = self.group_name.clone().into();
+            group["Precedence"] = self.precedence.into();
+            group["RoleArn"] = self.role_arn.clone().into();
+            group["UserPoolId"] = user_pool_id.into();
+            value["Group"] = super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.add_group(group).cloned()
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
     }
 }
 
@@ -77,7 +93,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = CreateGroupError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
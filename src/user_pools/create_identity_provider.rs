@@ -1,4 +1,4 @@
-use crate::common::USER_POOL_ID_REGEX;
+use crate::common::{IDP_IDENTIFIER_REGEX, USER_POOL_ID_REGEX};
 use crate::{
     http,
     validator::{includes, regex_in_array},
@@ -13,7 +13,6 @@ pub const CREATE_IDENTITY_PROVIDER_NAME: &str = "CreateIdentityProvider";
 pub const CREATE_IDENTITY_PROVIDER_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.CreateIdentityProvider";
 
-static IDP_IDENTIFIER_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w\s+=.@-]+").unwrap());
 static PROVIDER_NAME_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"[^_][\p{L}\p{M}\p{S}\p{N}\p{P}][^_]+").unwrap());
 
@@ -36,7 +35,8 @@ fn validate_provider_type(value: &str) -> Result<(), ValidationError> {
 
 super::gen_response_err!(
     CreateIdentityProviderError,
-    InvalidParameterException
+    DuplicateProviderException
+    | InvalidParameterException
     | InvalidLambdaResponseException
     | InvalidSmsRoleAccessPolicyException
     | InvalidSmsRoleTrustRelationshipException
@@ -84,7 +84,19 @@ impl super::ToActionName for CreateIdentityProviderRequest {
 impl super::ToResponse for CreateIdentityProviderRequest {
     type E = CreateIdentityProviderError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_IDENTITY_PROVIDER_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, CREATE_IDENTITY_PROVIDER_NAME);
+        }
+        super::to_converted_json_response(self, CREATE_IDENTITY_PROVIDER_NAME, |mut value| {
+            value["IdentityProvider"] = super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool
+                    .add_identity_provider(value["IdentityProvider"].clone())
+                    .cloned()
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
     }
 }
 
@@ -117,7 +129,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = CreateIdentityProviderError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -1,7 +1,7 @@
-use crate::common::USER_POOL_ID_REGEX;
+use crate::common::{
+    RESOURCE_SERVER_IDENTIFIER_REGEX, RESOURCE_SERVER_NAME_REGEX, USER_POOL_ID_REGEX,
+};
 use crate::http;
-use once_cell::sync::Lazy;
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 use validator::Validate;
@@ -9,9 +9,6 @@ use validator::Validate;
 pub const CREATE_RESOURCE_SERVER_NAME: &str = "CreateResourceServer";
 pub const CREATE_RESOURCE_SERVER_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.CreateResourceServer";
-static IDENTIFIER_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"[\x21\x23-\x5B\x5D-\x7E]+").unwrap());
-static NAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w\s+=,.@-]+").unwrap());
 
 super::gen_response_err!(
     CreateResourceServerError,
@@ -28,13 +25,14 @@ super::gen_response_err!(
 pub struct CreateResourceServerRequest {
     #[validate(required)]
     #[validate(length(min = 1, max = 256))]
-    #[validate(regex(path = *IDENTIFIER_REGEX))]
+    #[validate(regex(path = *RESOURCE_SERVER_IDENTIFIER_REGEX))]
     pub identifier: Option<String>,
     #[validate(required)]
     #[validate(length(min = 1, max = 256))]
-    #[validate(regex(path = *NAME_REGEX))]
+    #[validate(regex(path = *RESOURCE_SERVER_NAME_REGEX))]
     pub name: Option<String>,
     #[validate(length(max = 100))]
+    #[validate(nested)]
     pub scopes: Option<Vec<super::data_types::ResourceServerScopeType>>,
     #[validate(required)]
     #[validate(length(min = 1, max = 55))]
@@ -51,7 +49,28 @@ impl super::ToActionName for CreateResourceServerRequest {
 impl super::ToResponse for CreateResourceServerRequest {
     type E = CreateResourceServerError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_RESOURCE_SERVER_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, CREATE_RESOURCE_SERVER_NAME);
+        }
+        super::to_converted_json_response(self, CREATE_RESOURCE_SERVER_NAME, |mut value| {
+            value["ResourceServer"] = super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool
+                    .add_resource_server(value["ResourceServer"].clone())
+                    .cloned()
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_scope_names(self.scopes.as_ref()?).map(|message| {
+            (
+                CreateResourceServerError::InvalidParameterException,
+                message,
+            )
+        })
     }
 }
 
@@ -84,7 +103,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = CreateResourceServerError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
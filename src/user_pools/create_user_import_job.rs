@@ -48,7 +48,23 @@ impl super::ToActionName for CreateUserImportJobRequest {
 impl super::ToResponse for CreateUserImportJobRequest {
     type E = CreateUserImportJobError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_USER_IMPORT_JOB_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, CREATE_USER_IMPORT_JOB_NAME);
+        }
+        super::to_converted_json_response(self, CREATE_USER_IMPORT_JOB_NAME, |mut value| {
+            let job = super::UserImportJobState::new(
+                self.job_name.as_deref().unwrap_or_default(),
+                self.cloud_watch_logs_role_arn
+                    .as_deref()
+                    .unwrap_or_default(),
+            );
+            value["UserImportJob"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool.add_import_job(job).to_value(user_pool_id))
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
     }
 }
 
@@ -65,7 +81,6 @@ mod tests {
             ),
             job_name: Some("job-name".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -78,14 +93,13 @@ mod tests {
             ),
             job_name: Some("job_name".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = CreateUserImportJobError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
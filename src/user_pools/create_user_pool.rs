@@ -75,7 +75,8 @@ pub struct CreateUserPoolRequest {
     lambda_config: Option<super::data_types::LambdaConfigType>,
     #[validate(custom(function = validate_mfa_configuration))]
     mfa_configuration: Option<String>,
-    plicies: Option<super::data_types::UserPoolPolicyType>,
+    #[validate(nested)]
+    policies: Option<super::data_types::UserPoolPolicyType>,
     #[validate(required)]
     #[validate(length(min = 1, max = 128))]
     #[validate(regex(path = *POOL_NAME_REGEX))]
@@ -90,8 +91,11 @@ pub struct CreateUserPoolRequest {
     sms_verification_message: Option<String>,
     #[validate(custom(function = validate_username_attributes))]
     username_attributes: Option<Vec<String>>,
+    #[validate(nested)]
     username_configuration: Option<super::data_types::UsernameConfigurationType>,
     user_pool_add_ons: Option<super::data_types::UserPoolAddOnsType>,
+    #[validate(nested)]
+    user_attribute_update_settings: Option<super::data_types::UserAttributeUpdateSettingsType>,
     user_pool_tags: Option<std::collections::HashMap<String, String>>,
     verification_message_template: Option<super::data_types::VerificationMessageTemplateType>,
 }
@@ -105,7 +109,76 @@ impl super::ToActionName for CreateUserPoolRequest {
 impl super::ToResponse for CreateUserPoolRequest {
     type E = CreateUserPoolError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_USER_POOL_NAME)
+        super::to_converted_json_response(self, CREATE_USER_POOL_NAME, |mut value| {
+            let user_pool = self.to_user_pool_state(&value["UserPool"]);
+            value["UserPool"] = user_pool.description.clone();
+            super::tag_resource(
+                &user_pool.arn(),
+                self.user_pool_tags.as_ref().unwrap_or(&Default::default()),
+            )
+            .map_err(super::to_response_error)?;
+            super::insert_user_pool(user_pool);
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        if self.alias_attributes.is_some() && self.username_attributes.is_some() {
+            return Some((
+                CreateUserPoolError::InvalidParameterException,
+                "Only one of the aliasAttributes or usernameAttributes can be set in a User Pool."
+                    .to_string(),
+            ));
+        }
+        if let Some(message) = self.user_pool_tags.as_ref().and_then(super::check_tags) {
+            return Some((CreateUserPoolError::UserPoolTaggingException, message));
+        }
+        let schema = self.schema.as_ref()?;
+        super::check_custom_attribute_names(
+            schema
+                .iter()
+                .filter_map(|a| a.name.as_deref())
+                .filter(|name| !super::STANDARD_ATTRIBUTES.contains(name)),
+            0,
+        )
+        .map(|message| (CreateUserPoolError::InvalidParameterException, message))
+    }
+}
+
+impl CreateUserPoolRequest {
+    /// Returns the user pool to store with the description rendered from the template.
+    fn to_user_pool_state(&self, rendered: &serde_json::Value) -> super::UserPoolState {
+        let mut user_pool =
+            super::UserPoolState::new(self.pool_name.as_deref().unwrap_or_default());
+        let mut fields = super::request_value(self);
+        if let Some(fields) = fields.as_object_mut() {
+            fields.remove("PoolName");
+            if fields.remove("Schema").is_some() {
+                let schema = self.schema.iter().flatten().map(|a| {
+                    super::request_value(&super::SchemaAttributeType {
+                        name: Some(super::schema_attribute_name(a)),
+                        ..a.clone()
+                    })
+                });
+                fields.insert("SchemaAttributes".to_string(), schema.collect());
+            }
+        }
+        let mut description = rendered.clone();
+        super::merge_value(&mut description, fields);
+        super::merge_value(
+            &mut description,
+            serde_json::json!({
+                "Arn": user_pool.arn(),
+                "CreationDate": user_pool.created_at.timestamp(),
+                "EstimatedNumberOfUsers": 0,
+                "Id": user_pool.id,
+                "LastModifiedDate": user_pool.last_modified_at.timestamp(),
+                "Name": user_pool.name,
+                "Status": "Enabled",
+            }),
+        );
+        user_pool.description = description;
+        user_pool
     }
 }
 
@@ -134,7 +207,7 @@ mod tests {
 
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = CreateUserPoolError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -132,7 +132,106 @@ impl super::ToActionName for CreateUserPoolClientRequest {
 impl super::ToResponse for CreateUserPoolClientRequest {
     type E = CreateUserPoolClientError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_USER_POOL_CLIENT_NAME)
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, CREATE_USER_POOL_CLIENT_NAME);
+        }
+        super::to_converted_json_response(self, CREATE_USER_POOL_CLIENT_NAME, |mut value| {
+            let client = self.to_client_state();
+            let mut description = value["UserPoolClient"].take();
+            super::merge_value(&mut description, super::request_value(self));
+            super::merge_value(
+                &mut description,
+                serde_json::json!({
+                    "ClientId": client.client_id,
+                    "ClientName": client.client_name,
+                    "EnableTokenRevocation": client.enable_token_revocation,
+                    "UserPoolId": user_pool_id,
+                }),
+            );
+            if let Some(description) = description.as_object_mut() {
+                description.remove("GenerateSecret");
+                match client.client_secret.as_ref() {
+                    Some(secret) => {
+                        description.insert("ClientSecret".to_string(), secret.as_str().into());
+                    }
+                    None => {
+                        description.remove("ClientSecret");
+                    }
+                }
+            }
+            value["UserPoolClient"] = description;
+            super::with_user_pool(user_pool_id, |user_pool| {
+                if let Some(scopes) = self.allowed_oauth_scopes.as_deref() {
+                    user_pool.check_allowed_oauth_scopes(scopes)?;
+                }
+                user_pool.clients.insert(client.client_id.clone(), client);
+                Ok(())
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        if super::user_pool_exists(self.user_pool_id.as_deref().unwrap_or_default()) {
+            return None;
+        }
+        super::check_oauth_scopes(self.allowed_oauth_scopes.as_ref()?).map(|message| {
+            (
+                CreateUserPoolClientError::ScopeDoesNotExistException,
+                message,
+            )
+        })
+    }
+}
+
+impl CreateUserPoolClientRequest {
+    fn to_client_state(&self) -> super::ClientState {
+        let units = self.token_validity_units.as_ref();
+        let mut client = super::ClientState::new(
+            self.client_name.as_deref().unwrap_or_default(),
+            self.generate_secret.unwrap_or_default(),
+        );
+        client.explicit_auth_flows = self.explicit_auth_flows.clone().unwrap_or_default();
+        client.enable_token_revocation = self.enable_token_revocation.unwrap_or(true);
+        if self
+            .allowed_oauth_flows_user_pool_client
+            .unwrap_or_default()
+        {
+            client.allowed_oauth_flows = self.allowed_oatuh_flows.clone().unwrap_or_default();
+        }
+        client.callback_urls = self.callback_urls.clone().unwrap_or_default();
+        client.logout_urls = self.logout_urls.clone().unwrap_or_default();
+        if let Some(validity) = self.access_token_validity {
+            client.access_token_validity = token_validity(
+                validity,
+                units.and_then(|u| u.access_token.as_deref()),
+                "hours",
+            );
+        }
+        if let Some(validity) = self.id_token_validity {
+            client.id_token_validity =
+                token_validity(validity, units.and_then(|u| u.id_token.as_deref()), "hours");
+        }
+        if let Some(validity) = self.refresh_token_validity {
+            client.refresh_token_validity = token_validity(
+                validity,
+                units.and_then(|u| u.refresh_token.as_deref()),
+                "days",
+            );
+        }
+        client
+    }
+}
+
+/// Returns a token validity in the unit of `TokenValidityUnits`.
+fn token_validity(validity: i64, unit: Option<&str>, default_unit: &str) -> chrono::Duration {
+    match unit.unwrap_or(default_unit) {
+        "seconds" => chrono::Duration::seconds(validity),
+        "minutes" => chrono::Duration::minutes(validity),
+        "days" => chrono::Duration::days(validity),
+        _ => chrono::Duration::hours(validity),
     }
 }
 
@@ -161,9 +260,18 @@ mod tests {
         assert!(request.validate().is_err());
     }
 
+    #[test]
+    fn token_validity_has_unit() {
+        assert_eq!(
+            chrono::Duration::minutes(5),
+            token_validity(5, Some("minutes"), "hours")
+        );
+        assert_eq!(chrono::Duration::days(30), token_validity(30, None, "days"));
+    }
+
     #[test]
     fn error_can_convert_to_status_code() {
-        use crate::user_pools::ToStatusCode;
+        use crate::responses::ToStatusCode;
 
         let error = CreateUserPoolClientError::InvalidParameterException;
         assert_eq!(http::status_code(400), error.to_status_code());
@@ -0,0 +1,112 @@
+use crate::common::{DOMAIN_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const CREATE_USER_POOL_DOMAIN_NAME: &str = "CreateUserPoolDomain";
+pub const CREATE_USER_POOL_DOMAIN_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.CreateUserPoolDomain";
+
+super::gen_response_err!(
+    CreateUserPoolDomainError,
+    InvalidParameterException
+    | LimitExceededException
+    | NotAuthorizedException
+    | ResourceNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateUserPoolDomainRequest {
+    #[validate(nested)]
+    pub custom_domain_config: Option<super::CustomDomainConfigType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 63))]
+    #[validate(regex(path = *DOMAIN_REGEX))]
+    pub domain: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for CreateUserPoolDomainRequest {
+    fn to_action_name() -> &'static str {
+        CREATE_USER_POOL_DOMAIN_NAME
+    }
+}
+
+impl super::ToResponse for CreateUserPoolDomainRequest {
+    type E = CreateUserPoolDomainError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, CREATE_USER_POOL_DOMAIN_NAME);
+        }
+        super::to_converted_json_response(self, CREATE_USER_POOL_DOMAIN_NAME, |mut value| {
+            let domain = super::DomainState::new(
+                self.domain.as_deref().unwrap_or_default(),
+                self.custom_domain_config
+                    .as_ref()
+                    .and_then(|c| c.certificate_arn.clone()),
+            );
+            value["CloudFrontDomain"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool.add_domain(domain)?.cloud_front_domain.clone())
+            })
+            .map_err(super::to_response_error)?
+            .into();
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_domain_in_use(self.domain.as_deref()?, None).map(|message| {
+            (
+                CreateUserPoolDomainError::InvalidParameterException,
+                message,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::CustomDomainConfigType;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = CreateUserPoolDomainRequest {
+            domain: Some("auth-prefix".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = CreateUserPoolDomainRequest {
+            custom_domain_config: Some(CustomDomainConfigType {
+                certificate_arn: Some("".to_string()),
+            }),
+            domain: Some("auth-prefix".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = CreateUserPoolDomainError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = CreateUserPoolDomainError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -1,9 +1,15 @@
 mod account_recovery_setting_type;
+mod account_takeover_action_type;
+mod account_takeover_actions_type;
+mod account_takeover_risk_configuration_type;
 mod admin_create_user_config_type;
 mod analytics_configuration_type;
 mod analytics_metadata_type;
 mod attribute_type;
+mod compromised_credentials_actions_type;
+mod compromised_credentials_risk_configuration_type;
 mod context_data_type;
+mod custom_domain_config_type;
 mod custom_email_lambda_version_config_type;
 mod custom_sms_lambda_version_config_type;
 mod device_configuration_type;
@@ -13,17 +19,21 @@ mod http_header;
 mod lambda_config_type;
 mod message_template_type;
 mod mfa_option_type;
+mod notify_configuration_type;
+mod notify_email_type;
 mod number_attribute_constraints_type;
 mod password_policy_type;
 mod provider_user_identifier_type;
 mod recovery_option_type;
 mod resource_server_scope_type;
+mod risk_exception_configuration_type;
 mod schema_attribute_type;
 mod sms_configuration_type;
 mod sms_mfa_settings_type;
 mod software_token_mfa_settings_type;
 mod string_attribute_constraints_type;
 mod token_validity_units_type;
+mod user_attribute_update_settings_type;
 mod user_context_data_type;
 mod user_pool_add_ons_type;
 mod user_pool_policy_type;
@@ -31,11 +41,17 @@ mod username_configuration_type;
 mod verification_message_template_type;
 
 pub use account_recovery_setting_type::*;
+pub use account_takeover_action_type::*;
+pub use account_takeover_actions_type::*;
+pub use account_takeover_risk_configuration_type::*;
 pub use admin_create_user_config_type::*;
 pub use analytics_configuration_type::*;
 pub use analytics_metadata_type::*;
 pub use attribute_type::*;
+pub use compromised_credentials_actions_type::*;
+pub use compromised_credentials_risk_configuration_type::*;
 pub use context_data_type::*;
+pub use custom_domain_config_type::*;
 pub use custom_email_lambda_version_config_type::*;
 pub use custom_sms_lambda_version_config_type::*;
 pub use device_configuration_type::*;
@@ -45,17 +61,21 @@ pub use http_header::*;
 pub use lambda_config_type::*;
 pub use message_template_type::*;
 pub use mfa_option_type::*;
+pub use notify_configuration_type::*;
+pub use notify_email_type::*;
 pub use number_attribute_constraints_type::*;
 pub use password_policy_type::*;
 pub use provider_user_identifier_type::*;
 pub use recovery_option_type::*;
 pub use resource_server_scope_type::*;
+pub use risk_exception_configuration_type::*;
 pub use schema_attribute_type::*;
 pub use sms_configuration_type::*;
 pub use sms_mfa_settings_type::*;
 pub use software_token_mfa_settings_type::*;
 pub use string_attribute_constraints_type::*;
 pub use token_validity_units_type::*;
+pub use user_attribute_update_settings_type::*;
 pub use user_context_data_type::*;
 pub use user_pool_add_ons_type::*;
 pub use user_pool_policy_type::*;
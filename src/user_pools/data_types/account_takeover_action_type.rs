@@ -0,0 +1,20 @@
+use crate::validator::includes;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+fn validate_event_action(value: &str) -> Result<(), ValidationError> {
+    includes(
+        value,
+        vec!["BLOCK", "MFA_IF_CONFIGURED", "MFA_REQUIRED", "NO_ACTION"],
+    )
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountTakeoverActionType {
+    #[validate(required)]
+    #[validate(custom(function = validate_event_action))]
+    pub event_action: Option<String>,
+    #[validate(required)]
+    pub notify: Option<bool>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountTakeoverActionsType {
+    #[validate(nested)]
+    pub high_action: Option<super::AccountTakeoverActionType>,
+    #[validate(nested)]
+    pub low_action: Option<super::AccountTakeoverActionType>,
+    #[validate(nested)]
+    pub medium_action: Option<super::AccountTakeoverActionType>,
+}
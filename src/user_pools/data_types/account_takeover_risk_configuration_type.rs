@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountTakeoverRiskConfigurationType {
+    #[validate(required)]
+    #[validate(nested)]
+    pub actions: Option<super::AccountTakeoverActionsType>,
+    #[validate(nested)]
+    pub notify_configuration: Option<super::NotifyConfigurationType>,
+}
@@ -0,0 +1,15 @@
+use crate::validator::includes;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+fn validate_event_action(value: &str) -> Result<(), ValidationError> {
+    includes(value, vec!["BLOCK", "NO_ACTION"])
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompromisedCredentialsActionsType {
+    #[validate(required)]
+    #[validate(custom(function = validate_event_action))]
+    pub event_action: Option<String>,
+}
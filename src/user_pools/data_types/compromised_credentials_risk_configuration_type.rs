@@ -0,0 +1,17 @@
+use crate::validator::includes_in_array;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+fn validate_event_filter(value: &[String]) -> Result<(), ValidationError> {
+    includes_in_array(value, vec!["SIGN_IN", "PASSWORD_CHANGE", "SIGN_UP"])
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompromisedCredentialsRiskConfigurationType {
+    #[validate(required)]
+    #[validate(nested)]
+    pub actions: Option<super::CompromisedCredentialsActionsType>,
+    #[validate(custom(function = validate_event_filter))]
+    pub event_filter: Option<Vec<String>>,
+}
@@ -0,0 +1,12 @@
+use crate::common::ARN_REGEX;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct CustomDomainConfigType {
+    #[validate(required)]
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex(path = *ARN_REGEX))]
+    pub certificate_arn: Option<String>,
+}
@@ -4,6 +4,6 @@ use validator::Validate;
 #[derive(Serialize, Deserialize, Debug, Default, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct DeviceSecretVerifierConfigType {
-    password_verifier: Option<String>,
-    salt: Option<String>,
+    pub password_verifier: Option<String>,
+    pub salt: Option<String>,
 }
@@ -0,0 +1,20 @@
+use crate::common::ARN_REGEX;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct NotifyConfigurationType {
+    #[validate(nested)]
+    pub block_email: Option<super::NotifyEmailType>,
+    pub from: Option<String>,
+    #[validate(nested)]
+    pub mfa_email: Option<super::NotifyEmailType>,
+    #[validate(nested)]
+    pub no_action_email: Option<super::NotifyEmailType>,
+    pub reply_to: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex(path = *ARN_REGEX))]
+    pub source_arn: Option<String>,
+}
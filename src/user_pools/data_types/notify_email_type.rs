@@ -0,0 +1,25 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+static BODY_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\p{L}\p{M}\p{S}\p{N}\p{P}\s*]+").unwrap());
+
+static SUBJECT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[\p{L}\p{M}\p{S}\p{N}\p{P}\s]+").unwrap());
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct NotifyEmailType {
+    #[validate(length(min = 6, max = 20000))]
+    #[validate(regex(path = *BODY_REGEX))]
+    pub html_body: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 140))]
+    #[validate(regex(path = *SUBJECT_REGEX))]
+    pub subject: Option<String>,
+    #[validate(length(min = 6, max = 20000))]
+    #[validate(regex(path = *BODY_REGEX))]
+    pub text_body: Option<String>,
+}
@@ -1,14 +1,27 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-static DEVICE_KEY_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w-]+_[0-9a-f-]+").unwrap());
+static NUMBER_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^-?[0-9]+$").unwrap());
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+fn validate_value_range(value: &NumberAttributeConstraintsType) -> Result<(), ValidationError> {
+    let min = value.min_value.as_ref().and_then(|v| v.parse::<i64>().ok());
+    let max = value.max_value.as_ref().and_then(|v| v.parse::<i64>().ok());
+    match (min, max) {
+        (Some(min), Some(max)) if min > max => Err(ValidationError::new(
+            "MinValue must be less than or equal to MaxValue",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
+#[validate(schema(function = validate_value_range))]
 pub struct NumberAttributeConstraintsType {
-    max_value: Option<String>,
-    #[validate(regex(path = *DEVICE_KEY_REGEX))]
-    min_value: Option<String>,
+    #[validate(regex(path = *NUMBER_REGEX))]
+    pub max_value: Option<String>,
+    #[validate(regex(path = *NUMBER_REGEX))]
+    pub min_value: Option<String>,
 }
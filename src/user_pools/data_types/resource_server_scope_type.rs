@@ -9,8 +9,10 @@ static SCOPE_NAME_REGEX: Lazy<Regex> =
 #[derive(Serialize, Deserialize, Debug, Default, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct ResourceServerScopeType {
+    #[validate(required)]
     #[validate(length(min = 1, max = 256))]
     pub scope_description: Option<String>,
+    #[validate(required)]
     #[validate(length(min = 1, max = 256))]
     #[validate(regex(path = *SCOPE_NAME_REGEX))]
     pub scope_name: Option<String>,
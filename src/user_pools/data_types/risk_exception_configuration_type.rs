@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+fn validate_ip_ranges(value: &[String]) -> Result<(), ValidationError> {
+    if value
+        .iter()
+        .all(|range| crate::user_pools::is_ip_range(range))
+    {
+        return Ok(());
+    }
+    Err(ValidationError::new("is not ip address range"))
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct RiskExceptionConfigurationType {
+    #[serde(rename = "BlockedIPRangeList")]
+    #[validate(length(max = 200))]
+    #[validate(custom(function = validate_ip_ranges))]
+    pub blocked_ip_range_list: Option<Vec<String>>,
+    #[serde(rename = "SkippedIPRangeList")]
+    #[validate(length(max = 200))]
+    #[validate(custom(function = validate_ip_ranges))]
+    pub skipped_ip_range_list: Option<Vec<String>>,
+}
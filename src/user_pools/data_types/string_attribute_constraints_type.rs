@@ -1,9 +1,33 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+static LENGTH_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[0-9]+$").unwrap());
+
+fn validate_length_range(value: &StringAttributeConstraintsType) -> Result<(), ValidationError> {
+    let min = value
+        .min_length
+        .as_ref()
+        .and_then(|v| v.parse::<u64>().ok());
+    let max = value
+        .max_length
+        .as_ref()
+        .and_then(|v| v.parse::<u64>().ok());
+    match (min, max) {
+        (Some(min), Some(max)) if min > max => Err(ValidationError::new(
+            "MinLength must be less than or equal to MaxLength",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
+#[validate(schema(function = validate_length_range))]
 pub struct StringAttributeConstraintsType {
-    max_length: Option<String>,
-    min_length: Option<String>,
+    #[validate(regex(path = *LENGTH_REGEX))]
+    pub max_length: Option<String>,
+    #[validate(regex(path = *LENGTH_REGEX))]
+    pub min_length: Option<String>,
 }
@@ -10,9 +10,9 @@ fn includes_valid_time_unit(value: &str) -> Result<(), ValidationError> {
 #[serde(rename_all = "PascalCase")]
 pub struct TokenValidityUnitsType {
     #[validate(custom(function = includes_valid_time_unit))]
-    access_token: Option<String>,
+    pub access_token: Option<String>,
     #[validate(custom(function = includes_valid_time_unit))]
-    id_token: Option<String>,
+    pub id_token: Option<String>,
     #[validate(custom(function = includes_valid_time_unit))]
-    refresh_token: Option<String>,
+    pub refresh_token: Option<String>,
 }
@@ -0,0 +1,14 @@
+use crate::validator::includes_in_array;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+fn validate_attributes(value: &[String]) -> Result<(), ValidationError> {
+    includes_in_array(value, vec!["phone_number", "email"])
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserAttributeUpdateSettingsType {
+    #[validate(custom(function = validate_attributes))]
+    attributes_require_verification_before_update: Option<Vec<String>>,
+}
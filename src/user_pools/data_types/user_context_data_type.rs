@@ -4,5 +4,6 @@ use validator::Validate;
 #[derive(Serialize, Deserialize, Debug, Default, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct UserContextDataType {
-    encoded_data: Option<String>,
+    pub encoded_data: Option<String>,
+    pub ip_address: Option<String>,
 }
@@ -5,5 +5,5 @@ use validator::Validate;
 #[serde(rename_all = "PascalCase")]
 pub struct UserPoolPolicyType {
     #[validate(nested)]
-    password_policy: Option<super::PasswordPolicyType>,
+    pub password_policy: Option<super::PasswordPolicyType>,
 }
@@ -0,0 +1,88 @@
+use crate::common::{RESOURCE_SERVER_IDENTIFIER_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DELETE_RESOURCE_SERVER_NAME: &str = "DeleteResourceServer";
+pub const DELETE_RESOURCE_SERVER_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DeleteResourceServer";
+
+super::gen_response_err!(
+    DeleteResourceServerError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteResourceServerRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 256))]
+    #[validate(regex(path = *RESOURCE_SERVER_IDENTIFIER_REGEX))]
+    pub identifier: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DeleteResourceServerRequest {
+    fn to_action_name() -> &'static str {
+        DELETE_RESOURCE_SERVER_NAME
+    }
+}
+
+impl super::ToResponse for DeleteResourceServerRequest {
+    type E = DeleteResourceServerError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.remove_resource_server(self.identifier.as_deref().unwrap_or_default())
+            })
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DeleteResourceServerRequest {
+            identifier: Some("identifier".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DeleteResourceServerRequest {
+            identifier: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = DeleteResourceServerError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DeleteResourceServerError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DELETE_USER_NAME: &str = "DeleteUser";
+pub const DELETE_USER_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.DeleteUser";
+
+super::gen_response_err!(
+    DeleteUserError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteUserRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+}
+
+impl super::ToActionName for DeleteUserRequest {
+    fn to_action_name() -> &'static str {
+        DELETE_USER_NAME
+    }
+}
+
+impl super::ToResponse for DeleteUserRequest {
+    type E = DeleteUserError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| user_pool.remove_user(&user.username).map(|_| ()),
+            )
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DeleteUserRequest {
+            access_token: Some("access_token".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DeleteUserRequest {
+            access_token: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = DeleteUserError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DeleteUserError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
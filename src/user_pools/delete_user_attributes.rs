@@ -0,0 +1,105 @@
+use crate::common::{NAME_REGEX, TOKEN_REGEX};
+use crate::{http, validator::regex_in_array};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const DELETE_USER_ATTRIBUTES_NAME: &str = "DeleteUserAttributes";
+pub const DELETE_USER_ATTRIBUTES_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DeleteUserAttributes";
+
+fn validate_user_attribute_names_regex(value: &[String]) -> Result<(), ValidationError> {
+    regex_in_array(value, &NAME_REGEX)
+}
+
+super::gen_response_err!(
+    DeleteUserAttributesError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteUserAttributesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 32))]
+    #[validate(custom(function = validate_user_attribute_names_regex))]
+    pub user_attribute_names: Option<Vec<String>>,
+}
+
+impl super::ToActionName for DeleteUserAttributesRequest {
+    fn to_action_name() -> &'static str {
+        DELETE_USER_ATTRIBUTES_NAME
+    }
+}
+
+impl super::ToResponse for DeleteUserAttributesRequest {
+    type E = DeleteUserAttributesError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    user_pool.check_removed_user_attributes(
+                        self.user_attribute_names
+                            .iter()
+                            .flatten()
+                            .map(String::as_str),
+                    )?;
+                    let user = user_pool.user_mut(&user.username)?;
+                    for name in self.user_attribute_names.iter().flatten() {
+                        user.remove_attribute(name);
+                    }
+                    Ok(())
+                },
+            )
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DeleteUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            user_attribute_names: Some(vec!["name".to_string()]),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DeleteUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            user_attribute_names: Some(vec![]),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = DeleteUserAttributesError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DeleteUserAttributesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::common::{CLIENT_ID_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DESCRIBE_RISK_CONFIGURATION_NAME: &str = "DescribeRiskConfiguration";
+pub const DESCRIBE_RISK_CONFIGURATION_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DescribeRiskConfiguration";
+
+super::gen_response_err!(
+    DescribeRiskConfigurationError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserPoolAddOnNotEnabledException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DescribeRiskConfigurationRequest {
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DescribeRiskConfigurationRequest {
+    fn to_action_name() -> &'static str {
+        DESCRIBE_RISK_CONFIGURATION_NAME
+    }
+}
+
+impl super::ToResponse for DescribeRiskConfigurationRequest {
+    type E = DescribeRiskConfigurationError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        super::to_converted_json_response(self, DESCRIBE_RISK_CONFIGURATION_NAME, |mut value| {
+            let client_id = self.client_id.as_deref();
+            let configuration = if super::user_pool_exists(user_pool_id) {
+                super::with_user_pool(user_pool_id, |user_pool| {
+                    if let Some(client_id) = client_id {
+                        user_pool.client(client_id)?;
+                    }
+                    Ok(match user_pool.risk_configuration(client_id) {
+                        Some(configuration) => configuration.clone(),
+                        None => super::risk_configuration(&user_pool.risk_rules(client_id)),
+                    })
+                })
+                .map_err(super::to_response_error)?
+            } else {
+                super::risk_configuration(&super::configured_risk_rules())
+            };
+            super::merge_value(&mut value["RiskConfiguration"], configuration);
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DescribeRiskConfigurationRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DescribeRiskConfigurationRequest {
+            user_pool_id: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = DescribeRiskConfigurationError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DescribeRiskConfigurationError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DESCRIBE_USER_POOL_NAME: &str = "DescribeUserPool";
+pub const DESCRIBE_USER_POOL_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DescribeUserPool";
+
+super::gen_response_err!(
+    DescribeUserPoolError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserPoolTaggingException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DescribeUserPoolRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DescribeUserPoolRequest {
+    fn to_action_name() -> &'static str {
+        DESCRIBE_USER_POOL_NAME
+    }
+}
+
+impl super::ToResponse for DescribeUserPoolRequest {
+    type E = DescribeUserPoolError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, DESCRIBE_USER_POOL_NAME);
+        }
+        super::to_converted_json_response(self, DESCRIBE_USER_POOL_NAME, |mut value| {
+            value["UserPool"] = super::with_user_pool(user_pool_id, |user_pool| {
+                let mut description = user_pool.description.clone();
+                description["EstimatedNumberOfUsers"] = user_pool.users.len().into();
+                description["UserPoolTags"] =
+                    serde_json::json!(super::resource_tags(&user_pool.arn()).unwrap_or_default());
+                Ok(description)
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DescribeUserPoolRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DescribeUserPoolRequest {
+            user_pool_id: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = DescribeUserPoolError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DescribeUserPoolError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::common::DOMAIN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DESCRIBE_USER_POOL_DOMAIN_NAME: &str = "DescribeUserPoolDomain";
+pub const DESCRIBE_USER_POOL_DOMAIN_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DescribeUserPoolDomain";
+
+super::gen_response_err!(
+    DescribeUserPoolDomainError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DescribeUserPoolDomainRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 63))]
+    #[validate(regex(path = *DOMAIN_REGEX))]
+    pub domain: Option<String>,
+}
+
+impl super::ToActionName for DescribeUserPoolDomainRequest {
+    fn to_action_name() -> &'static str {
+        DESCRIBE_USER_POOL_DOMAIN_NAME
+    }
+}
+
+impl super::ToResponse for DescribeUserPoolDomainRequest {
+    type E = DescribeUserPoolDomainError;
+    fn to_response(&self) -> super::Response {
+        let domain = self.domain.as_deref().unwrap_or_default();
+        let Some(user_pool_id) = super::domain_user_pool_id(domain) else {
+            return super::to_json_response(self, DESCRIBE_USER_POOL_DOMAIN_NAME);
+        };
+        super::to_converted_json_response(self, DESCRIBE_USER_POOL_DOMAIN_NAME, |mut value| {
+            value["DomainDescription"] = super::with_user_pool(&user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .domains
+                    .get(domain)
+                    .map(|d| d.to_value(&user_pool_id))
+                    .unwrap_or_default())
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DescribeUserPoolDomainRequest {
+            domain: Some("auth-prefix".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DescribeUserPoolDomainRequest {
+            domain: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = DescribeUserPoolDomainError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DescribeUserPoolDomainError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,220 @@
+use super::{srp, StoreError, StoreResult, UserPoolState};
+use crate::common::{self, ALPHANUMERIC};
+use chrono::{DateTime, Utc};
+
+/// Challenge of a user who signs in on a confirmed device.
+pub const DEVICE_SRP_AUTH_CHALLENGE: &str = "DEVICE_SRP_AUTH";
+
+/// Challenge to prove the device password after `DEVICE_SRP_AUTH`.
+pub const DEVICE_PASSWORD_VERIFIER_CHALLENGE: &str = "DEVICE_PASSWORD_VERIFIER";
+
+const REMEMBERED: &str = "remembered";
+const NOT_REMEMBERED: &str = "not_remembered";
+const DEVICE_GROUP_KEY_LENGTH: usize = 9;
+
+/// Device which a user signed in on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceState {
+    pub device_key: String,
+    pub device_group_key: String,
+    pub name: Option<String>,
+    /// `PasswordVerifier` of ConfirmDevice in hex.
+    pub password_verifier: Option<String>,
+    /// `Salt` of ConfirmDevice in hex.
+    pub salt: Option<String>,
+    pub confirmed: bool,
+    pub remembered: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_modified_at: DateTime<Utc>,
+    pub last_authenticated_at: DateTime<Utc>,
+}
+
+/// Keys of a device tracked by an authentication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDeviceMetadata {
+    pub device_key: String,
+    pub device_group_key: String,
+}
+
+impl NewDeviceMetadata {
+    /// Returns the `NewDeviceMetadataType` of the responses.
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "DeviceGroupKey": self.device_group_key,
+            "DeviceKey": self.device_key,
+        })
+    }
+}
+
+impl DeviceState {
+    /// Returns the `DeviceType` of the responses.
+    pub fn to_value(&self) -> serde_json::Value {
+        let mut attributes = vec![
+            serde_json::json!({ "Name": "device_status", "Value": "valid" }),
+            serde_json::json!({
+                "Name": "dev:device_remembered_status",
+                "Value": if self.remembered { REMEMBERED } else { NOT_REMEMBERED },
+            }),
+        ];
+        if let Some(name) = self.name.as_ref() {
+            attributes.push(serde_json::json!({ "Name": "device_name", "Value": name }));
+        }
+        serde_json::json!({
+            "DeviceAttributes": attributes,
+            "DeviceCreateDate": self.created_at.timestamp(),
+            "DeviceKey": self.device_key,
+            "DeviceLastAuthenticatedDate": self.last_authenticated_at.timestamp(),
+            "DeviceLastModifiedDate": self.last_modified_at.timestamp(),
+        })
+    }
+
+    /// Returns whether a user can sign in on the device by `DEVICE_SRP_AUTH`.
+    pub fn is_verifiable(&self) -> bool {
+        self.confirmed && self.password_verifier.is_some() && self.salt.is_some()
+    }
+}
+
+fn device_not_found() -> (StoreError, String) {
+    (
+        StoreError::ResourceNotFoundException,
+        "Device does not exist.".to_string(),
+    )
+}
+
+impl UserPoolState {
+    /// Returns whether the user pool tracks devices by `DeviceConfiguration`.
+    pub fn tracks_devices(&self) -> bool {
+        self.description
+            .get("DeviceConfiguration")
+            .is_some_and(|c| c.is_object())
+    }
+
+    fn remembers_devices_on_user_prompt(&self) -> bool {
+        self.description
+            .pointer("/DeviceConfiguration/DeviceOnlyRememberedOnUserPrompt")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Tracks a new device of a user, which the user confirms by ConfirmDevice.
+    pub fn new_device(&mut self, username: &str) -> StoreResult<NewDeviceMetadata> {
+        let now = Utc::now();
+        let device = DeviceState {
+            device_key: format!("{}_{}", super::REGION, common::new_uuid()),
+            device_group_key: format!(
+                "-{}",
+                common::random_string(ALPHANUMERIC, DEVICE_GROUP_KEY_LENGTH)
+            ),
+            name: None,
+            password_verifier: None,
+            salt: None,
+            confirmed: false,
+            remembered: false,
+            created_at: now,
+            last_modified_at: now,
+            last_authenticated_at: now,
+        };
+        let metadata = NewDeviceMetadata {
+            device_key: device.device_key.clone(),
+            device_group_key: device.device_group_key.clone(),
+        };
+        self.user_mut(username)?
+            .devices
+            .insert(device.device_key.clone(), device);
+        Ok(metadata)
+    }
+
+    /// Returns a device of a user.
+    pub fn device(&self, username: &str, device_key: &str) -> StoreResult<&DeviceState> {
+        self.user(username)?
+            .devices
+            .get(device_key)
+            .ok_or_else(device_not_found)
+    }
+
+    fn device_mut(&mut self, username: &str, device_key: &str) -> StoreResult<&mut DeviceState> {
+        self.user_mut(username)?
+            .devices
+            .get_mut(device_key)
+            .ok_or_else(device_not_found)
+    }
+
+    /// Confirms a device with the verifier of its password (ConfirmDevice).
+    ///
+    /// Returns `UserConfirmationNecessary`, i.e. whether the device is remembered only on a user prompt.
+    pub fn confirm_device(
+        &mut self,
+        username: &str,
+        device_key: &str,
+        name: Option<&str>,
+        verifier: Option<&super::DeviceSecretVerifierConfigType>,
+    ) -> StoreResult<bool> {
+        let on_user_prompt = self.remembers_devices_on_user_prompt();
+        let invalid_verifier = || {
+            (
+                StoreError::InvalidParameterException,
+                "Invalid device secret verifier config.".to_string(),
+            )
+        };
+        let (password_verifier, salt) = match verifier {
+            Some(verifier) => (
+                Some(
+                    verifier
+                        .password_verifier
+                        .as_deref()
+                        .and_then(srp::base64_to_hex)
+                        .ok_or_else(invalid_verifier)?,
+                ),
+                Some(
+                    verifier
+                        .salt
+                        .as_deref()
+                        .and_then(srp::base64_to_hex)
+                        .ok_or_else(invalid_verifier)?,
+                ),
+            ),
+            None => (None, None),
+        };
+        let device = self.device_mut(username, device_key)?;
+        device.name = name.map(str::to_string);
+        device.password_verifier = password_verifier;
+        device.salt = salt;
+        device.confirmed = true;
+        device.remembered = !on_user_prompt;
+        device.last_modified_at = Utc::now();
+        Ok(on_user_prompt)
+    }
+
+    /// Updates `DeviceRememberedStatus` of a device.
+    pub fn update_device_status(
+        &mut self,
+        username: &str,
+        device_key: &str,
+        status: Option<&str>,
+    ) -> StoreResult<()> {
+        let device = self.device_mut(username, device_key)?;
+        device.remembered = status == Some(REMEMBERED);
+        device.last_modified_at = Utc::now();
+        Ok(())
+    }
+
+    /// Forgets a device of a user and revokes the tokens issued on it.
+    pub fn forget_device(&mut self, username: &str, device_key: &str) -> StoreResult<()> {
+        let user = self.user_mut(username)?;
+        user.devices
+            .remove(device_key)
+            .ok_or_else(device_not_found)?;
+        for session in self.sessions.values_mut() {
+            if session.device_key.as_deref() == Some(device_key) {
+                session.revoked = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks a device as authenticated now.
+    pub fn authenticate_device(&mut self, username: &str, device_key: &str) -> StoreResult<()> {
+        self.device_mut(username, device_key)?.last_authenticated_at = Utc::now();
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use super::{StoreError, StoreResult, UserPoolState, ACCOUNT_ID, REGION};
+use crate::common::{self, LOWERCASE_ALPHANUMERIC};
+use chrono::{DateTime, Utc};
+
+/// Error message of creating or updating a domain which another user pool uses.
+pub const DOMAIN_IN_USE_MESSAGE: &str = "Domain already associated with another user pool.";
+
+const CLOUD_FRONT_DOMAIN_ID_LENGTH: usize = 14;
+const S3_BUCKET: &str = "aws-cognito-prod-iad-assets";
+
+/// Domain of a user pool created by CreateUserPoolDomain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainState {
+    pub domain: String,
+    /// `CertificateArn` of a custom domain, or `None` for a prefix domain.
+    pub certificate_arn: Option<String>,
+    pub cloud_front_domain: String,
+    pub version: DateTime<Utc>,
+}
+
+impl DomainState {
+    pub fn new(domain: &str, certificate_arn: Option<String>) -> Self {
+        DomainState {
+            domain: domain.to_string(),
+            certificate_arn,
+            cloud_front_domain: format!(
+                "d{}.cloudfront.net",
+                common::random_string(LOWERCASE_ALPHANUMERIC, CLOUD_FRONT_DOMAIN_ID_LENGTH)
+            ),
+            version: Utc::now(),
+        }
+    }
+
+    /// Returns the host of the hosted UI of the domain (e.g. `<prefix>.auth.us-east-1.amazoncognito.com`).
+    pub fn host(&self) -> String {
+        match self.certificate_arn {
+            Some(_) => self.domain.clone(),
+            None => format!("{}.auth.{}.amazoncognito.com", self.domain, REGION),
+        }
+    }
+
+    /// Returns `DomainDescription` of DescribeUserPoolDomain.
+    pub fn to_value(&self, user_pool_id: &str) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "AWSAccountId": ACCOUNT_ID,
+            "CloudFrontDomain": self.cloud_front_domain,
+            "Domain": self.domain,
+            "S3Bucket": S3_BUCKET,
+            "Status": "ACTIVE",
+            "UserPoolId": user_pool_id,
+            "Version": self.version.format("%Y%m%d%H%M%S").to_string(),
+        });
+        if let Some(certificate_arn) = self.certificate_arn.as_deref() {
+            value["CustomDomainConfig"] = serde_json::json!({ "CertificateArn": certificate_arn });
+        }
+        value
+    }
+}
+
+/// Returns the id of the stored user pool which uses a domain.
+pub fn domain_user_pool_id(domain: &str) -> Option<String> {
+    super::find_user_pool_id(|user_pool| user_pool.domains.contains_key(domain))
+}
+
+/// Returns the id of the stored user pool whose domain serves a `Host` header.
+pub fn host_user_pool_id(host: &str) -> Option<String> {
+    let host = host
+        .split(':')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    super::find_user_pool_id(|user_pool| user_pool.domains.values().any(|d| d.host() == host))
+}
+
+/// Returns an error message if a domain is already in use by a user pool other than `user_pool_id`.
+///
+/// `user_pool_id` is `None` when any user pool using the domain is a conflict (e.g. creating).
+pub fn check_domain_in_use(domain: &str, user_pool_id: Option<&str>) -> Option<String> {
+    domain_user_pool_id(domain)
+        .filter(|used_by| user_pool_id.is_none_or(|id| id != used_by))
+        .map(|_| DOMAIN_IN_USE_MESSAGE.to_string())
+}
+
+fn domain_not_found() -> (StoreError, String) {
+    (
+        StoreError::InvalidParameterException,
+        "No such domain or user pool exists.".to_string(),
+    )
+}
+
+impl UserPoolState {
+    /// Adds a prefix or custom domain, at most one of each kind to a user pool.
+    pub fn add_domain(&mut self, domain: DomainState) -> StoreResult<&DomainState> {
+        let is_custom = domain.certificate_arn.is_some();
+        if self
+            .domains
+            .values()
+            .any(|d| d.certificate_arn.is_some() == is_custom)
+        {
+            return Err((
+                StoreError::InvalidParameterException,
+                format!("User pool {} already has a domain configured.", self.id),
+            ));
+        }
+        Ok(self.domains.entry(domain.domain.clone()).or_insert(domain))
+    }
+
+    /// Replaces the certificate of a custom domain (UpdateUserPoolDomain).
+    pub fn update_domain(
+        &mut self,
+        domain: &str,
+        certificate_arn: Option<String>,
+    ) -> StoreResult<&DomainState> {
+        let stored = self.domains.get_mut(domain).ok_or_else(domain_not_found)?;
+        if stored.certificate_arn.is_none() {
+            return Err((
+                StoreError::InvalidParameterException,
+                "Only custom domains can be updated.".to_string(),
+            ));
+        }
+        stored.certificate_arn = certificate_arn.or(stored.certificate_arn.take());
+        stored.version = Utc::now();
+        Ok(stored)
+    }
+
+    /// Removes a domain of a user pool.
+    pub fn remove_domain(&mut self, domain: &str) -> StoreResult<()> {
+        self.domains
+            .remove(domain)
+            .map(|_| ())
+            .ok_or_else(domain_not_found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn user_pool_has_one_domain_of_each_kind() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_domain(DomainState::new("auth-prefix", None))
+            .unwrap();
+        assert_eq!(
+            Err(StoreError::InvalidParameterException),
+            user_pool
+                .add_domain(DomainState::new("other-prefix", None))
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        let custom = DomainState::new("auth.example.com", Some("arn".to_string()));
+        assert_eq!("auth.example.com", custom.host());
+        user_pool.add_domain(custom).unwrap();
+
+        let prefix = &user_pool.domains["auth-prefix"];
+        assert_eq!(
+            "auth-prefix.auth.us-east-1.amazoncognito.com",
+            prefix.host()
+        );
+        assert_eq!(
+            Err(StoreError::InvalidParameterException),
+            user_pool
+                .update_domain("auth-prefix", None)
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        user_pool.remove_domain("auth-prefix").unwrap();
+        assert!(user_pool.remove_domain("auth-prefix").is_err());
+    }
+}
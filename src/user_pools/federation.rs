@@ -0,0 +1,516 @@
+use super::{LoginQuery, StoreError, StoreResult, UserPoolState, UserState, UserStatus};
+use crate::{common, mock_idp};
+use chrono::{DateTime, Duration, Utc};
+
+/// Path of the endpoint which receives the authorization codes of identity providers under
+/// `OAUTH2_PATH`.
+pub const IDP_RESPONSE_PATH: &str = "idpresponse";
+
+const AUTHORIZE_URL_DETAIL: &str = "authorize_url";
+const TOKEN_URL_DETAIL: &str = "token_url";
+const OIDC_ISSUER_DETAIL: &str = "oidc_issuer";
+const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+const FEDERATION_VALIDITY_MINUTES: i64 = 10;
+
+/// Provider types whose endpoints are not given by ProviderDetails, which sign in through the
+/// built-in mock identity provider.
+const SOCIAL_PROVIDER_TYPES: [&str; 4] =
+    ["Facebook", "Google", "LoginWithAmazon", "SignInWithApple"];
+
+/// Sign-in through an identity provider which waits for its authorization code.
+#[derive(Debug, Clone)]
+pub struct FederationState {
+    pub provider_name: String,
+    /// Query of `/oauth2/authorize` which the tokens are returned for.
+    pub query: LoginQuery,
+    /// `redirect_uri` sent to the identity provider.
+    pub idp_response_uri: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Request to the token endpoint of an identity provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenRequest {
+    pub token_url: String,
+    /// Body of `application/x-www-form-urlencoded`.
+    pub form: String,
+}
+
+fn provider_detail<'a>(provider: &'a serde_json::Value, name: &str) -> StoreResult<&'a str> {
+    provider["ProviderDetails"][name]
+        .as_str()
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| {
+            (
+                StoreError::InvalidParameterException,
+                format!(
+                    "{} of ProviderDetails is required to sign in through {}.",
+                    name, provider["ProviderName"]
+                ),
+            )
+        })
+}
+
+/// Returns an endpoint in ProviderDetails, or the endpoint of the built-in mock identity provider
+/// for social identity providers.
+fn provider_endpoint(provider: &serde_json::Value, name: &str) -> StoreResult<String> {
+    let is_social = provider["ProviderType"]
+        .as_str()
+        .is_some_and(|t| SOCIAL_PROVIDER_TYPES.contains(&t));
+    match provider_detail(provider, name) {
+        Ok(endpoint) => Ok(endpoint.to_string()),
+        Err(_) if is_social => Ok(match name {
+            AUTHORIZE_URL_DETAIL => format!("{}/{}", mock_idp::issuer(), mock_idp::AUTHORIZE_PATH),
+            TOKEN_URL_DETAIL => format!("{}/{}", mock_idp::issuer(), mock_idp::TOKEN_PATH),
+            _ => mock_idp::issuer(),
+        }),
+        Err(e) => Err(e),
+    }
+}
+
+fn form_string(pairs: &[(&str, &str)]) -> String {
+    pairs
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, common::uri_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn claim_value(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Bool(_) | serde_json::Value::Number(_) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Sends the request to the token endpoint of an identity provider and returns its response.
+///
+/// Only `http` endpoints (e.g. the built-in mock identity provider) can be reached.
+pub async fn exchange_code(request: &TokenRequest) -> Result<serde_json::Value, String> {
+    let http_request = warp::http::Request::post(request.token_url.as_str())
+        .header("Content-Type", FORM_CONTENT_TYPE)
+        .body(warp::hyper::Body::from(request.form.clone()))
+        .map_err(|e| e.to_string())?;
+    let response = warp::hyper::Client::new()
+        .request(http_request)
+        .await
+        .map_err(|e| format!("{} cannot be reached: {}", request.token_url, e))?;
+    let status = response.status();
+    let body = warp::hyper::body::to_bytes(response.into_body())
+        .await
+        .map_err(|e| e.to_string())?;
+    if !status.is_success() {
+        return Err(format!(
+            "{} returned {}: {}",
+            request.token_url,
+            status,
+            String::from_utf8_lossy(&body)
+        ));
+    }
+    serde_json::from_slice(&body).map_err(|e| e.to_string())
+}
+
+impl UserPoolState {
+    /// Returns the authorize URL of an identity provider which sends the code to
+    /// `idp_response_uri`.
+    pub fn start_federation(
+        &mut self,
+        provider_name: &str,
+        query: &LoginQuery,
+        idp_response_uri: &str,
+    ) -> StoreResult<String> {
+        let now = Utc::now();
+        self.federations.retain(|_, f| f.expires_at > now);
+        let provider = self.identity_provider(provider_name)?;
+        let authorize_url = provider_endpoint(provider, AUTHORIZE_URL_DETAIL)?;
+        let client_id = provider_detail(provider, "client_id")?;
+        let scopes = provider["ProviderDetails"]["authorize_scopes"]
+            .as_str()
+            .unwrap_or("openid");
+        let state = common::new_uuid();
+        let location = format!(
+            "{}{}{}",
+            authorize_url,
+            if authorize_url.contains('?') {
+                "&"
+            } else {
+                "?"
+            },
+            form_string(&[
+                ("client_id", client_id),
+                ("redirect_uri", idp_response_uri),
+                ("response_type", "code"),
+                ("scope", scopes),
+                ("state", &state),
+            ])
+        );
+        self.federations.insert(
+            state,
+            FederationState {
+                provider_name: provider_name.to_string(),
+                query: query.clone(),
+                idp_response_uri: idp_response_uri.to_string(),
+                expires_at: now + Duration::minutes(FEDERATION_VALIDITY_MINUTES),
+            },
+        );
+        Ok(location)
+    }
+
+    /// Takes the sign-in waiting for a code and returns the request which exchanges the code.
+    ///
+    /// The sign-in is removed even if it fails, so a state can be used only once.
+    pub fn take_federation(
+        &mut self,
+        state: &str,
+        code: &str,
+    ) -> StoreResult<(FederationState, TokenRequest)> {
+        let federation = self.federations.remove(state).ok_or_else(|| {
+            (
+                StoreError::NotAuthorizedException,
+                "The state is not issued by the user pool.".to_string(),
+            )
+        })?;
+        if federation.expires_at <= Utc::now() {
+            return Err((
+                StoreError::NotAuthorizedException,
+                "The state has expired.".to_string(),
+            ));
+        }
+        let provider = self.identity_provider(&federation.provider_name)?;
+        let details = &provider["ProviderDetails"];
+        let mut pairs = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", federation.idp_response_uri.as_str()),
+            ("client_id", provider_detail(provider, "client_id")?),
+        ];
+        if let Some(secret) = details["client_secret"].as_str() {
+            pairs.push(("client_secret", secret));
+        }
+        let request = TokenRequest {
+            token_url: provider_endpoint(provider, TOKEN_URL_DETAIL)?,
+            form: form_string(&pairs),
+        };
+        Ok((federation, request))
+    }
+
+    /// Returns the claims of the ID token of an identity provider if it is issued by the provider
+    /// for its `client_id` and has not expired.
+    ///
+    /// The signature of the token is not verified.
+    pub fn id_token_claims(
+        &self,
+        provider_name: &str,
+        id_token: &str,
+    ) -> StoreResult<serde_json::Map<String, serde_json::Value>> {
+        let provider = self.identity_provider(provider_name)?;
+        let invalid = |message: &str| {
+            (
+                StoreError::NotAuthorizedException,
+                format!("Invalid ID token of {}: {}", provider_name, message),
+            )
+        };
+        let claims = crate::jwt::token_claims(id_token).ok_or_else(|| invalid("not a JWT"))?;
+        let issuer = provider_endpoint(provider, OIDC_ISSUER_DETAIL)?;
+        let token_issuer = claims.get("iss").and_then(|iss| iss.as_str());
+        if token_issuer.map(|iss| iss.trim_end_matches('/')) != Some(issuer.trim_end_matches('/')) {
+            return Err(invalid("iss does not match oidc_issuer"));
+        }
+        let client_id = provider_detail(provider, "client_id")?;
+        let audience_matches = match claims.get("aud") {
+            Some(serde_json::Value::String(aud)) => aud == client_id,
+            Some(serde_json::Value::Array(aud)) => aud.iter().any(|a| a == client_id),
+            _ => false,
+        };
+        if !audience_matches {
+            return Err(invalid("aud does not match client_id"));
+        }
+        let expires_at = claims.get("exp").and_then(|exp| exp.as_i64());
+        if expires_at.is_none_or(|exp| exp <= Utc::now().timestamp()) {
+            return Err(invalid("the token has expired"));
+        }
+        Ok(claims)
+    }
+
+    /// Signs in the federated user (e.g. `Google_1234`) of the ID token claims of an identity
+    /// provider, or the native user linked to it, and returns its username.
+    ///
+    /// The federated user is created like other users, and the attributes of `AttributeMapping`
+    /// are updated by the claims on every sign-in.
+    pub fn sign_in_federated_user(
+        &mut self,
+        provider_name: &str,
+        claims: &serde_json::Map<String, serde_json::Value>,
+    ) -> StoreResult<String> {
+        let provider = self.identity_provider(provider_name)?.clone();
+        let subject = claims.get("sub").and_then(claim_value).ok_or_else(|| {
+            (
+                StoreError::NotAuthorizedException,
+                "The ID token of the identity provider has no sub claim.".to_string(),
+            )
+        })?;
+        let federated_username = format!("{}_{}", provider_name, subject);
+        if let Some(username) = self.linked_username(&federated_username) {
+            self.user(&username)?.check_enabled()?;
+            return Ok(username);
+        }
+        let mapping = provider["AttributeMapping"].as_object();
+        let mut attributes = mapping
+            .into_iter()
+            .flatten()
+            .filter(|(attribute, _)| *attribute != "username")
+            .filter_map(|(attribute, claim)| {
+                let value = claims.get(claim.as_str()?).and_then(claim_value)?;
+                Some((attribute.clone(), value))
+            })
+            .collect::<Vec<_>>();
+        let identities = |created_at: DateTime<Utc>| {
+            serde_json::json!([{
+                "dateCreated": created_at.timestamp_millis(),
+                "issuer": null,
+                "primary": true,
+                "providerName": provider_name,
+                "providerType": provider["ProviderType"],
+                "userId": subject,
+            }])
+            .to_string()
+        };
+        let username = match self.user(&federated_username) {
+            Ok(user) => {
+                user.check_enabled()?;
+                let username = user.username.clone();
+                attributes.push(("identities".to_string(), identities(user.created_at)));
+                self.claim_aliases(&username, &attributes, false)?;
+                username
+            }
+            Err(_) => {
+                let mut user = UserState::new(&federated_username, UserStatus::ExternalProvider);
+                attributes.push(("identities".to_string(), identities(user.created_at)));
+                for (name, value) in &attributes {
+                    user.set_attribute(name, value);
+                }
+                return Ok(self.add_user(user)?.username.clone());
+            }
+        };
+        let user = self.user_mut(&username)?;
+        for (name, value) in &attributes {
+            user.set_attribute(name, value);
+        }
+        Ok(username)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn user_pool_with_provider() -> UserPoolState {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_identity_provider(serde_json::json!({
+                "AttributeMapping": { "email": "email", "username": "sub" },
+                "ProviderDetails": {
+                    "authorize_scopes": "openid email",
+                    "authorize_url": "http://localhost:8080/mock-idp/authorize",
+                    "client_id": "idp-client",
+                    "oidc_issuer": "http://localhost:8080/mock-idp",
+                    "token_url": "http://localhost:8080/mock-idp/token",
+                },
+                "ProviderName": "Mock",
+                "ProviderType": "OIDC",
+            }))
+            .unwrap();
+        user_pool
+    }
+
+    #[test]
+    fn federation_exchanges_code_of_its_state() {
+        let mut user_pool = user_pool_with_provider();
+        let location = user_pool
+            .start_federation(
+                "Mock",
+                &LoginQuery::default(),
+                "http://auth.example.com/oauth2/idpresponse",
+            )
+            .unwrap();
+        assert!(location.starts_with(
+            "http://localhost:8080/mock-idp/authorize?client_id=idp-client&redirect_uri=http%3A%2F%2Fauth.example.com%2Foauth2%2Fidpresponse&response_type=code&scope=openid%20email&state="
+        ));
+        let state = location.rsplit_once("state=").unwrap().1;
+        let (federation, request) = user_pool.take_federation(state, "code").unwrap();
+        assert_eq!("Mock", federation.provider_name);
+        assert_eq!("http://localhost:8080/mock-idp/token", request.token_url);
+        assert!(request.form.contains("&code=code&"));
+        assert_eq!(
+            Err(StoreError::NotAuthorizedException),
+            user_pool
+                .take_federation(state, "code")
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+    }
+
+    #[test]
+    fn federated_user_has_mapped_attributes() {
+        let mut user_pool = user_pool_with_provider();
+        let claims = serde_json::json!({ "email": "user@example.com", "sub": "1234" });
+        let username = user_pool
+            .sign_in_federated_user("Mock", claims.as_object().unwrap())
+            .unwrap();
+        assert_eq!("Mock_1234", username);
+        let user = user_pool.user(&username).unwrap();
+        assert_eq!(UserStatus::ExternalProvider, user.status);
+        assert_eq!(Some("user@example.com"), user.attribute("email"));
+        assert!(user.attribute("username").is_none());
+        assert!(user.attribute("identities").unwrap().contains("\"OIDC\""));
+    }
+
+    #[test]
+    fn federated_user_keeps_username_in_user_pool_of_username_attributes() {
+        let mut user_pool = user_pool_with_provider();
+        user_pool.description = serde_json::json!({
+            "UsernameAttributes": ["email"],
+            "UsernameConfiguration": { "CaseSensitive": false },
+        });
+        let claims = serde_json::json!({ "email": "user@example.com", "sub": "1234" });
+        let username = user_pool
+            .sign_in_federated_user("Mock", claims.as_object().unwrap())
+            .unwrap();
+        assert_eq!("Mock_1234", username);
+        assert_eq!("Mock_1234", user_pool.user("mock_1234").unwrap().username);
+        let username = user_pool
+            .sign_in_federated_user("Mock", claims.as_object().unwrap())
+            .unwrap();
+        assert_eq!("Mock_1234", username);
+        assert_eq!(1, user_pool.users.len());
+    }
+
+    #[test]
+    fn federated_user_cannot_take_alias_of_other_user() {
+        let mut user_pool = user_pool_with_provider();
+        user_pool.description = serde_json::json!({ "AliasAttributes": ["email"] });
+        let mut user = UserState::new("user", UserStatus::Confirmed);
+        user.set_attribute("email", "user@example.com");
+        user.set_attribute("email_verified", "true");
+        user_pool.add_user(user).unwrap();
+        user_pool
+            .update_identity_provider(
+                "Mock",
+                serde_json::json!({
+                    "AttributeMapping": { "email": "email", "email_verified": "email_verified" }
+                }),
+            )
+            .unwrap();
+        let claims = serde_json::json!({
+            "email": "user@example.com",
+            "email_verified": true,
+            "sub": "1234",
+        });
+        assert_eq!(
+            Err(StoreError::AliasExistsException),
+            user_pool
+                .sign_in_federated_user("Mock", claims.as_object().unwrap())
+                .map_err(|(e, _)| e)
+        );
+    }
+
+    #[test]
+    fn expired_federation_is_rejected_and_removed() {
+        let mut user_pool = user_pool_with_provider();
+        let location = user_pool
+            .start_federation(
+                "Mock",
+                &LoginQuery::default(),
+                "http://localhost/idpresponse",
+            )
+            .unwrap();
+        let state = location.rsplit_once("state=").unwrap().1;
+        user_pool.federations.get_mut(state).unwrap().expires_at = Utc::now();
+        user_pool
+            .start_federation(
+                "Mock",
+                &LoginQuery::default(),
+                "http://localhost/idpresponse",
+            )
+            .unwrap();
+        assert_eq!(1, user_pool.federations.len());
+        assert!(!user_pool.federations.contains_key(state));
+
+        let state = user_pool.federations.keys().next().unwrap().clone();
+        user_pool.federations.get_mut(&state).unwrap().expires_at = Utc::now();
+        assert_eq!(
+            Err(StoreError::NotAuthorizedException),
+            user_pool
+                .take_federation(&state, "code")
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        assert!(user_pool.federations.is_empty());
+    }
+
+    #[test]
+    fn social_provider_signs_in_through_mock_identity_provider() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_identity_provider(serde_json::json!({
+                "ProviderDetails": { "authorize_scopes": "openid", "client_id": "google-client" },
+                "ProviderName": "Google",
+                "ProviderType": "Google",
+            }))
+            .unwrap();
+        let location = user_pool
+            .start_federation(
+                "Google",
+                &LoginQuery::default(),
+                "http://localhost/idpresponse",
+            )
+            .unwrap();
+        assert!(location.starts_with(&format!(
+            "{}/authorize?client_id=google-client&",
+            mock_idp::issuer()
+        )));
+        let state = location.rsplit_once("state=").unwrap().1;
+        let (_, request) = user_pool.take_federation(state, "code").unwrap();
+        assert_eq!(format!("{}/token", mock_idp::issuer()), request.token_url);
+    }
+
+    #[test]
+    fn id_token_is_checked_against_provider() {
+        let user_pool = user_pool_with_provider();
+        let signer = crate::jwt::token_signer();
+        let id_token = |claims: serde_json::Value| signer.sign(&claims);
+        let exp = Utc::now().timestamp() + 60;
+        let valid = serde_json::json!({
+            "aud": "idp-client",
+            "exp": exp,
+            "iss": "http://localhost:8080/mock-idp",
+            "sub": "1234",
+        });
+        let claims = user_pool
+            .id_token_claims("Mock", &id_token(valid.clone()))
+            .unwrap();
+        assert_eq!("1234", claims["sub"]);
+
+        let mut invalid_tokens = vec!["token".to_string()];
+        for (name, value) in [
+            ("iss", serde_json::json!("http://localhost:8080/other")),
+            ("aud", serde_json::json!("other-client")),
+            ("exp", serde_json::json!(Utc::now().timestamp() - 1)),
+        ] {
+            let mut claims = valid.clone();
+            claims[name] = value;
+            invalid_tokens.push(id_token(claims));
+        }
+        for token in invalid_tokens {
+            assert_eq!(
+                Err(StoreError::NotAuthorizedException),
+                user_pool
+                    .id_token_claims("Mock", &token)
+                    .map(|_| ())
+                    .map_err(|(e, _)| e)
+            );
+        }
+    }
+}
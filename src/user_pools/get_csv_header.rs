@@ -0,0 +1,85 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_CSV_HEADER_NAME: &str = "GetCSVHeader";
+pub const GET_CSV_HEADER_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.GetCSVHeader";
+
+const CSV_HEADER_KEY: &str = "CSVHeader";
+
+super::gen_response_err!(
+    GetCSVHeaderError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetCSVHeaderRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for GetCSVHeaderRequest {
+    fn to_action_name() -> &'static str {
+        GET_CSV_HEADER_NAME
+    }
+}
+
+impl super::ToResponse for GetCSVHeaderRequest {
+    type E = GetCSVHeaderError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        super::to_converted_json_response(self, GET_CSV_HEADER_NAME, |mut value| {
+            if super::user_pool_exists(user_pool_id) {
+                value[CSV_HEADER_KEY] =
+                    super::with_user_pool(user_pool_id, |user_pool| Ok(user_pool.csv_header()))
+                        .map_err(super::to_response_error)?
+                        .into();
+            } else if value.get(CSV_HEADER_KEY).is_none() {
+                value[CSV_HEADER_KEY] = super::csv_header().into();
+            }
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetCSVHeaderRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetCSVHeaderRequest {
+            user_pool_id: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetCSVHeaderError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetCSVHeaderError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::common::{DEVICE_KEY_REGEX, TOKEN_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_DEVICE_NAME: &str = "GetDevice";
+pub const GET_DEVICE_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.GetDevice";
+
+super::gen_response_err!(
+    GetDeviceError,
+    InvalidParameterException
+    | InvalidUserPoolConfigurationException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetDeviceRequest {
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *DEVICE_KEY_REGEX))]
+    pub device_key: Option<String>,
+}
+
+impl super::ToActionName for GetDeviceRequest {
+    fn to_action_name() -> &'static str {
+        GET_DEVICE_NAME
+    }
+}
+
+impl super::ToResponse for GetDeviceRequest {
+    type E = GetDeviceError;
+    fn to_response(&self) -> super::Response {
+        super::to_converted_json_response(self, GET_DEVICE_NAME, |mut value| {
+            value["Device"] = super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    user_pool
+                        .device(
+                            &user.username,
+                            self.device_key.as_deref().unwrap_or_default(),
+                        )
+                        .map(super::DeviceState::to_value)
+                },
+            )
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetDeviceRequest {
+            access_token: Some("access_token".to_string()),
+            device_key: Some("us-east-1_device".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetDeviceRequest {
+            access_token: Some("access_token".to_string()),
+            device_key: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetDeviceError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetDeviceError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
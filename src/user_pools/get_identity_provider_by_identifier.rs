@@ -0,0 +1,97 @@
+use crate::common::{IDP_IDENTIFIER_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_IDENTITY_PROVIDER_BY_IDENTIFIER_NAME: &str = "GetIdentityProviderByIdentifier";
+pub const GET_IDENTITY_PROVIDER_BY_IDENTIFIER_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.GetIdentityProviderByIdentifier";
+
+super::gen_response_err!(
+    GetIdentityProviderByIdentifierError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetIdentityProviderByIdentifierRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 40))]
+    #[validate(regex(path = *IDP_IDENTIFIER_REGEX))]
+    pub idp_identifier: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for GetIdentityProviderByIdentifierRequest {
+    fn to_action_name() -> &'static str {
+        GET_IDENTITY_PROVIDER_BY_IDENTIFIER_NAME
+    }
+}
+
+impl super::ToResponse for GetIdentityProviderByIdentifierRequest {
+    type E = GetIdentityProviderByIdentifierError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, GET_IDENTITY_PROVIDER_BY_IDENTIFIER_NAME);
+        }
+        super::to_converted_json_response(
+            self,
+            GET_IDENTITY_PROVIDER_BY_IDENTIFIER_NAME,
+            |mut value| {
+                value["IdentityProvider"] = super::with_user_pool(user_pool_id, |user_pool| {
+                    user_pool
+                        .identity_provider_by_identifier(
+                            self.idp_identifier.as_deref().unwrap_or_default(),
+                        )
+                        .cloned()
+                })
+                .map_err(super::to_response_error)?;
+                Ok(value)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetIdentityProviderByIdentifierRequest {
+            idp_identifier: Some("example.com".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetIdentityProviderByIdentifierRequest {
+            idp_identifier: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetIdentityProviderByIdentifierError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetIdentityProviderByIdentifierError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
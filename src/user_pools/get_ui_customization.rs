@@ -0,0 +1,100 @@
+use crate::common::{CLIENT_ID_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_UI_CUSTOMIZATION_NAME: &str = "GetUICustomization";
+pub const GET_UI_CUSTOMIZATION_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.GetUICustomization";
+
+super::gen_response_err!(
+    GetUICustomizationError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUICustomizationRequest {
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for GetUICustomizationRequest {
+    fn to_action_name() -> &'static str {
+        GET_UI_CUSTOMIZATION_NAME
+    }
+}
+
+impl super::ToResponse for GetUICustomizationRequest {
+    type E = GetUICustomizationError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, GET_UI_CUSTOMIZATION_NAME);
+        }
+        super::to_converted_json_response(self, GET_UI_CUSTOMIZATION_NAME, |mut value| {
+            let client_id = self.client_id.as_deref();
+            value["UICustomization"] = super::with_user_pool(user_pool_id, |user_pool| {
+                if let Some(client_id) = client_id {
+                    user_pool.client(client_id)?;
+                }
+                Ok(match user_pool.ui_customization(client_id) {
+                    Some((client_id, customization)) => {
+                        customization.to_value(user_pool_id, client_id)
+                    }
+                    None => serde_json::json!({
+                        "ClientId": client_id.unwrap_or(super::ALL_CLIENTS),
+                        "UserPoolId": user_pool_id,
+                    }),
+                })
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetUICustomizationRequest {
+            client_id: Some("client_id".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetUICustomizationRequest {
+            client_id: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetUICustomizationError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetUICustomizationError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
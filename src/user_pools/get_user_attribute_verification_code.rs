@@ -0,0 +1,114 @@
+use crate::common::{NAME_REGEX, TOKEN_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_USER_ATTRIBUTE_VERIFICATION_CODE_NAME: &str = "GetUserAttributeVerificationCode";
+pub const GET_USER_ATTRIBUTE_VERIFICATION_CODE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode";
+
+super::gen_response_err!(
+    GetUserAttributeVerificationCodeError,
+    CodeDeliveryFailureException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | LimitExceededException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUserAttributeVerificationCodeRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 32))]
+    #[validate(regex(path = *NAME_REGEX))]
+    pub attribute_name: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for GetUserAttributeVerificationCodeRequest {
+    fn to_action_name() -> &'static str {
+        GET_USER_ATTRIBUTE_VERIFICATION_CODE_NAME
+    }
+}
+
+impl super::ToResponse for GetUserAttributeVerificationCodeRequest {
+    type E = GetUserAttributeVerificationCodeError;
+    fn to_response(&self) -> super::Response {
+        let mut code = None;
+        let response = super::to_converted_json_response(
+            self,
+            GET_USER_ATTRIBUTE_VERIFICATION_CODE_NAME,
+            |mut value| {
+                let delivery = super::with_access_token_user(
+                    self.access_token.as_deref().unwrap_or_default(),
+                    |user_pool, user| {
+                        user_pool.send_verification_code(
+                            &user.username,
+                            self.attribute_name.as_deref().unwrap_or_default(),
+                        )
+                    },
+                )
+                .map_err(super::to_response_error)?;
+                value["CodeDeliveryDetails"] = delivery.to_value();
+                code = Some(delivery.code);
+                Ok(value)
+            },
+        );
+        super::with_code_header(response, code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetUserAttributeVerificationCodeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("email".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetUserAttributeVerificationCodeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GetUserAttributeVerificationCodeError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetUserAttributeVerificationCodeError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GLOBAL_SIGN_OUT_NAME: &str = "GlobalSignOut";
+pub const GLOBAL_SIGN_OUT_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.GlobalSignOut";
+
+super::gen_response_err!(
+    GlobalSignOutError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GlobalSignOutRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+}
+
+impl super::ToActionName for GlobalSignOutRequest {
+    fn to_action_name() -> &'static str {
+        GLOBAL_SIGN_OUT_NAME
+    }
+}
+
+impl super::ToResponse for GlobalSignOutRequest {
+    type E = GlobalSignOutError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    user_pool.revoke_user_sessions(&user.username);
+                    Ok(())
+                },
+            )
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GlobalSignOutRequest {
+            access_token: Some("access_token".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GlobalSignOutRequest {
+            access_token: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = GlobalSignOutError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GlobalSignOutError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,210 @@
+use super::{StoreError, StoreResult, UserPoolState};
+
+fn group_not_found() -> (StoreError, String) {
+    (
+        StoreError::ResourceNotFoundException,
+        "Group not found.".to_string(),
+    )
+}
+
+fn precedence(group: &serde_json::Value) -> Option<i64> {
+    group["Precedence"].as_i64()
+}
+
+/// Claims of the groups of a user in the tokens.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupClaims {
+    /// `cognito:groups`, the group names in the order of their precedence.
+    pub groups: Vec<String>,
+    /// `cognito:roles`, the role ARNs of the groups.
+    pub roles: Vec<String>,
+    /// `cognito:preferred_role`, the role of the group with the lowest precedence unless another
+    /// group with a role has the same precedence.
+    pub preferred_role: Option<String>,
+}
+
+impl GroupClaims {
+    /// Adds the claims to the claims of a token unless the user belongs to no group.
+    pub fn merge_into(&self, claims: &mut serde_json::Value) {
+        if self.groups.is_empty() {
+            return;
+        }
+        claims["cognito:groups"] = self.groups.clone().into();
+        if !self.roles.is_empty() {
+            claims["cognito:roles"] = self.roles.clone().into();
+        }
+        if let Some(role) = self.preferred_role.as_deref() {
+            claims["cognito:preferred_role"] = role.into();
+        }
+    }
+}
+
+impl UserPoolState {
+    /// Stores `Group` of CreateGroup.
+    pub fn add_group(&mut self, group: serde_json::Value) -> StoreResult<&serde_json::Value> {
+        let group_name = group["GroupName"].as_str().unwrap_or_default().to_string();
+        if self.groups.contains_key(&group_name) {
+            return Err((
+                StoreError::GroupExistsException,
+                "A group with the name already exists.".to_string(),
+            ));
+        }
+        Ok(self.groups.entry(group_name).or_insert(group))
+    }
+
+    /// Returns a stored group by the name.
+    pub fn group(&self, group_name: &str) -> StoreResult<&serde_json::Value> {
+        self.groups.get(group_name).ok_or_else(group_not_found)
+    }
+
+    /// Adds a user to a stored group.
+    pub fn add_user_to_group(&mut self, username: &str, group_name: &str) -> StoreResult<()> {
+        self.group(group_name)?;
+        let user = self.user_mut(username)?;
+        user.groups.insert(group_name.to_string());
+        Ok(())
+    }
+
+    /// Removes a user from a stored group.
+    pub fn remove_user_from_group(&mut self, username: &str, group_name: &str) -> StoreResult<()> {
+        self.group(group_name)?;
+        let user = self.user_mut(username)?;
+        user.groups.remove(group_name);
+        Ok(())
+    }
+
+    /// Returns the groups of a user in the order of their names.
+    pub fn user_groups(&self, username: &str) -> StoreResult<Vec<&serde_json::Value>> {
+        Ok(self
+            .user(username)?
+            .groups
+            .iter()
+            .filter_map(|name| self.groups.get(name))
+            .collect())
+    }
+
+    /// Returns the claims of the groups of a user.
+    pub fn group_claims(&self, username: &str) -> StoreResult<GroupClaims> {
+        let mut groups = self.user_groups(username)?;
+        groups.sort_by_key(|group| (precedence(group).is_none(), precedence(group)));
+        let with_roles = groups
+            .iter()
+            .filter(|group| group["RoleArn"].is_string())
+            .collect::<Vec<_>>();
+        let preferred_role = match with_roles.as_slice() {
+            [first, second, ..] if precedence(first) == precedence(second) => None,
+            [first, ..] if precedence(first).is_some() => first["RoleArn"].as_str(),
+            _ => None,
+        };
+        Ok(GroupClaims {
+            groups: groups
+                .iter()
+                .filter_map(|group| group["GroupName"].as_str())
+                .map(str::to_string)
+                .collect(),
+            roles: with_roles
+                .iter()
+                .filter_map(|group| group["RoleArn"].as_str())
+                .map(str::to_string)
+                .collect(),
+            preferred_role: preferred_role.map(str::to_string),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{UserState, UserStatus};
+    use pretty_assertions::assert_eq;
+
+    fn group(name: &str, precedence: Option<i64>, role_arn: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "GroupName": name,
+            "Precedence": precedence,
+            "RoleArn": role_arn,
+        })
+    }
+
+    fn user_pool_in_groups(groups: Vec<serde_json::Value>) -> UserPoolState {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_user(UserState::new("user", UserStatus::Confirmed))
+            .unwrap();
+        for group in groups {
+            let name = group["GroupName"].as_str().unwrap().to_string();
+            user_pool.add_group(group).unwrap();
+            user_pool.add_user_to_group("user", &name).unwrap();
+        }
+        user_pool
+    }
+
+    #[test]
+    fn groups_are_unique_and_required_by_membership() {
+        let mut user_pool = user_pool_in_groups(vec![group("admins", Some(1), None)]);
+        assert_eq!(
+            Err(StoreError::GroupExistsException),
+            user_pool
+                .add_group(group("admins", None, None))
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        assert_eq!(
+            Err(StoreError::ResourceNotFoundException),
+            user_pool
+                .add_user_to_group("user", "unknown")
+                .map_err(|(e, _)| e)
+        );
+        assert_eq!(
+            Err(StoreError::UserNotFoundException),
+            user_pool
+                .add_user_to_group("unknown", "admins")
+                .map_err(|(e, _)| e)
+        );
+        user_pool.remove_user_from_group("user", "admins").unwrap();
+        assert!(user_pool.user_groups("user").unwrap().is_empty());
+    }
+
+    #[test]
+    fn group_claims_prefer_role_of_lowest_precedence() {
+        let user_pool = user_pool_in_groups(vec![
+            group(
+                "readers",
+                Some(10),
+                Some("arn:aws:iam::123456789012:role/Reader"),
+            ),
+            group("everyone", None, None),
+            group(
+                "admins",
+                Some(1),
+                Some("arn:aws:iam::123456789012:role/Admin"),
+            ),
+        ]);
+        assert_eq!(
+            GroupClaims {
+                groups: vec![
+                    "admins".to_string(),
+                    "readers".to_string(),
+                    "everyone".to_string()
+                ],
+                roles: vec![
+                    "arn:aws:iam::123456789012:role/Admin".to_string(),
+                    "arn:aws:iam::123456789012:role/Reader".to_string(),
+                ],
+                preferred_role: Some("arn:aws:iam::123456789012:role/Admin".to_string()),
+            },
+            user_pool.group_claims("user").unwrap()
+        );
+    }
+
+    #[test]
+    fn group_claims_have_no_preferred_role_for_tied_precedence() {
+        let user_pool = user_pool_in_groups(vec![
+            group("a", Some(1), Some("arn:aws:iam::123456789012:role/A")),
+            group("b", Some(1), Some("arn:aws:iam::123456789012:role/B")),
+        ]);
+        let claims = user_pool.group_claims("user").unwrap();
+        assert_eq!(2, claims.roles.len());
+        assert_eq!(None, claims.preferred_role);
+    }
+}
@@ -0,0 +1,549 @@
+use super::{AuthOutcome, AuthenticationResult, StoreError, StoreResult, UserPoolState};
+use crate::common;
+use crate::responses::Response;
+use serde::Deserialize;
+use warp::http::StatusCode;
+
+/// Path of the userInfo endpoint of the user pool domains.
+pub const USER_INFO_PATH: &str = "userInfo";
+
+/// Path prefix of the OAuth 2.0 endpoints of the user pool domains.
+pub const OAUTH2_PATH: &str = "oauth2";
+
+/// Path of the authorization endpoint under `OAUTH2_PATH`.
+pub const AUTHORIZE_PATH: &str = "authorize";
+
+/// Path of the built-in login page of the user pool domains.
+pub const LOGIN_PATH: &str = "login";
+
+/// Path of the logout endpoint of the user pool domains.
+pub const LOGOUT_PATH: &str = "logout";
+
+/// Name of the cookie which keeps the session of the login page.
+pub const SESSION_COOKIE: &str = "cognito";
+
+const BEARER_PREFIX: &str = "Bearer ";
+const IMPLICIT_FLOW: &str = "implicit";
+const TOKEN_RESPONSE_TYPE: &str = "token";
+const HTML_CONTENT_TYPE: &str = "text/html; charset=utf-8";
+const ERROR_PAGE_TITLE: &str = "An error was encountered with the requested page.";
+const DEFAULT_LOGIN_CSS: &str = "body { font-family: sans-serif; }
+.banner-customizable { padding: 25px 0; background-color: #fafafa; }
+.logo-customizable { max-width: 100%; max-height: 100%; }
+.label-customizable { display: block; margin-top: 10px; }
+.inputField-customizable { width: 100%; height: 34px; }
+.submitButton-customizable { width: 100%; height: 40px; margin-top: 20px; }
+.errorMessage-customizable { color: #d0021b; }
+";
+
+/// Query parameters of the login page and the authorization endpoint.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LoginQuery {
+    pub client_id: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub response_type: Option<String>,
+    pub state: Option<String>,
+    /// Name of the identity provider which `/oauth2/authorize` signs in through.
+    pub identity_provider: Option<String>,
+}
+
+/// Query parameters which identity providers redirect to `/oauth2/idpresponse` with.
+#[derive(Debug, Default, Deserialize)]
+pub struct IdpResponseQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+/// Query parameters of the logout endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct LogoutQuery {
+    pub client_id: Option<String>,
+    pub logout_uri: Option<String>,
+}
+
+/// Form parameters posted by the login page.
+#[derive(Debug, Default, Deserialize)]
+pub struct LoginForm {
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// Returns the claims of the userInfo endpoint for the access token of an `Authorization` header.
+///
+/// The token must be issued by the user pool of the domain which serves the request.
+pub fn user_info(
+    user_pool_id: &str,
+    authorization: Option<&str>,
+) -> StoreResult<serde_json::Value> {
+    let access_token = authorization
+        .and_then(|a| a.strip_prefix(BEARER_PREFIX))
+        .unwrap_or_default();
+    let token_user = super::access_token_user(access_token)?;
+    if token_user.user_pool_id != user_pool_id {
+        return Err((
+            StoreError::NotAuthorizedException,
+            "Access token is not issued by the user pool of the domain.".to_string(),
+        ));
+    }
+    super::with_user_pool(user_pool_id, |user_pool| {
+        let user = user_pool.user(&token_user.username)?;
+        let mut claims = serde_json::Map::new();
+        claims.insert("sub".to_string(), user.sub.as_str().into());
+        for (name, value) in user.attributes.iter() {
+            claims.insert(name.clone(), value.as_str().into());
+        }
+        claims.insert("username".to_string(), user.username.as_str().into());
+        Ok(claims.into())
+    })
+}
+
+/// Page, redirect (with a `Set-Cookie` header) or error of a request to the login page.
+enum LoginOutcome {
+    Page(String),
+    Redirect(String, Option<String>),
+    Error(&'static str, String),
+}
+
+impl LoginOutcome {
+    fn into_response(self) -> Response {
+        let builder = warp::http::Response::builder();
+        match self {
+            LoginOutcome::Page(html) => builder
+                .status(StatusCode::OK)
+                .header("Content-Type", HTML_CONTENT_TYPE)
+                .body(html.into()),
+            LoginOutcome::Redirect(location, cookie) => {
+                let builder = builder.status(StatusCode::FOUND).header("Location", location);
+                match cookie {
+                    Some(cookie) => builder.header("Set-Cookie", cookie),
+                    None => builder,
+                }
+                .body(warp::hyper::Body::empty())
+            }
+            LoginOutcome::Error(error, description) => builder
+                .status(StatusCode::BAD_REQUEST)
+                .header("Content-Type", HTML_CONTENT_TYPE)
+                .body(
+                    format!(
+                        "<!DOCTYPE html>\n<html><head><title>Error</title></head><body><h1>{}</h1><p>{}</p><p>{}</p></body></html>\n",
+                        ERROR_PAGE_TITLE,
+                        error,
+                        escape_html(&description)
+                    )
+                    .into(),
+                ),
+        }
+        .unwrap()
+    }
+}
+
+/// Returns the login page of an app client, which uses the CSS and logo of SetUICustomization.
+pub fn login_page(user_pool_id: &str, query: &LoginQuery) -> Response {
+    login_outcome(user_pool_id, |user_pool| {
+        Ok(match check_login_query(user_pool, query) {
+            Ok(client_id) => LoginOutcome::Page(render_login_page(user_pool, client_id, None)),
+            Err((error, description)) => LoginOutcome::Error(error, description),
+        })
+    })
+}
+
+/// Signs in a user by the posted login form and redirects to the callback URL with the tokens.
+///
+/// Only the implicit grant (`response_type=token`) is supported, and the redirect sets the
+/// session cookie which `authorize` signs in by.
+pub fn login(user_pool_id: &str, query: &LoginQuery, form: &LoginForm) -> Response {
+    login_outcome(user_pool_id, |user_pool| {
+        let client_id = match check_login_query(user_pool, query) {
+            Ok(client_id) => client_id,
+            Err((error, description)) => return Ok(LoginOutcome::Error(error, description)),
+        };
+        let name = form.username.as_deref().unwrap_or_default();
+        let username = user_pool
+            .resolve_username(name)
+            .unwrap_or_else(|| name.to_string());
+        let password = form.password.as_deref().unwrap_or_default();
+        let outcome = user_pool.sign_in(
+            client_id,
+            &username,
+            password,
+            None,
+            super::EventContext::default(),
+        );
+        let message = match outcome {
+            Ok(AuthOutcome::Authenticated(result)) => {
+                return Ok(LoginOutcome::Redirect(
+                    token_location(query, &result),
+                    Some(start_hosted_ui_session(user_pool, username)),
+                ));
+            }
+            Ok(AuthOutcome::Challenge { name, .. }) => {
+                format!("The login page does not support the {} challenge.", name)
+            }
+            Err((_, message)) => message,
+        };
+        Ok(LoginOutcome::Page(render_login_page(
+            user_pool,
+            client_id,
+            Some(&message),
+        )))
+    })
+}
+
+/// Redirects to the callback URL with new tokens if the session cookie is signed in, or to the
+/// login page otherwise.
+///
+/// With `identity_provider`, redirects to the authorize URL of the identity provider, which sends
+/// the code back to `/oauth2/idpresponse` of the `Host`.
+pub fn authorize(
+    user_pool_id: &str,
+    host: &str,
+    query: &LoginQuery,
+    session: Option<&str>,
+) -> Response {
+    login_outcome(user_pool_id, |user_pool| {
+        let client_id = match check_login_query(user_pool, query) {
+            Ok(client_id) => client_id,
+            Err((error, description)) => return Ok(LoginOutcome::Error(error, description)),
+        };
+        if let Some(provider_name) = query.identity_provider.as_deref() {
+            let idp_response_uri = format!(
+                "http://{}/{}/{}",
+                host,
+                super::OAUTH2_PATH,
+                super::IDP_RESPONSE_PATH
+            );
+            return Ok(
+                match user_pool.start_federation(provider_name, query, &idp_response_uri) {
+                    Ok(location) => LoginOutcome::Redirect(location, None),
+                    Err((_, message)) => LoginOutcome::Error("invalid_request", message),
+                },
+            );
+        }
+        let username = session
+            .and_then(|session| user_pool.hosted_ui_sessions.get(session))
+            .filter(|username| user_pool.user(username).is_ok_and(|user| user.enabled))
+            .cloned();
+        Ok(match username {
+            Some(username) => {
+                let result = user_pool.start_session(client_id, &username)?;
+                LoginOutcome::Redirect(token_location(query, &result), None)
+            }
+            None => LoginOutcome::Redirect(
+                format!("/{}?{}", LOGIN_PATH, login_query_string(query)),
+                None,
+            ),
+        })
+    })
+}
+
+/// Exchanges the code of an identity provider for its ID token, signs in the federated user of
+/// the claims and redirects to the callback URL of the authorization with the tokens.
+pub async fn idp_response(user_pool_id: &str, query: &IdpResponseQuery) -> Response {
+    let state = query.state.as_deref().unwrap_or_default();
+    let code = query.code.as_deref().unwrap_or_default();
+    let taken = super::with_user_pool(user_pool_id, |user_pool| {
+        user_pool.take_federation(state, code)
+    });
+    if let Some(error) = query.error.as_deref() {
+        let description = query.error_description.clone().unwrap_or_default();
+        return LoginOutcome::Error("invalid_request", format!("{}: {}", error, description))
+            .into_response();
+    }
+    let (federation, request) = match taken {
+        Ok(taken) => taken,
+        Err((_, message)) => {
+            return LoginOutcome::Error("invalid_request", message).into_response()
+        }
+    };
+    let id_token = super::exchange_code(&request).await.and_then(|response| {
+        response["id_token"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| "The identity provider did not return an ID token.".to_string())
+    });
+    let id_token = match id_token {
+        Ok(id_token) => id_token,
+        Err(message) => return LoginOutcome::Error("invalid_request", message).into_response(),
+    };
+    login_outcome(user_pool_id, |user_pool| {
+        let claims = user_pool.id_token_claims(&federation.provider_name, &id_token)?;
+        let username = user_pool.sign_in_federated_user(&federation.provider_name, &claims)?;
+        let client_id = federation.query.client_id.as_deref().unwrap_or_default();
+        let result = user_pool.start_session(client_id, &username)?;
+        Ok(LoginOutcome::Redirect(
+            token_location(&federation.query, &result),
+            Some(start_hosted_ui_session(user_pool, username)),
+        ))
+    })
+}
+
+/// Signs out the session cookie and redirects to `logout_uri`, which must be one of `LogoutURLs`
+/// of the app client.
+pub fn logout(user_pool_id: &str, query: &LogoutQuery, session: Option<&str>) -> Response {
+    login_outcome(user_pool_id, |user_pool| {
+        let client_id = query.client_id.as_deref().unwrap_or_default();
+        let logout_uri = query.logout_uri.as_deref().unwrap_or_default();
+        match user_pool.client(client_id) {
+            Ok(client) if client.logout_urls.iter().any(|url| url == logout_uri) => {}
+            Ok(_) => {
+                return Ok(LoginOutcome::Error(
+                    "redirect_mismatch",
+                    format!("{} is not a logout URL of the client.", logout_uri),
+                ))
+            }
+            Err((_, message)) => return Ok(LoginOutcome::Error("invalid_client", message)),
+        }
+        if let Some(session) = session {
+            user_pool.hosted_ui_sessions.remove(session);
+        }
+        Ok(LoginOutcome::Redirect(
+            logout_uri.to_string(),
+            Some(format!("{}=; Path=/; Max-Age=0", SESSION_COOKIE)),
+        ))
+    })
+}
+
+/// Starts a session of the login page and returns its `Set-Cookie` header.
+fn start_hosted_ui_session(user_pool: &mut UserPoolState, username: String) -> String {
+    let session = common::new_uuid();
+    let cookie = format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Lax",
+        SESSION_COOKIE, session
+    );
+    user_pool.hosted_ui_sessions.insert(session, username);
+    cookie
+}
+
+/// Returns the callback URL with the tokens and the state in the fragment.
+fn token_location(query: &LoginQuery, result: &AuthenticationResult) -> String {
+    let mut location = format!(
+        "{}#id_token={}&access_token={}&token_type=Bearer&expires_in={}",
+        query.redirect_uri.as_deref().unwrap_or_default(),
+        result.id_token,
+        result.access_token,
+        result.expires_in
+    );
+    if let Some(state) = query.state.as_deref() {
+        location.push_str(&format!("&state={}", common::uri_encode(state)));
+    }
+    location
+}
+
+fn login_query_string(query: &LoginQuery) -> String {
+    [
+        ("client_id", &query.client_id),
+        ("redirect_uri", &query.redirect_uri),
+        ("response_type", &query.response_type),
+        ("state", &query.state),
+    ]
+    .iter()
+    .filter_map(|(name, value)| {
+        value
+            .as_deref()
+            .map(|v| format!("{}={}", name, common::uri_encode(v)))
+    })
+    .collect::<Vec<_>>()
+    .join("&")
+}
+
+fn login_outcome<F>(user_pool_id: &str, f: F) -> Response
+where
+    F: FnOnce(&mut UserPoolState) -> StoreResult<LoginOutcome>,
+{
+    super::with_user_pool(user_pool_id, f)
+        .unwrap_or_else(|(_, message)| LoginOutcome::Error("invalid_request", message))
+        .into_response()
+}
+
+/// Returns the client id of the query if the client can receive tokens at `redirect_uri`.
+fn check_login_query<'a>(
+    user_pool: &UserPoolState,
+    query: &'a LoginQuery,
+) -> Result<&'a str, (&'static str, String)> {
+    let client_id = query.client_id.as_deref().unwrap_or_default();
+    let client = user_pool
+        .client(client_id)
+        .map_err(|(_, message)| ("invalid_client", message))?;
+    let redirect_uri = query.redirect_uri.as_deref().unwrap_or_default();
+    if !client.callback_urls.iter().any(|url| url == redirect_uri) {
+        return Err((
+            "redirect_mismatch",
+            format!("{} is not a callback URL of the client.", redirect_uri),
+        ));
+    }
+    if query.response_type.as_deref() != Some(TOKEN_RESPONSE_TYPE) {
+        return Err((
+            "unsupported_response_type",
+            "Only response_type=token is supported.".to_string(),
+        ));
+    }
+    if !client
+        .allowed_oauth_flows
+        .iter()
+        .any(|f| f == IMPLICIT_FLOW)
+    {
+        return Err((
+            "unauthorized_client",
+            "The client does not allow the implicit grant.".to_string(),
+        ));
+    }
+    Ok(client_id)
+}
+
+fn render_login_page(user_pool: &UserPoolState, client_id: &str, message: Option<&str>) -> String {
+    let customization = user_pool.ui_customization(Some(client_id));
+    let css = customization
+        .and_then(|(_, c)| c.css.as_deref())
+        .unwrap_or_default();
+    let logo = customization
+        .filter(|(_, c)| c.image_file.is_some())
+        .map(|(customized_client, _)| {
+            format!(
+                "<img class=\"logo-customizable\" src=\"{}\" alt=\"logo\">",
+                escape_html(&super::ui_image_url(
+                    &user_pool.id,
+                    customized_client.unwrap_or(super::ALL_CLIENTS)
+                ))
+            )
+        })
+        .unwrap_or_default();
+    let message = message
+        .map(|m| {
+            format!(
+                "<p class=\"errorMessage-customizable\">{}</p>\n",
+                escape_html(m)
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Signin</title>
+<style>
+{}{}
+</style>
+</head>
+<body>
+<div class="banner-customizable">{}</div>
+<form method="post">
+{}<label class="label-customizable" for="username">Username</label>
+<input id="username" name="username" type="text" class="inputField-customizable">
+<label class="label-customizable" for="password">Password</label>
+<input id="password" name="password" type="password" class="inputField-customizable">
+<input name="signInSubmitButton" type="submit" value="Sign in" class="submitButton-customizable">
+</form>
+</body>
+</html>
+"#,
+        DEFAULT_LOGIN_CSS, css, logo, message
+    )
+}
+
+fn escape_html(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#39;".to_string(),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn user_pool_with_client(flows: &[&str]) -> (UserPoolState, String) {
+        let mut user_pool = UserPoolState::new("pool");
+        let mut client = super::super::ClientState::new("client", false);
+        client.allowed_oauth_flows = flows.iter().map(|f| f.to_string()).collect();
+        client.callback_urls = vec!["https://example.com/callback".to_string()];
+        let client_id = client.client_id.clone();
+        user_pool.clients.insert(client_id.clone(), client);
+        (user_pool, client_id)
+    }
+
+    fn query(client_id: &str, redirect_uri: &str, response_type: &str) -> LoginQuery {
+        LoginQuery {
+            client_id: Some(client_id.to_string()),
+            redirect_uri: Some(redirect_uri.to_string()),
+            response_type: Some(response_type.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn login_query_is_checked_against_client() {
+        let (user_pool, client_id) = user_pool_with_client(&[IMPLICIT_FLOW]);
+        let check = |query: &LoginQuery| {
+            check_login_query(&user_pool, query)
+                .map(str::to_string)
+                .map_err(|(e, _)| e)
+        };
+        assert_eq!(
+            Ok(client_id.clone()),
+            check(&query(&client_id, "https://example.com/callback", "token"))
+        );
+        assert_eq!(
+            Err("invalid_client"),
+            check(&query("unknown", "https://example.com/callback", "token"))
+        );
+        assert_eq!(
+            Err("redirect_mismatch"),
+            check(&query(&client_id, "https://example.org/", "token"))
+        );
+        assert_eq!(
+            Err("unsupported_response_type"),
+            check(&query(&client_id, "https://example.com/callback", "code"))
+        );
+
+        let (user_pool, client_id) = user_pool_with_client(&["code"]);
+        assert_eq!(
+            Err("unauthorized_client"),
+            check_login_query(
+                &user_pool,
+                &query(&client_id, "https://example.com/callback", "token")
+            )
+            .map_err(|(e, _)| e)
+        );
+    }
+
+    #[test]
+    fn login_query_is_passed_to_login_page() {
+        let mut login_query = query("client", "https://example.com/callback", "token");
+        assert_eq!(
+            "client_id=client&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&response_type=token",
+            login_query_string(&login_query)
+        );
+        login_query.state = Some("a b".to_string());
+        assert!(login_query_string(&login_query).ends_with("&state=a%20b"));
+    }
+
+    #[test]
+    fn login_page_uses_ui_customization() {
+        let (mut user_pool, client_id) = user_pool_with_client(&[IMPLICIT_FLOW]);
+        let page = render_login_page(&user_pool, &client_id, Some("<error>"));
+        assert!(!page.contains("logo-customizable\" src"));
+        assert!(page.contains("&lt;error&gt;"));
+
+        let css = ".submitButton-customizable { background-color: #123456; }";
+        user_pool
+            .set_ui_customization(
+                None,
+                Some(css.to_string()),
+                Some("iVBORw0KGgo=".to_string()),
+            )
+            .unwrap();
+        let page = render_login_page(&user_pool, &client_id, None);
+        assert!(page.contains(css));
+        assert!(page.contains(&format!("/{}/ui/ALL\"", user_pool.id)));
+    }
+}
@@ -0,0 +1,194 @@
+use super::{StoreError, StoreResult, UserPoolState};
+use chrono::Utc;
+
+fn provider_not_found(provider_name: &str) -> (StoreError, String) {
+    (
+        StoreError::ResourceNotFoundException,
+        format!("Identity provider {} does not exist.", provider_name),
+    )
+}
+
+fn idp_identifiers(provider: &serde_json::Value) -> impl Iterator<Item = &str> {
+    provider["IdpIdentifiers"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|i| i.as_str())
+}
+
+impl UserPoolState {
+    /// Stores `IdentityProvider` of CreateIdentityProvider.
+    pub fn add_identity_provider(
+        &mut self,
+        mut provider: serde_json::Value,
+    ) -> StoreResult<&serde_json::Value> {
+        let provider_name = provider["ProviderName"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        if self.identity_providers.contains_key(&provider_name) {
+            return Err((
+                StoreError::DuplicateProviderException,
+                format!(
+                    "A provider with the name {} already exists in this user pool.",
+                    provider_name
+                ),
+            ));
+        }
+        self.check_idp_identifiers(&provider_name, &provider)?;
+        let now = Utc::now().timestamp();
+        provider["CreationDate"] = now.into();
+        provider["LastModifiedDate"] = now.into();
+        Ok(self
+            .identity_providers
+            .entry(provider_name)
+            .or_insert(provider))
+    }
+
+    /// Returns a stored identity provider by the name.
+    pub fn identity_provider(&self, provider_name: &str) -> StoreResult<&serde_json::Value> {
+        self.identity_providers
+            .get(provider_name)
+            .ok_or_else(|| provider_not_found(provider_name))
+    }
+
+    /// Returns a stored identity provider by one of its `IdpIdentifiers`.
+    pub fn identity_provider_by_identifier(
+        &self,
+        idp_identifier: &str,
+    ) -> StoreResult<&serde_json::Value> {
+        self.identity_providers
+            .values()
+            .find(|provider| idp_identifiers(provider).any(|i| i == idp_identifier))
+            .ok_or_else(|| {
+                (
+                    StoreError::ResourceNotFoundException,
+                    format!("Identity provider {} does not exist.", idp_identifier),
+                )
+            })
+    }
+
+    /// Updates the fields of UpdateIdentityProvider of a stored identity provider.
+    pub fn update_identity_provider(
+        &mut self,
+        provider_name: &str,
+        fields: serde_json::Value,
+    ) -> StoreResult<&serde_json::Value> {
+        let mut provider = self.identity_provider(provider_name)?.clone();
+        for key in ["AttributeMapping", "IdpIdentifiers", "ProviderDetails"] {
+            if let Some(value) = fields.get(key).filter(|v| !v.is_null()) {
+                provider[key] = value.clone();
+            }
+        }
+        self.check_idp_identifiers(provider_name, &provider)?;
+        provider["LastModifiedDate"] = Utc::now().timestamp().into();
+        let stored = self
+            .identity_providers
+            .entry(provider_name.to_string())
+            .or_default();
+        *stored = provider;
+        Ok(stored)
+    }
+
+    /// Removes a stored identity provider.
+    pub fn remove_identity_provider(&mut self, provider_name: &str) -> StoreResult<()> {
+        self.identity_providers
+            .remove(provider_name)
+            .map(|_| ())
+            .ok_or_else(|| provider_not_found(provider_name))
+    }
+
+    fn check_idp_identifiers(
+        &self,
+        provider_name: &str,
+        provider: &serde_json::Value,
+    ) -> StoreResult<()> {
+        let used = idp_identifiers(provider).find(|identifier| {
+            self.identity_providers
+                .iter()
+                .filter(|(name, _)| name.as_str() != provider_name)
+                .any(|(_, other)| idp_identifiers(other).any(|i| i == *identifier))
+        });
+        match used {
+            Some(identifier) => Err((
+                StoreError::InvalidParameterException,
+                format!(
+                    "The identifier {} is already used by another identity provider.",
+                    identifier
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn provider(name: &str, identifiers: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "IdpIdentifiers": identifiers,
+            "ProviderName": name,
+            "ProviderType": "OIDC",
+        })
+    }
+
+    #[test]
+    fn identity_providers_are_unique() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_identity_provider(provider("idp", &["example.com"]))
+            .unwrap();
+        assert_eq!(
+            Err(StoreError::DuplicateProviderException),
+            user_pool
+                .add_identity_provider(provider("idp", &[]))
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        assert_eq!(
+            Err(StoreError::InvalidParameterException),
+            user_pool
+                .add_identity_provider(provider("other", &["example.com"]))
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        assert_eq!(
+            "idp",
+            user_pool
+                .identity_provider_by_identifier("example.com")
+                .unwrap()["ProviderName"]
+        );
+    }
+
+    #[test]
+    fn identity_provider_can_be_updated_and_removed() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_identity_provider(provider("idp", &["example.com"]))
+            .unwrap();
+        let updated = user_pool
+            .update_identity_provider(
+                "idp",
+                serde_json::json!({ "IdpIdentifiers": ["example.org"] }),
+            )
+            .unwrap();
+        assert_eq!(
+            serde_json::json!(["example.org"]),
+            updated["IdpIdentifiers"]
+        );
+        assert!(user_pool
+            .identity_provider_by_identifier("example.com")
+            .is_err());
+
+        user_pool.remove_identity_provider("idp").unwrap();
+        assert_eq!(
+            Err(StoreError::ResourceNotFoundException),
+            user_pool
+                .remove_identity_provider("idp")
+                .map_err(|(e, _)| e)
+        );
+    }
+}
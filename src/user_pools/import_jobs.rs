@@ -0,0 +1,431 @@
+use super::{AttributeWrite, StoreError, StoreResult, UserPoolState, UserState, UserStatus};
+use crate::common::{self, ALPHANUMERIC};
+use chrono::{DateTime, Utc};
+use strum_macros::Display;
+
+/// Path segment of the URLs which take the CSV files of user import jobs.
+pub const IMPORT_JOB_PATH: &str = "import";
+
+const IMPORT_JOB_ID_PREFIX: &str = "import-";
+const IMPORT_JOB_ID_LENGTH: usize = 10;
+const COGNITO_COLUMN_PREFIX: &str = "cognito:";
+const USERNAME_COLUMN: &str = "cognito:username";
+
+/// Status of a user import job.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+pub enum ImportJobStatus {
+    Created,
+    Pending,
+    InProgress,
+    Stopped,
+    Succeeded,
+    Failed,
+}
+
+/// User import job created by CreateUserImportJob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserImportJobState {
+    pub job_id: String,
+    pub job_name: String,
+    pub cloud_watch_logs_role_arn: String,
+    pub status: ImportJobStatus,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub completion_message: Option<String>,
+    pub imported_users: usize,
+    pub skipped_users: usize,
+    pub failed_users: usize,
+    /// CSV file uploaded to the pre-signed URL of the job.
+    pub csv: Option<String>,
+}
+
+impl UserImportJobState {
+    pub fn new(job_name: &str, cloud_watch_logs_role_arn: &str) -> Self {
+        UserImportJobState {
+            job_id: format!(
+                "{}{}",
+                IMPORT_JOB_ID_PREFIX,
+                common::random_string(ALPHANUMERIC, IMPORT_JOB_ID_LENGTH)
+            ),
+            job_name: job_name.to_string(),
+            cloud_watch_logs_role_arn: cloud_watch_logs_role_arn.to_string(),
+            status: ImportJobStatus::Created,
+            created_at: Utc::now(),
+            started_at: None,
+            completed_at: None,
+            completion_message: None,
+            imported_users: 0,
+            skipped_users: 0,
+            failed_users: 0,
+            csv: None,
+        }
+    }
+
+    /// Returns `UserImportJob` of the responses of the import job actions.
+    pub fn to_value(&self, user_pool_id: &str) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "CloudWatchLogsRoleArn": self.cloud_watch_logs_role_arn,
+            "CreationDate": self.created_at.timestamp(),
+            "FailedUsers": self.failed_users,
+            "ImportedUsers": self.imported_users,
+            "JobId": self.job_id,
+            "JobName": self.job_name,
+            "PreSignedUrl": import_job_url(user_pool_id, &self.job_id),
+            "SkippedUsers": self.skipped_users,
+            "Status": self.status.to_string(),
+            "UserPoolId": user_pool_id,
+        });
+        if let Some(started_at) = self.started_at {
+            value["StartDate"] = started_at.timestamp().into();
+        }
+        if let Some(completed_at) = self.completed_at {
+            value["CompletionDate"] = completed_at.timestamp().into();
+        }
+        if let Some(message) = self.completion_message.as_deref() {
+            value["CompletionMessage"] = message.into();
+        }
+        value
+    }
+
+    fn complete(&mut self, status: ImportJobStatus, message: &str) {
+        self.status = status;
+        self.completed_at = Some(Utc::now());
+        self.completion_message = Some(message.to_string());
+    }
+}
+
+/// Returns the URL which takes the CSV file of a user import job (`PreSignedUrl`).
+pub fn import_job_url(user_pool_id: &str, job_id: &str) -> String {
+    format!(
+        "{}/{}/{}",
+        super::user_pool_issuer(user_pool_id),
+        IMPORT_JOB_PATH,
+        job_id
+    )
+}
+
+/// Imports the users of a started import job, leaving the job `InProgress` in between.
+pub async fn run_import_job(user_pool_id: String, job_id: String) {
+    tokio::task::yield_now().await;
+    let begun = super::with_user_pool(&user_pool_id, |user_pool| {
+        let job = user_pool.import_job_mut(&job_id)?;
+        if job.status == ImportJobStatus::Pending {
+            job.status = ImportJobStatus::InProgress;
+        }
+        Ok(())
+    });
+    if begun.is_err() {
+        return;
+    }
+    tokio::task::yield_now().await;
+    if let Err((_, message)) =
+        super::with_user_pool(&user_pool_id, |user_pool| user_pool.import_users(&job_id))
+    {
+        log::error!("User import job {} failed: {}", job_id, message);
+    }
+}
+
+/// Returns the records of a CSV file, with quoted fields unquoted.
+fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records.retain(|r| r.iter().any(|f| !f.is_empty()));
+    records
+}
+
+fn job_not_found(job_id: &str) -> (StoreError, String) {
+    (
+        StoreError::ResourceNotFoundException,
+        format!("Import job {} does not exist.", job_id),
+    )
+}
+
+impl UserPoolState {
+    /// Stores an import job of CreateUserImportJob.
+    pub fn add_import_job(&mut self, job: UserImportJobState) -> &UserImportJobState {
+        self.import_jobs.entry(job.job_id.clone()).or_insert(job)
+    }
+
+    /// Returns a stored import job.
+    pub fn import_job(&self, job_id: &str) -> StoreResult<&UserImportJobState> {
+        self.import_jobs
+            .get(job_id)
+            .ok_or_else(|| job_not_found(job_id))
+    }
+
+    fn import_job_mut(&mut self, job_id: &str) -> StoreResult<&mut UserImportJobState> {
+        self.import_jobs
+            .get_mut(job_id)
+            .ok_or_else(|| job_not_found(job_id))
+    }
+
+    /// Takes the CSV file of an import job which has not been started.
+    pub fn upload_import_csv(&mut self, job_id: &str, csv: String) -> StoreResult<()> {
+        let job = self.import_job_mut(job_id)?;
+        if job.status != ImportJobStatus::Created {
+            return Err((
+                StoreError::PreconditionNotMetException,
+                format!("Import job {} has already been started.", job_id),
+            ));
+        }
+        job.csv = Some(csv);
+        Ok(())
+    }
+
+    /// Starts an import job with an uploaded CSV file (StartUserImportJob).
+    pub fn start_import_job(&mut self, job_id: &str) -> StoreResult<&UserImportJobState> {
+        let job = self.import_job_mut(job_id)?;
+        if job.status != ImportJobStatus::Created {
+            return Err((
+                StoreError::PreconditionNotMetException,
+                format!("Import job {} has already been started.", job_id),
+            ));
+        }
+        if job.csv.is_none() {
+            return Err((
+                StoreError::PreconditionNotMetException,
+                "No CSV file has been uploaded to the pre-signed URL.".to_string(),
+            ));
+        }
+        job.status = ImportJobStatus::Pending;
+        job.started_at = Some(Utc::now());
+        Ok(job)
+    }
+
+    /// Stops an import job which is pending or in progress (StopUserImportJob).
+    pub fn stop_import_job(&mut self, job_id: &str) -> StoreResult<&UserImportJobState> {
+        let job = self.import_job_mut(job_id)?;
+        if !matches!(
+            job.status,
+            ImportJobStatus::Pending | ImportJobStatus::InProgress
+        ) {
+            return Err((
+                StoreError::PreconditionNotMetException,
+                format!("Import job {} is not running.", job_id),
+            ));
+        }
+        job.complete(ImportJobStatus::Stopped, "Import Job Stopped.");
+        Ok(job)
+    }
+
+    /// Imports the users of the CSV file of an import job in progress as `RESET_REQUIRED` users.
+    ///
+    /// Existing users are skipped, and the job fails if any row cannot be imported.
+    pub fn import_users(&mut self, job_id: &str) -> StoreResult<()> {
+        let job = self.import_job(job_id)?;
+        if job.status != ImportJobStatus::InProgress {
+            return Ok(());
+        }
+        let mut records = parse_csv(job.csv.as_deref().unwrap_or_default()).into_iter();
+        let header = records.next().unwrap_or_default();
+        let columns = self.csv_header();
+        let invalid_column = header
+            .iter()
+            .find(|c| !columns.contains(c))
+            .cloned()
+            .or_else(|| {
+                (!header.iter().any(|c| c == USERNAME_COLUMN)).then(|| USERNAME_COLUMN.to_string())
+            });
+        if let Some(column) = invalid_column {
+            let message = format!("The CSV header is invalid at the column {}.", column);
+            self.import_job_mut(job_id)?
+                .complete(ImportJobStatus::Failed, &message);
+            return Ok(());
+        }
+        let (mut imported, mut skipped, mut failed) = (0, 0, 0);
+        for record in records {
+            match self.import_user(&header, &record) {
+                Ok(()) => imported += 1,
+                Err((StoreError::UsernameExistsException, _)) => skipped += 1,
+                Err(_) => failed += 1,
+            }
+        }
+        let job = self.import_job_mut(job_id)?;
+        job.imported_users = imported;
+        job.skipped_users = skipped;
+        job.failed_users = failed;
+        if failed == 0 {
+            job.complete(
+                ImportJobStatus::Succeeded,
+                "Import Job Completed Successfully.",
+            );
+        } else {
+            job.complete(
+                ImportJobStatus::Failed,
+                &format!("Import Job Completed. {} users failed to import.", failed),
+            );
+        }
+        Ok(())
+    }
+
+    fn import_user(&mut self, header: &[String], record: &[String]) -> StoreResult<()> {
+        if record.len() != header.len() {
+            return Err((
+                StoreError::InvalidParameterException,
+                "The number of the fields does not match the CSV header.".to_string(),
+            ));
+        }
+        let fields = header.iter().zip(record.iter());
+        let username = fields
+            .clone()
+            .find(|(column, _)| column.as_str() == USERNAME_COLUMN)
+            .map(|(_, value)| value.as_str())
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                (
+                    StoreError::InvalidParameterException,
+                    "Username is required.".to_string(),
+                )
+            })?;
+        if self.resolve_username(username).is_some() {
+            return Err((
+                StoreError::UsernameExistsException,
+                "User account already exists.".to_string(),
+            ));
+        }
+        let attributes = fields
+            .filter(|(column, value)| {
+                !column.starts_with(COGNITO_COLUMN_PREFIX) && !value.is_empty()
+            })
+            .map(|(column, value)| (column.clone(), value.clone()))
+            .collect::<Vec<_>>();
+        self.check_user_attributes(&attributes, AttributeWrite::Create { admin: true })?;
+        let mut user = UserState::new(username, UserStatus::ResetRequired);
+        user.attributes = attributes;
+        self.add_user(user).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn csv(rows: &[&str]) -> String {
+        let header = UserPoolState::new("pool").csv_header().join(",");
+        std::iter::once(header.as_str())
+            .chain(rows.iter().copied())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn row(username: &str, email: &str) -> String {
+        let header = UserPoolState::new("pool").csv_header();
+        header
+            .iter()
+            .map(|column| match column.as_str() {
+                "cognito:username" => username.to_string(),
+                "email" => email.to_string(),
+                "email_verified" => "true".to_string(),
+                "cognito:mfa_enabled" => "false".to_string(),
+                _ => String::new(),
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    #[test]
+    fn quoted_csv_fields_are_unquoted() {
+        assert_eq!(
+            vec![
+                vec!["a".to_string(), "b,\"c\"".to_string()],
+                vec!["d".to_string(), String::new()],
+            ],
+            parse_csv("a,\"b,\"\"c\"\"\"\r\n\nd,\n")
+        );
+    }
+
+    #[test]
+    fn import_job_imports_reset_required_users() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool
+            .add_user(UserState::new("existing", UserStatus::Confirmed))
+            .unwrap();
+        let job_id = user_pool
+            .add_import_job(UserImportJobState::new("job", "arn"))
+            .job_id
+            .clone();
+        assert_eq!(
+            Err(StoreError::PreconditionNotMetException),
+            user_pool
+                .start_import_job(&job_id)
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        let rows = [
+            row("user", "user@example.com"),
+            row("existing", "existing@example.com"),
+            "invalid".to_string(),
+        ];
+        user_pool
+            .upload_import_csv(
+                &job_id,
+                csv(&rows.iter().map(String::as_str).collect::<Vec<_>>()),
+            )
+            .unwrap();
+        user_pool.start_import_job(&job_id).unwrap();
+        user_pool.import_job_mut(&job_id).unwrap().status = ImportJobStatus::InProgress;
+        user_pool.import_users(&job_id).unwrap();
+
+        let job = user_pool
+            .import_job(&job_id)
+            .unwrap()
+            .to_value(&user_pool.id);
+        assert_eq!("Failed", job["Status"]);
+        assert_eq!(1, job["ImportedUsers"]);
+        assert_eq!(1, job["SkippedUsers"]);
+        assert_eq!(1, job["FailedUsers"]);
+        let user = user_pool.user("user").unwrap();
+        assert_eq!(UserStatus::ResetRequired, user.status);
+        assert!(user
+            .attributes
+            .contains(&("email".to_string(), "user@example.com".to_string())));
+    }
+
+    #[test]
+    fn import_job_fails_with_unknown_columns() {
+        let mut user_pool = UserPoolState::new("pool");
+        let job_id = user_pool
+            .add_import_job(UserImportJobState::new("job", "arn"))
+            .job_id
+            .clone();
+        user_pool
+            .upload_import_csv(
+                &job_id,
+                "cognito:username,custom:plan\nuser,free".to_string(),
+            )
+            .unwrap();
+        user_pool.start_import_job(&job_id).unwrap();
+        user_pool.import_job_mut(&job_id).unwrap().status = ImportJobStatus::InProgress;
+        user_pool.import_users(&job_id).unwrap();
+
+        let job = user_pool.import_job(&job_id).unwrap();
+        assert_eq!(ImportJobStatus::Failed, job.status);
+        assert_eq!(0, job.imported_users);
+        assert!(user_pool.user("user").is_err());
+    }
+}
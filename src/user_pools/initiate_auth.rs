@@ -0,0 +1,131 @@
+use crate::common::CLIENT_ID_REGEX;
+use crate::{http, validator::includes};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const INITIATE_AUTH_NAME: &str = "InitiateAuth";
+pub const INITIATE_AUTH_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.InitiateAuth";
+
+fn validate_auth_flow(value: &str) -> Result<(), ValidationError> {
+    includes(
+        value,
+        vec![
+            "USER_SRP_AUTH",
+            "REFRESH_TOKEN_AUTH",
+            "REFRESH_TOKEN",
+            "CUSTOM_AUTH",
+            "USER_PASSWORD_AUTH",
+        ],
+    )
+}
+
+super::gen_response_err!(
+    InitiateAuthError,
+    InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | InvalidUserPoolConfigurationException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct InitiateAuthRequest {
+    #[validate(nested)]
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(custom(function = validate_auth_flow))]
+    pub auth_flow: Option<String>,
+    pub auth_parameters: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+}
+
+impl super::ToActionName for InitiateAuthRequest {
+    fn to_action_name() -> &'static str {
+        INITIATE_AUTH_NAME
+    }
+}
+
+impl super::ToResponse for InitiateAuthRequest {
+    type E = InitiateAuthError;
+    fn to_response(&self) -> super::Response {
+        let client_id = self.client_id.as_deref().unwrap_or_default();
+        if super::client_user_pool_id(client_id).is_none() {
+            return super::to_json_response(self, INITIATE_AUTH_NAME);
+        }
+        let mut code = None;
+        let response = super::to_converted_json_response(self, INITIATE_AUTH_NAME, |value| {
+            let outcome = super::with_client_user_pool(client_id, |user_pool| {
+                let outcome = user_pool.initiate_auth(
+                    client_id,
+                    self.auth_flow.as_deref().unwrap_or_default(),
+                    self.auth_parameters.as_ref().unwrap_or(&Default::default()),
+                    false,
+                    super::EventContext::from_user_context_data(self.user_context_data.as_ref()),
+                )?;
+                code = user_pool.challenge_code(&outcome);
+                Ok(outcome)
+            })
+            .map_err(super::to_response_error)?;
+            Ok(outcome.to_value(value))
+        });
+        super::with_code_header(response, code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = InitiateAuthRequest {
+            auth_flow: Some("USER_PASSWORD_AUTH".to_string()),
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = InitiateAuthRequest {
+            auth_flow: Some("ADMIN_USER_PASSWORD_AUTH".to_string()),
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = InitiateAuthError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = InitiateAuthError::ForbiddenException;
+        assert_eq!(http::status_code(403), error.to_status_code());
+
+        let error = InitiateAuthError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
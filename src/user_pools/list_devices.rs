@@ -0,0 +1,129 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_DEVICES_NAME: &str = "ListDevices";
+pub const LIST_DEVICES_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.ListDevices";
+
+static PAGINATION_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListDevicesError,
+    InvalidParameterException
+    | InvalidUserPoolConfigurationException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListDevicesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+    #[validate(range(min = 0, max = 60))]
+    pub limit: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *PAGINATION_TOKEN_REGEX))]
+    pub pagination_token: Option<String>,
+}
+
+impl super::ToActionName for ListDevicesRequest {
+    fn to_action_name() -> &'static str {
+        LIST_DEVICES_NAME
+    }
+}
+
+impl super::ToResponse for ListDevicesRequest {
+    type E = ListDevicesError;
+    fn to_response(&self) -> super::Response {
+        super::to_converted_json_response(self, LIST_DEVICES_NAME, |mut value| {
+            value["Devices"] = super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    Ok(user_pool
+                        .user(&user.username)?
+                        .devices
+                        .values()
+                        .map(super::DeviceState::to_value)
+                        .collect())
+                },
+            )
+            .map_err(super::to_response_error)?;
+            super::to_page(self, value)
+        })
+    }
+}
+
+impl super::ToPage for ListDevicesRequest {
+    fn items_key() -> &'static str {
+        "Devices"
+    }
+
+    fn item_key() -> &'static str {
+        "DeviceKey"
+    }
+
+    fn token_key() -> &'static str {
+        "PaginationToken"
+    }
+
+    fn max_page_size() -> u8 {
+        60
+    }
+
+    fn page_limit(&self) -> Option<u8> {
+        self.limit
+    }
+
+    fn page_token(&self) -> Option<&str> {
+        self.pagination_token.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListDevicesRequest {
+            access_token: Some("access_token".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListDevicesRequest {
+            access_token: Some("access_token".to_string()),
+            limit: Some(61),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = ListDevicesError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListDevicesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,133 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_IDENTITY_PROVIDERS_NAME: &str = "ListIdentityProviders";
+pub const LIST_IDENTITY_PROVIDERS_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ListIdentityProviders";
+
+static NEXT_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListIdentityProvidersError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListIdentityProvidersRequest {
+    #[validate(range(min = 1, max = 60))]
+    pub max_results: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *NEXT_TOKEN_REGEX))]
+    pub next_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListIdentityProvidersRequest {
+    fn to_action_name() -> &'static str {
+        LIST_IDENTITY_PROVIDERS_NAME
+    }
+}
+
+impl super::ToResponse for ListIdentityProvidersRequest {
+    type E = ListIdentityProvidersError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_paginated_json_response(self, LIST_IDENTITY_PROVIDERS_NAME);
+        }
+        super::to_converted_json_response(self, LIST_IDENTITY_PROVIDERS_NAME, |mut value| {
+            value["Providers"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .identity_providers
+                    .values()
+                    .map(|provider| {
+                        serde_json::json!({
+                            "CreationDate": provider["CreationDate"],
+                            "LastModifiedDate": provider["LastModifiedDate"],
+                            "ProviderName": provider["ProviderName"],
+                            "ProviderType": provider["ProviderType"],
+                        })
+                    })
+                    .collect())
+            })
+            .map_err(super::to_response_error)?;
+            super::to_page(self, value)
+        })
+    }
+}
+
+impl super::ToPage for ListIdentityProvidersRequest {
+    fn items_key() -> &'static str {
+        "Providers"
+    }
+
+    fn item_key() -> &'static str {
+        "ProviderName"
+    }
+
+    fn token_key() -> &'static str {
+        "NextToken"
+    }
+
+    fn max_page_size() -> u8 {
+        60
+    }
+
+    fn page_limit(&self) -> Option<u8> {
+        self.max_results
+    }
+
+    fn page_token(&self) -> Option<&str> {
+        self.next_token.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListIdentityProvidersRequest {
+            max_results: Some(60),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListIdentityProvidersRequest {
+            max_results: Some(0),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = ListIdentityProvidersError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListIdentityProvidersError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::common::ARN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_TAGS_FOR_RESOURCE_NAME: &str = "ListTagsForResource";
+pub const LIST_TAGS_FOR_RESOURCE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ListTagsForResource";
+
+super::gen_response_err!(
+    ListTagsForResourceError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListTagsForResourceRequest {
+    #[validate(required)]
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex(path = *ARN_REGEX))]
+    pub resource_arn: Option<String>,
+}
+
+impl super::ToActionName for ListTagsForResourceRequest {
+    fn to_action_name() -> &'static str {
+        LIST_TAGS_FOR_RESOURCE_NAME
+    }
+}
+
+impl super::ToResponse for ListTagsForResourceRequest {
+    type E = ListTagsForResourceError;
+    fn to_response(&self) -> super::Response {
+        let resource_arn = self.resource_arn.as_deref().unwrap_or_default();
+        match super::resource_tags(resource_arn) {
+            Some(tags) => {
+                super::to_converted_json_response(self, LIST_TAGS_FOR_RESOURCE_NAME, |mut value| {
+                    value["Tags"] = serde_json::json!(tags);
+                    Ok(value)
+                })
+            }
+            None => super::to_json_response(self, LIST_TAGS_FOR_RESOURCE_NAME),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListTagsForResourceRequest {
+            resource_arn: Some(
+                "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example".to_string(),
+            ),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListTagsForResourceRequest {
+            resource_arn: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = ListTagsForResourceError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListTagsForResourceError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
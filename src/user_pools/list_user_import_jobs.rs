@@ -0,0 +1,127 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_USER_IMPORT_JOBS_NAME: &str = "ListUserImportJobs";
+pub const LIST_USER_IMPORT_JOBS_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ListUserImportJobs";
+
+static PAGINATION_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListUserImportJobsError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListUserImportJobsRequest {
+    #[validate(required)]
+    #[validate(range(min = 1, max = 60))]
+    pub max_results: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *PAGINATION_TOKEN_REGEX))]
+    pub pagination_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListUserImportJobsRequest {
+    fn to_action_name() -> &'static str {
+        LIST_USER_IMPORT_JOBS_NAME
+    }
+}
+
+impl super::ToResponse for ListUserImportJobsRequest {
+    type E = ListUserImportJobsError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_paginated_json_response(self, LIST_USER_IMPORT_JOBS_NAME);
+        }
+        super::to_converted_json_response(self, LIST_USER_IMPORT_JOBS_NAME, |mut value| {
+            value["UserImportJobs"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .import_jobs
+                    .values()
+                    .map(|job| job.to_value(user_pool_id))
+                    .collect())
+            })
+            .map_err(super::to_response_error)?;
+            super::to_page(self, value)
+        })
+    }
+}
+
+impl super::ToPage for ListUserImportJobsRequest {
+    fn items_key() -> &'static str {
+        "UserImportJobs"
+    }
+
+    fn item_key() -> &'static str {
+        "JobId"
+    }
+
+    fn token_key() -> &'static str {
+        "PaginationToken"
+    }
+
+    fn max_page_size() -> u8 {
+        60
+    }
+
+    fn page_limit(&self) -> Option<u8> {
+        self.max_results
+    }
+
+    fn page_token(&self) -> Option<&str> {
+        self.pagination_token.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListUserImportJobsRequest {
+            max_results: Some(60),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListUserImportJobsRequest {
+            max_results: None,
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = ListUserImportJobsError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListUserImportJobsError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
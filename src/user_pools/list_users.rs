@@ -0,0 +1,306 @@
+use crate::common::{NAME_REGEX, USER_POOL_ID_REGEX};
+use crate::{http, validator::regex_in_array};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const LIST_USERS_NAME: &str = "ListUsers";
+pub const LIST_USERS_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.ListUsers";
+
+const USERS_KEY: &str = "Users";
+const SEARCHABLE_ATTRIBUTES: [&str; 10] = [
+    "username",
+    "email",
+    "phone_number",
+    "name",
+    "given_name",
+    "family_name",
+    "preferred_username",
+    "cognito:user_status",
+    "status",
+    "sub",
+];
+
+static FILTER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\s*([\w:]+)\s*(\^?=)\s*"((?:[^"\\]|\\.)*)"\s*$"#).unwrap());
+static PAGINATION_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+fn validate_attributes_to_get(value: &[String]) -> Result<(), ValidationError> {
+    regex_in_array(value, &NAME_REGEX)
+}
+
+super::gen_response_err!(
+    ListUsersError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListUsersRequest {
+    #[validate(custom(function = validate_attributes_to_get))]
+    pub attributes_to_get: Option<Vec<String>>,
+    #[validate(length(max = 256))]
+    pub filter: Option<String>,
+    #[validate(range(min = 0, max = 60))]
+    pub limit: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *PAGINATION_TOKEN_REGEX))]
+    pub pagination_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListUsersRequest {
+    fn to_action_name() -> &'static str {
+        LIST_USERS_NAME
+    }
+}
+
+impl super::ToResponse for ListUsersRequest {
+    type E = ListUsersError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_converted_json_response(self, LIST_USERS_NAME, |value| {
+                self.to_users_page(value)
+            });
+        }
+        super::to_converted_json_response(self, LIST_USERS_NAME, |mut value| {
+            let users = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .users
+                    .values()
+                    .map(|user| user.to_value("Attributes"))
+                    .collect::<Vec<_>>())
+            })
+            .map_err(super::to_response_error)?;
+            value[USERS_KEY] = serde_json::Value::Array(users);
+            self.to_users_page(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        self.user_filter()
+            .err()
+            .map(|message| (ListUsersError::InvalidParameterException, message))
+    }
+}
+
+impl super::ToPage for ListUsersRequest {
+    fn items_key() -> &'static str {
+        USERS_KEY
+    }
+
+    fn item_key() -> &'static str {
+        "Username"
+    }
+
+    fn token_key() -> &'static str {
+        "PaginationToken"
+    }
+
+    fn max_page_size() -> u8 {
+        60
+    }
+
+    fn page_limit(&self) -> Option<u8> {
+        self.limit
+    }
+
+    fn page_token(&self) -> Option<&str> {
+        self.pagination_token.as_deref()
+    }
+}
+
+impl ListUsersRequest {
+    fn user_filter(&self) -> Result<Option<UserFilter>, String> {
+        self.filter
+            .as_deref()
+            .filter(|f| !f.trim().is_empty())
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Filters users rendered from the template or stored, and returns a page of them.
+    fn to_users_page(
+        &self,
+        mut value: serde_json::Value,
+    ) -> Result<serde_json::Value, (super::ResponseError<ListUsersError>, String)> {
+        let mut users = match value.get(USERS_KEY).and_then(|u| u.as_array()) {
+            Some(users) => users.clone(),
+            None => return Ok(value),
+        };
+        let filter = self.user_filter().map_err(|message| {
+            (
+                super::ResponseError::ActionError(ListUsersError::InvalidParameterException),
+                message,
+            )
+        })?;
+        if let Some(filter) = filter {
+            users.retain(|user| filter.matches(user));
+        }
+        if let Some(names) = self.attributes_to_get.as_ref() {
+            for user in users.iter_mut() {
+                if let Some(attributes) = user.get_mut("Attributes").and_then(|a| a.as_array_mut())
+                {
+                    attributes.retain(|a| {
+                        a["Name"]
+                            .as_str()
+                            .is_some_and(|name| names.iter().any(|n| n == name))
+                    });
+                }
+            }
+        }
+
+        value[USERS_KEY] = serde_json::Value::Array(users);
+        super::to_page(self, value)
+    }
+}
+
+/// Filter type of a ListUsers filter expression.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UserFilterType {
+    /// `=`
+    Equals,
+    /// `^=`
+    StartsWith,
+}
+
+/// ListUsers filter expression (e.g. `email ^= "user"`).
+#[derive(Debug, PartialEq, Eq)]
+pub struct UserFilter {
+    pub attribute_name: String,
+    pub filter_type: UserFilterType,
+    pub value: String,
+}
+
+impl std::str::FromStr for UserFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let captures = FILTER_REGEX
+            .captures(s)
+            .ok_or_else(|| "Error while parsing filter.".to_string())?;
+        let attribute_name = captures[1].to_string();
+        if !SEARCHABLE_ATTRIBUTES.contains(&attribute_name.as_str()) {
+            return Err(format!("Invalid search attribute: {}", attribute_name));
+        }
+        let filter_type = match &captures[2] {
+            "=" => UserFilterType::Equals,
+            _ => UserFilterType::StartsWith,
+        };
+        let value = captures[3].replace("\\\"", "\"").replace("\\\\", "\\");
+        Ok(UserFilter {
+            attribute_name,
+            filter_type,
+            value,
+        })
+    }
+}
+
+impl UserFilter {
+    /// Returns true if a user (UserType json) matches the filter.
+    pub fn matches(&self, user: &serde_json::Value) -> bool {
+        let value = match self.attribute_name.as_str() {
+            "username" => user["Username"].as_str().map(str::to_string),
+            "cognito:user_status" => user["UserStatus"].as_str().map(str::to_string),
+            "status" => user["Enabled"]
+                .as_bool()
+                .map(|enabled| if enabled { "Enabled" } else { "Disabled" }.to_string()),
+            name => user["Attributes"].as_array().and_then(|attributes| {
+                attributes
+                    .iter()
+                    .find(|a| a["Name"] == name)
+                    .and_then(|a| a["Value"].as_str())
+                    .map(str::to_string)
+            }),
+        };
+        value.is_some_and(|v| match self.filter_type {
+            UserFilterType::Equals => v == self.value,
+            UserFilterType::StartsWith => v.starts_with(&self.value),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListUsersRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListUsersRequest {
+            user_pool_id: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn success_to_parse_filter() {
+        let filter = "email ^= \"user\"".parse::<UserFilter>();
+        assert_eq!(
+            Ok(UserFilter {
+                attribute_name: "email".to_string(),
+                filter_type: UserFilterType::StartsWith,
+                value: "user".to_string(),
+            }),
+            filter
+        );
+
+        let filter = "status=\"Enabled\"".parse::<UserFilter>();
+        assert_eq!(UserFilterType::Equals, filter.unwrap().filter_type);
+    }
+
+    #[test]
+    fn failure_to_parse_filter() {
+        assert!("email = user".parse::<UserFilter>().is_err());
+        assert!("email != \"user\"".parse::<UserFilter>().is_err());
+        assert!("custom:foo = \"bar\"".parse::<UserFilter>().is_err());
+    }
+
+    #[test]
+    fn filter_matches_user() {
+        let user = serde_json::json!({
+            "Attributes": [{ "Name": "email", "Value": "user@example.com" }],
+            "Enabled": false,
+            "UserStatus": "CONFIRMED",
+            "Username": "username"
+        });
+        let matches = |f: &str| f.parse::<UserFilter>().unwrap().matches(&user);
+        assert!(matches("email ^= \"user@\""));
+        assert!(!matches("email = \"user@\""));
+        assert!(matches("username = \"username\""));
+        assert!(matches("cognito:user_status = \"CONFIRMED\""));
+        assert!(matches("status = \"Disabled\""));
+        assert!(!matches("name = \"user\""));
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = ListUsersError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListUsersError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
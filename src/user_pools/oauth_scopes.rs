@@ -0,0 +1,199 @@
+use super::{StoreError, StoreResult, UserPoolState};
+use std::collections::HashSet;
+
+/// OAuth scopes reserved by Cognito.
+pub const STANDARD_OAUTH_SCOPES: [&str; 5] = [
+    "aws.cognito.signin.user.admin",
+    "email",
+    "openid",
+    "phone",
+    "profile",
+];
+
+const CUSTOM_SCOPE_SEPARATOR: char = '/';
+
+/// Returns a message for the first duplicated scope name of a resource server.
+pub fn check_scope_names(scopes: &[super::ResourceServerScopeType]) -> Option<String> {
+    let mut names = HashSet::new();
+    scopes
+        .iter()
+        .filter_map(|scope| scope.scope_name.as_deref())
+        .find(|name| !names.insert(*name))
+        .map(|name| format!("Duplicate scope names are not allowed: {}", name))
+}
+
+/// Returns a message for the first OAuth scope which is neither a standard nor a custom scope.
+///
+/// User pools which are not stored have no resource servers to check custom scopes against.
+pub fn check_oauth_scopes(scopes: &[String]) -> Option<String> {
+    scopes
+        .iter()
+        .find(|scope| {
+            !STANDARD_OAUTH_SCOPES.contains(&scope.as_str())
+                && !scope.contains(CUSTOM_SCOPE_SEPARATOR)
+        })
+        .map(|scope| format!("Invalid scope requested: {}", scope))
+}
+
+fn resource_server_not_found(identifier: &str) -> (StoreError, String) {
+    (
+        StoreError::ResourceNotFoundException,
+        format!("Resource server {} does not exist.", identifier),
+    )
+}
+
+impl UserPoolState {
+    /// Stores `ResourceServer` of CreateResourceServer.
+    pub fn add_resource_server(
+        &mut self,
+        resource_server: serde_json::Value,
+    ) -> StoreResult<&serde_json::Value> {
+        let identifier = resource_server["Identifier"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        if self.resource_servers.contains_key(&identifier) {
+            return Err((
+                StoreError::InvalidParameterException,
+                format!("{} already exists in user pool {}.", identifier, self.id),
+            ));
+        }
+        Ok(self
+            .resource_servers
+            .entry(identifier)
+            .or_insert(resource_server))
+    }
+
+    /// Returns a stored resource server by the identifier.
+    pub fn resource_server(&self, identifier: &str) -> StoreResult<&serde_json::Value> {
+        self.resource_servers
+            .get(identifier)
+            .ok_or_else(|| resource_server_not_found(identifier))
+    }
+
+    /// Replaces a stored resource server with `ResourceServer` of UpdateResourceServer.
+    pub fn update_resource_server(
+        &mut self,
+        resource_server: serde_json::Value,
+    ) -> StoreResult<&serde_json::Value> {
+        let identifier = resource_server["Identifier"].as_str().unwrap_or_default();
+        let stored = self
+            .resource_servers
+            .get_mut(identifier)
+            .ok_or_else(|| resource_server_not_found(identifier))?;
+        *stored = resource_server;
+        Ok(stored)
+    }
+
+    /// Removes a stored resource server.
+    pub fn remove_resource_server(&mut self, identifier: &str) -> StoreResult<()> {
+        self.resource_servers
+            .remove(identifier)
+            .map(|_| ())
+            .ok_or_else(|| resource_server_not_found(identifier))
+    }
+
+    /// Checks that OAuth scopes are standard scopes or scopes of the stored resource servers.
+    pub fn check_allowed_oauth_scopes(&self, scopes: &[String]) -> StoreResult<()> {
+        let custom_scopes = self
+            .resource_servers
+            .values()
+            .flat_map(|resource_server| {
+                let identifier = resource_server["Identifier"].as_str().unwrap_or_default();
+                resource_server["Scopes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|scope| scope["ScopeName"].as_str())
+                    .map(move |name| format!("{}{}{}", identifier, CUSTOM_SCOPE_SEPARATOR, name))
+            })
+            .collect::<HashSet<_>>();
+        match scopes.iter().find(|scope| {
+            !STANDARD_OAUTH_SCOPES.contains(&scope.as_str()) && !custom_scopes.contains(*scope)
+        }) {
+            Some(scope) => Err((
+                StoreError::ScopeDoesNotExistException,
+                format!("Invalid scope requested: {}", scope),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::ResourceServerScopeType;
+    use pretty_assertions::assert_eq;
+
+    fn scope(name: &str) -> ResourceServerScopeType {
+        ResourceServerScopeType {
+            scope_description: Some("description".to_string()),
+            scope_name: Some(name.to_string()),
+        }
+    }
+
+    #[test]
+    fn unique_scope_names_are_valid() {
+        assert_eq!(None, check_scope_names(&[scope("read"), scope("write")]));
+    }
+
+    #[test]
+    fn duplicated_scope_names_are_invalid() {
+        assert_eq!(
+            Some("Duplicate scope names are not allowed: read".to_string()),
+            check_scope_names(&[scope("read"), scope("write"), scope("read")])
+        );
+    }
+
+    #[test]
+    fn standard_and_custom_scopes_are_valid() {
+        let scopes = vec![
+            "openid".to_string(),
+            "https://api.example.com/read".to_string(),
+        ];
+        assert_eq!(None, check_oauth_scopes(&scopes));
+    }
+
+    #[test]
+    fn scopes_of_stored_resource_servers_are_allowed() {
+        let mut user_pool = UserPoolState::new("pool");
+        let resource_server = serde_json::json!({
+            "Identifier": "https://api.example.com",
+            "Name": "api",
+            "Scopes": [{ "ScopeDescription": "read", "ScopeName": "read" }],
+        });
+        user_pool
+            .add_resource_server(resource_server.clone())
+            .unwrap();
+        assert_eq!(
+            Err(StoreError::InvalidParameterException),
+            user_pool
+                .add_resource_server(resource_server)
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+
+        let scopes = vec![
+            "openid".to_string(),
+            "https://api.example.com/read".to_string(),
+        ];
+        assert_eq!(Ok(()), user_pool.check_allowed_oauth_scopes(&scopes));
+        let scopes = vec!["https://api.example.com/write".to_string()];
+        assert_eq!(
+            Err(StoreError::ScopeDoesNotExistException),
+            user_pool
+                .check_allowed_oauth_scopes(&scopes)
+                .map_err(|(e, _)| e)
+        );
+    }
+
+    #[test]
+    fn unknown_scopes_are_invalid() {
+        let scopes = vec!["openid".to_string(), "admin".to_string()];
+        assert_eq!(
+            Some("Invalid scope requested: admin".to_string()),
+            check_oauth_scopes(&scopes)
+        );
+    }
+}
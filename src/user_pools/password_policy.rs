@@ -0,0 +1,231 @@
+use super::{StoreError, StoreResult, UserPoolState};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// Config section of the password policy applied to every user pool.
+pub const CONFIG_PASSWORD_POLICY: &str = "PasswordPolicy";
+
+/// Minimum password length when the policy does not specify it.
+pub const DEFAULT_MINIMUM_PASSWORD_LENGTH: i64 = 8;
+
+/// Days before a temporary password expires when the policy does not specify it.
+pub const DEFAULT_TEMPORARY_PASSWORD_VALIDITY_DAYS: i64 = 7;
+
+const PASSWORD_POLICY_ERROR_PREFIX: &str = "Password did not conform with policy";
+const PASSWORD_SYMBOLS: &str = "^$*.[]{}()?\"!@#%&/\\,><':;|_~`=+- ";
+
+/// Returns a policy violation message if the password breaks the configured password policy.
+pub fn check_password(password: &str) -> Option<String> {
+    configured_password_policy()?.check_password(password)
+}
+
+/// Returns the password policy in the config file.
+pub fn configured_password_policy() -> Option<super::PasswordPolicyType> {
+    super::config()
+        .get(CONFIG_PASSWORD_POLICY)
+        .map(password_policy_from_config)
+}
+
+fn password_policy_from_config(config: &HashMap<String, String>) -> super::PasswordPolicyType {
+    fn parse<T: std::str::FromStr>(config: &HashMap<String, String>, name: &str) -> Option<T> {
+        config.get(name).and_then(|v| v.parse().ok())
+    }
+
+    super::PasswordPolicyType {
+        minimum_length: parse(config, "minimum_length"),
+        require_lowercase: parse(config, "require_lowercase"),
+        require_numbers: parse(config, "require_numbers"),
+        require_symbols: parse(config, "require_symbols"),
+        require_uppercase: parse(config, "require_uppercase"),
+        temporary_password_validity_days: parse(config, "temporary_password_validity_days"),
+    }
+}
+
+impl super::PasswordPolicyType {
+    /// Returns a policy violation message for the first rule the password breaks.
+    pub fn check_password(&self, password: &str) -> Option<String> {
+        let minimum_length = self
+            .minimum_length
+            .unwrap_or(DEFAULT_MINIMUM_PASSWORD_LENGTH);
+        let requires = |required: Option<bool>, f: fn(char) -> bool| {
+            required.unwrap_or(false) && !password.chars().any(f)
+        };
+
+        let violation = if (password.chars().count() as i64) < minimum_length {
+            "Password not long enough"
+        } else if requires(self.require_lowercase, |c| c.is_ascii_lowercase()) {
+            "Password must have lowercase characters"
+        } else if requires(self.require_uppercase, |c| c.is_ascii_uppercase()) {
+            "Password must have uppercase characters"
+        } else if requires(self.require_numbers, |c| c.is_ascii_digit()) {
+            "Password must have numeric characters"
+        } else if requires(self.require_symbols, |c| PASSWORD_SYMBOLS.contains(c)) {
+            "Password must have symbol characters"
+        } else {
+            return None;
+        };
+        Some(format!("{}: {}", PASSWORD_POLICY_ERROR_PREFIX, violation))
+    }
+}
+
+impl UserPoolState {
+    /// Returns `Policies.PasswordPolicy` of the user pool, or the configured password policy.
+    pub fn password_policy(&self) -> Option<super::PasswordPolicyType> {
+        self.description
+            .pointer("/Policies/PasswordPolicy")
+            .and_then(|policy| serde_json::from_value(policy.clone()).ok())
+            .or_else(configured_password_policy)
+    }
+
+    /// Returns an error if the password breaks the password policy of the user pool.
+    pub fn check_password(&self, password: &str) -> StoreResult<()> {
+        match self
+            .password_policy()
+            .and_then(|policy| policy.check_password(password))
+        {
+            Some(message) => Err((StoreError::InvalidPasswordException, message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the time when a temporary password set now expires.
+    ///
+    /// An error is returned if the validity days are out of the range of a duration.
+    pub fn temporary_password_expires_at(&self) -> StoreResult<DateTime<Utc>> {
+        let days = self
+            .password_policy()
+            .and_then(|policy| policy.temporary_password_validity_days)
+            .unwrap_or(DEFAULT_TEMPORARY_PASSWORD_VALIDITY_DAYS);
+        Duration::try_days(days)
+            .and_then(|validity| Utc::now().checked_add_signed(validity))
+            .ok_or_else(|| {
+                (
+                    StoreError::InternalErrorException,
+                    format!(
+                        "temporary_password_validity_days of the password policy is out of range: {}",
+                        days
+                    ),
+                )
+            })
+    }
+
+    /// Returns a new temporary password which conforms to the password policy.
+    pub fn new_temporary_password(&self) -> String {
+        let minimum_length = self
+            .password_policy()
+            .and_then(|policy| policy.minimum_length)
+            .unwrap_or(DEFAULT_MINIMUM_PASSWORD_LENGTH);
+        super::new_temporary_password(minimum_length.max(0) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::PasswordPolicyType;
+    use pretty_assertions::assert_eq;
+
+    fn strict_policy() -> PasswordPolicyType {
+        PasswordPolicyType {
+            minimum_length: Some(10),
+            require_lowercase: Some(true),
+            require_numbers: Some(true),
+            require_symbols: Some(true),
+            require_uppercase: Some(true),
+            temporary_password_validity_days: Some(7),
+        }
+    }
+
+    #[test]
+    fn success_to_check_password() {
+        assert_eq!(None, strict_policy().check_password("Passw0rd!!"));
+        assert_eq!(
+            None,
+            PasswordPolicyType::default().check_password("password")
+        );
+    }
+
+    #[test]
+    fn failure_to_check_password() {
+        let policy = strict_policy();
+        let message = |violation: &str| {
+            Some(format!(
+                "Password did not conform with policy: {}",
+                violation
+            ))
+        };
+        assert_eq!(
+            message("Password not long enough"),
+            policy.check_password("Passw0rd!")
+        );
+        assert_eq!(
+            message("Password must have lowercase characters"),
+            policy.check_password("PASSW0RD!!")
+        );
+        assert_eq!(
+            message("Password must have uppercase characters"),
+            policy.check_password("passw0rd!!")
+        );
+        assert_eq!(
+            message("Password must have numeric characters"),
+            policy.check_password("Password!!")
+        );
+        assert_eq!(
+            message("Password must have symbol characters"),
+            policy.check_password("Passw0rd00")
+        );
+        assert_eq!(
+            message("Password not long enough"),
+            PasswordPolicyType::default().check_password("passwd")
+        );
+    }
+
+    #[test]
+    fn password_policy_can_read_from_config() {
+        let config = HashMap::from([
+            ("minimum_length".to_string(), "12".to_string()),
+            ("require_numbers".to_string(), "true".to_string()),
+            ("require_symbols".to_string(), "invalid".to_string()),
+        ]);
+        let policy = password_policy_from_config(&config);
+        assert_eq!(Some(12), policy.minimum_length);
+        assert_eq!(Some(true), policy.require_numbers);
+        assert_eq!(None, policy.require_symbols);
+        assert_eq!(None, policy.require_lowercase);
+    }
+
+    #[test]
+    fn user_pool_policy_overrides_configured_policy() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool.description = serde_json::json!({
+            "Policies": {
+                "PasswordPolicy": { "MinimumLength": 16, "TemporaryPasswordValidityDays": 1 }
+            }
+        });
+        assert_eq!(
+            Some(StoreError::InvalidPasswordException),
+            user_pool.check_password("Passw0rd!").err().map(|(e, _)| e)
+        );
+        let password = user_pool.new_temporary_password();
+        assert_eq!(16, password.len());
+        assert_eq!(Ok(()), user_pool.check_password(&password));
+        assert!(
+            user_pool.temporary_password_expires_at().unwrap() <= Utc::now() + Duration::days(1)
+        );
+    }
+
+    #[test]
+    fn failure_to_expire_temporary_password_out_of_range() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool.description = serde_json::json!({
+            "Policies": { "PasswordPolicy": { "TemporaryPasswordValidityDays": i64::MAX } }
+        });
+        assert_eq!(
+            Some(StoreError::InternalErrorException),
+            user_pool
+                .temporary_password_expires_at()
+                .err()
+                .map(|(e, _)| e)
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use super::{StoreError, StoreResult, UserPoolState};
+use chrono::{DateTime, Utc};
+
+/// Provider name of the native users of user pools.
+pub const COGNITO_PROVIDER_NAME: &str = "Cognito";
+
+const DEFAULT_PROVIDER_ATTRIBUTE_NAME: &str = "Cognito_Subject";
+const MAX_PROVIDER_LINKS: usize = 5;
+
+/// Federated identity linked to a native user by AdminLinkProviderForUser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderLink {
+    pub provider_name: String,
+    pub provider_attribute_name: String,
+    pub provider_attribute_value: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ProviderLink {
+    pub fn new(source_user: &super::ProviderUserIdentifierType) -> Self {
+        ProviderLink {
+            provider_name: source_user.provider_name.clone().unwrap_or_default(),
+            provider_attribute_name: source_user
+                .provider_attribute_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_PROVIDER_ATTRIBUTE_NAME.to_string()),
+            provider_attribute_value: source_user
+                .provider_attribute_value
+                .clone()
+                .unwrap_or_default(),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Returns the username of the federated user of the identity (e.g. `Google_1234`).
+    pub fn federated_username(&self) -> String {
+        format!("{}_{}", self.provider_name, self.provider_attribute_value)
+    }
+
+    fn matches(&self, user: &super::ProviderUserIdentifierType) -> bool {
+        user.provider_name.as_deref() == Some(self.provider_name.as_str())
+            && user.provider_attribute_value.as_deref()
+                == Some(self.provider_attribute_value.as_str())
+            && user
+                .provider_attribute_name
+                .as_deref()
+                .is_none_or(|name| name == self.provider_attribute_name)
+    }
+
+    /// Returns the value of the `identities` attribute of a user with links.
+    pub fn identities_value(links: &[ProviderLink]) -> String {
+        links
+            .iter()
+            .map(|link| {
+                serde_json::json!({
+                    "dateCreated": link.created_at.timestamp_millis(),
+                    "issuer": null,
+                    "primary": false,
+                    "providerName": link.provider_name,
+                    "providerType": link.provider_name,
+                    "userId": link.provider_attribute_value,
+                })
+            })
+            .collect::<serde_json::Value>()
+            .to_string()
+    }
+}
+
+impl UserPoolState {
+    /// Returns the native user linked to a federated username.
+    pub fn linked_username(&self, federated_username: &str) -> Option<String> {
+        self.users
+            .values()
+            .find(|user| {
+                user.provider_links
+                    .iter()
+                    .any(|link| link.federated_username() == federated_username)
+            })
+            .map(|user| user.username.clone())
+    }
+
+    /// Links a federated identity to a native user (AdminLinkProviderForUser).
+    pub fn link_provider(
+        &mut self,
+        username: &str,
+        source_user: &super::ProviderUserIdentifierType,
+    ) -> StoreResult<()> {
+        let link = ProviderLink::new(source_user);
+        let destination = self.user(username)?.username.clone();
+        let linked = self.users.values().find(|user| {
+            user.provider_links
+                .iter()
+                .any(|l| l.federated_username() == link.federated_username())
+        });
+        if let Some(linked) = linked {
+            if linked.username == destination {
+                return Ok(());
+            }
+            return Err((
+                StoreError::AliasExistsException,
+                "The source user is already linked to another user.".to_string(),
+            ));
+        }
+        let user = self.user_mut(&destination)?;
+        if user.provider_links.len() >= MAX_PROVIDER_LINKS {
+            return Err((
+                StoreError::LimitExceededException,
+                format!(
+                    "A user can be linked to at most {} identities.",
+                    MAX_PROVIDER_LINKS
+                ),
+            ));
+        }
+        user.provider_links.push(link);
+        user.last_modified_at = Utc::now();
+        Ok(())
+    }
+
+    /// Removes the link of a federated identity (AdminDisableProviderForUser).
+    pub fn disable_provider(
+        &mut self,
+        user: &super::ProviderUserIdentifierType,
+    ) -> StoreResult<()> {
+        let linked = self
+            .users
+            .values_mut()
+            .find(|u| u.provider_links.iter().any(|link| link.matches(user)));
+        let Some(linked) = linked else {
+            return Err((
+                StoreError::UserNotFoundException,
+                "User does not exist.".to_string(),
+            ));
+        };
+        linked.provider_links.retain(|link| !link.matches(user));
+        linked.last_modified_at = Utc::now();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{ProviderUserIdentifierType, UserState, UserStatus};
+    use pretty_assertions::assert_eq;
+
+    fn google_user(value: &str) -> ProviderUserIdentifierType {
+        ProviderUserIdentifierType {
+            provider_attribute_name: Some("Cognito_Subject".to_string()),
+            provider_attribute_value: Some(value.to_string()),
+            provider_name: Some("Google".to_string()),
+        }
+    }
+
+    #[test]
+    fn linked_identity_resolves_to_native_user() {
+        let mut user_pool = UserPoolState::new("pool");
+        for username in ["user", "other"] {
+            user_pool
+                .add_user(UserState::new(username, UserStatus::Confirmed))
+                .unwrap();
+        }
+        user_pool
+            .link_provider("user", &google_user("1234"))
+            .unwrap();
+        assert_eq!(
+            Ok(()),
+            user_pool.link_provider("user", &google_user("1234"))
+        );
+        assert_eq!(
+            Err(StoreError::AliasExistsException),
+            user_pool
+                .link_provider("other", &google_user("1234"))
+                .map_err(|(e, _)| e)
+        );
+        assert_eq!(
+            Some("user".to_string()),
+            user_pool.linked_username("Google_1234")
+        );
+
+        user_pool.disable_provider(&google_user("1234")).unwrap();
+        assert_eq!(None, user_pool.linked_username("Google_1234"));
+        assert_eq!(
+            Err(StoreError::UserNotFoundException),
+            user_pool
+                .disable_provider(&google_user("1234"))
+                .map_err(|(e, _)| e)
+        );
+    }
+}
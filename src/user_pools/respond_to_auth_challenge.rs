@@ -0,0 +1,163 @@
+use crate::common::CLIENT_ID_REGEX;
+use crate::{http, validator::includes};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const RESPOND_TO_AUTH_CHALLENGE_NAME: &str = "RespondToAuthChallenge";
+pub const RESPOND_TO_AUTH_CHALLENGE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.RespondToAuthChallenge";
+
+fn validate_challenge_name(value: &str) -> Result<(), ValidationError> {
+    includes(
+        value,
+        vec![
+            "SMS_MFA",
+            "SOFTWARE_TOKEN_MFA",
+            "SELECT_MFA_TYPE",
+            "MFA_SETUP",
+            "PASSWORD_VERIFIER",
+            "CUSTOM_CHALLENGE",
+            "DEVICE_SRP_AUTH",
+            "DEVICE_PASSWORD_VERIFIER",
+            "ADMIN_NO_SRP_AUTH",
+            "NEW_PASSWORD_REQUIRED",
+        ],
+    )
+}
+
+super::gen_response_err!(
+    RespondToAuthChallengeError,
+    AliasExistsException
+    | CodeMismatchException
+    | ExpiredCodeException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidPasswordException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | InvalidUserPoolConfigurationException
+    | MFAMethodNotFoundException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | SoftwareTokenMFANotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct RespondToAuthChallengeRequest {
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(custom(function = validate_challenge_name))]
+    pub challenge_name: Option<String>,
+    pub challenge_responses: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(length(min = 20, max = 2048))]
+    pub session: Option<String>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+}
+
+impl super::ToActionName for RespondToAuthChallengeRequest {
+    fn to_action_name() -> &'static str {
+        RESPOND_TO_AUTH_CHALLENGE_NAME
+    }
+}
+
+impl super::ToResponse for RespondToAuthChallengeRequest {
+    type E = RespondToAuthChallengeError;
+    fn to_response(&self) -> super::Response {
+        let client_id = self.client_id.as_deref().unwrap_or_default();
+        if super::client_user_pool_id(client_id).is_none() {
+            return super::to_json_response(self, RESPOND_TO_AUTH_CHALLENGE_NAME);
+        }
+        super::to_converted_json_response(self, RESPOND_TO_AUTH_CHALLENGE_NAME, |value| {
+            let outcome = super::with_client_user_pool(client_id, |user_pool| {
+                let outcome = user_pool.respond_to_auth_challenge(
+                    client_id,
+                    self.challenge_name.as_deref().unwrap_or_default(),
+                    self.session.as_deref(),
+                    self.challenge_responses
+                        .as_ref()
+                        .unwrap_or(&Default::default()),
+                );
+                if let Some(username) = self
+                    .challenge_responses
+                    .as_ref()
+                    .and_then(|r| r.get("USERNAME"))
+                {
+                    user_pool.record_challenge_response(
+                        username,
+                        self.challenge_name.as_deref().unwrap_or_default(),
+                        &outcome,
+                    );
+                }
+                outcome
+            })
+            .map_err(super::to_response_error)?;
+            Ok(outcome.to_value(value))
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        if self.challenge_name.as_deref() != Some(super::NEW_PASSWORD_REQUIRED_CHALLENGE) {
+            return None;
+        }
+        let new_password = self.challenge_responses.as_ref()?.get("NEW_PASSWORD")?;
+        super::check_password(new_password).map(|message| {
+            (
+                RespondToAuthChallengeError::InvalidPasswordException,
+                message,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = RespondToAuthChallengeRequest {
+            challenge_name: Some("NEW_PASSWORD_REQUIRED".to_string()),
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = RespondToAuthChallengeRequest {
+            challenge_name: Some("UNKNOWN".to_string()),
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = RespondToAuthChallengeError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = RespondToAuthChallengeError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::common::{CLIENT_ID_REGEX, TOKEN_REGEX};
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const REVOKE_TOKEN_NAME: &str = "RevokeToken";
+pub const REVOKE_TOKEN_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.RevokeToken";
+
+static CLIENT_SECRET_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w+]+").unwrap());
+
+super::gen_response_err!(
+    RevokeTokenError,
+    InvalidParameterException
+    | TooManyRequestsException
+    | UnsupportedOperationException
+    | UnsupportedTokenTypeException => http::status_code(400),
+    UnauthorizedException => http::status_code(401),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct RevokeTokenRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    #[validate(length(min = 1, max = 64))]
+    #[validate(regex(path = *CLIENT_SECRET_REGEX))]
+    pub client_secret: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub token: Option<String>,
+}
+
+impl super::ToActionName for RevokeTokenRequest {
+    fn to_action_name() -> &'static str {
+        REVOKE_TOKEN_NAME
+    }
+}
+
+impl super::ToResponse for RevokeTokenRequest {
+    type E = RevokeTokenError;
+    fn to_response(&self) -> super::Response {
+        let client_id = self.client_id.as_deref().unwrap_or_default();
+        if super::client_user_pool_id(client_id).is_none() {
+            return super::to_empty_response(self);
+        }
+        super::to_applied_empty_response(self, || {
+            super::with_client_user_pool(client_id, |user_pool| {
+                if user_pool.client(client_id)?.client_secret != self.client_secret {
+                    return Err((
+                        super::StoreError::UnauthorizedException,
+                        "invalid_client".to_string(),
+                    ));
+                }
+                user_pool.revoke_refresh_token(client_id, self.token.as_deref().unwrap_or_default())
+            })
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = RevokeTokenRequest {
+            client_id: Some("client_id".to_string()),
+            token: Some("token".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = RevokeTokenRequest {
+            client_id: Some("client_id".to_string()),
+            token: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = RevokeTokenError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = RevokeTokenError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
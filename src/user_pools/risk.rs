@@ -0,0 +1,375 @@
+use super::{StoreResult, UserPoolState};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use strum_macros::Display;
+
+/// Config section of the risk rules applied to authentications.
+pub const CONFIG_RISK_RULES: &str = "RiskRules";
+
+/// Error message of authentications blocked by the risk rules.
+pub const BLOCKED_BY_RISK_MESSAGE: &str = "Unable to login because of security reasons.";
+
+const CONFIG_ADVANCED_SECURITY_MODE: &str = "advanced_security_mode";
+const CONFIG_SKIPPED_IP_RANGES: &str = "skipped_ip_ranges";
+const CONFIG_BLOCKED_IP_RANGES: &str = "blocked_ip_ranges";
+const CONFIG_MFA_IP_RANGES: &str = "mfa_ip_ranges";
+const CONFIG_BLOCKED_DEVICE_KEYS: &str = "blocked_device_keys";
+const CONFIG_MFA_DEVICE_KEYS: &str = "mfa_device_keys";
+const CONFIG_COMPROMISED_PASSWORDS: &str = "compromised_passwords";
+const ENFORCED_SECURITY_MODE: &str = "ENFORCED";
+
+/// Risk decision of an authentication.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Display)]
+pub enum RiskDecision {
+    NoRisk,
+    AccountTakeover,
+    Block,
+}
+
+impl RiskDecision {
+    /// Returns `RiskLevel` of auth events with the decision.
+    pub fn risk_level(self) -> &'static str {
+        match self {
+            RiskDecision::NoRisk => "Low",
+            RiskDecision::AccountTakeover => "Medium",
+            RiskDecision::Block => "High",
+        }
+    }
+}
+
+/// Sign-in context evaluated by the risk rules.
+#[derive(Debug, Default)]
+pub struct RiskContext<'a> {
+    pub ip_address: Option<&'a str>,
+    pub device_key: Option<&'a str>,
+    pub password: Option<&'a str>,
+}
+
+/// Returns `RiskRules` of the config, which are empty if not configured.
+pub fn configured_risk_rules() -> HashMap<String, String> {
+    super::config()
+        .get(CONFIG_RISK_RULES)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Returns the risk decision of the configured risk rules for a sign-in.
+pub fn evaluate_risk(context: &RiskContext) -> RiskDecision {
+    evaluate_risk_with(&configured_risk_rules(), context)
+}
+
+/// Returns true if a password is one of the compromised passwords of risk rules.
+pub fn is_compromised_password(rules: &HashMap<String, String>, password: &str) -> bool {
+    rules
+        .get(CONFIG_COMPROMISED_PASSWORDS)
+        .is_some_and(|passwords| split_list(passwords).any(|p| p == password))
+}
+
+/// Returns the risk decision of risk rules for a sign-in.
+pub fn evaluate_risk_with(rules: &HashMap<String, String>, context: &RiskContext) -> RiskDecision {
+    let enforced = rules
+        .get(CONFIG_ADVANCED_SECURITY_MODE)
+        .is_none_or(|mode| mode == ENFORCED_SECURITY_MODE);
+    let ip_matches = |ip_ranges: &str| {
+        context
+            .ip_address
+            .zip(rules.get(ip_ranges))
+            .is_some_and(|(ip, ranges)| ip_in_ranges(ip, ranges))
+    };
+    let listed = |value: Option<&str>, list: &str| {
+        value
+            .zip(rules.get(list))
+            .is_some_and(|(value, list)| split_list(list).any(|v| v == value))
+    };
+
+    if !enforced || ip_matches(CONFIG_SKIPPED_IP_RANGES) {
+        RiskDecision::NoRisk
+    } else if ip_matches(CONFIG_BLOCKED_IP_RANGES)
+        || listed(context.device_key, CONFIG_BLOCKED_DEVICE_KEYS)
+        || context
+            .password
+            .is_some_and(|p| is_compromised_password(rules, p))
+    {
+        RiskDecision::Block
+    } else if ip_matches(CONFIG_MFA_IP_RANGES) || listed(context.device_key, CONFIG_MFA_DEVICE_KEYS)
+    {
+        RiskDecision::AccountTakeover
+    } else {
+        RiskDecision::NoRisk
+    }
+}
+
+/// Returns `RiskConfiguration` of DescribeRiskConfiguration which risk rules amount to.
+pub fn risk_configuration(rules: &HashMap<String, String>) -> serde_json::Value {
+    let ip_ranges = |name: &str| {
+        rules
+            .get(name)
+            .map(|ranges| split_list(ranges).collect::<Vec<_>>())
+            .unwrap_or_default()
+    };
+    let compromised_action = if rules.contains_key(CONFIG_COMPROMISED_PASSWORDS) {
+        "BLOCK"
+    } else {
+        "NO_ACTION"
+    };
+    serde_json::json!({
+        "AccountTakeoverRiskConfiguration": {
+            "Actions": {
+                "HighAction": { "EventAction": "BLOCK", "Notify": false },
+                "LowAction": { "EventAction": "NO_ACTION", "Notify": false },
+                "MediumAction": { "EventAction": "MFA_REQUIRED", "Notify": false }
+            }
+        },
+        "CompromisedCredentialsRiskConfiguration": {
+            "Actions": { "EventAction": compromised_action },
+            "EventFilter": ["SIGN_IN"]
+        },
+        "RiskExceptionConfiguration": {
+            "BlockedIPRangeList": ip_ranges(CONFIG_BLOCKED_IP_RANGES),
+            "SkippedIPRangeList": ip_ranges(CONFIG_SKIPPED_IP_RANGES)
+        }
+    })
+}
+
+impl UserPoolState {
+    /// Stores `RiskConfiguration` of SetRiskConfiguration for the user pool or an app client.
+    pub fn set_risk_configuration(
+        &mut self,
+        client_id: Option<&str>,
+        configuration: serde_json::Value,
+    ) -> StoreResult<()> {
+        if let Some(client_id) = client_id {
+            self.client(client_id)?;
+        }
+        self.risk_configurations
+            .insert(client_id.map(str::to_string), configuration);
+        Ok(())
+    }
+
+    /// Returns the risk configuration stored for an app client or the user pool.
+    pub fn risk_configuration(&self, client_id: Option<&str>) -> Option<&serde_json::Value> {
+        client_id
+            .and_then(|c| self.risk_configurations.get(&Some(c.to_string())))
+            .or_else(|| self.risk_configurations.get(&None))
+    }
+
+    /// Returns `RiskRules` of the config overridden by `UserPoolAddOns` and the stored risk configuration.
+    pub fn risk_rules(&self, client_id: Option<&str>) -> HashMap<String, String> {
+        let mut rules = configured_risk_rules();
+        if let Some(mode) = self
+            .description
+            .pointer("/UserPoolAddOns/AdvancedSecurityMode")
+            .and_then(|v| v.as_str())
+        {
+            rules.insert(CONFIG_ADVANCED_SECURITY_MODE.to_string(), mode.to_string());
+        }
+        let Some(configuration) = self.risk_configuration(client_id) else {
+            return rules;
+        };
+        let exceptions = &configuration["RiskExceptionConfiguration"];
+        for (name, list) in [
+            (CONFIG_BLOCKED_IP_RANGES, "BlockedIPRangeList"),
+            (CONFIG_SKIPPED_IP_RANGES, "SkippedIPRangeList"),
+        ] {
+            if let Some(ranges) = exceptions[list].as_array() {
+                let ranges = ranges.iter().filter_map(|r| r.as_str()).collect::<Vec<_>>();
+                rules.insert(name.to_string(), ranges.join(","));
+            }
+        }
+        let compromised_action =
+            configuration.pointer("/CompromisedCredentialsRiskConfiguration/Actions/EventAction");
+        if compromised_action.and_then(|a| a.as_str()) == Some("NO_ACTION") {
+            rules.remove(CONFIG_COMPROMISED_PASSWORDS);
+        }
+        rules
+    }
+}
+
+/// Returns true if an ip address is in any of comma separated ip addresses or CIDR blocks.
+pub fn ip_in_ranges(ip_address: &str, ranges: &str) -> bool {
+    match ip_address.trim().parse::<IpAddr>() {
+        Ok(ip) => split_list(ranges).any(|range| ip_in_range(ip, range)),
+        Err(_) => false,
+    }
+}
+
+/// Returns true if a string is an ip address or a CIDR block.
+pub fn is_ip_range(range: &str) -> bool {
+    let (address, prefix) = match range.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (range, None),
+    };
+    let bits = match address.parse::<IpAddr>() {
+        Ok(IpAddr::V4(_)) => 32,
+        Ok(IpAddr::V6(_)) => 128,
+        Err(_) => return false,
+    };
+    prefix.is_none_or(|p| p.parse::<u32>().is_ok_and(|p| p <= bits))
+}
+
+fn ip_in_range(ip: IpAddr, range: &str) -> bool {
+    let (address, prefix) = match range.split_once('/') {
+        Some((address, prefix)) => (address, prefix.parse::<u32>().ok()),
+        None => (range, None),
+    };
+    let (ip, network, bits) = match (ip, address.parse::<IpAddr>()) {
+        (IpAddr::V4(ip), Ok(IpAddr::V4(network))) => {
+            (u32::from(ip) as u128, u32::from(network) as u128, 32)
+        }
+        (IpAddr::V6(ip), Ok(IpAddr::V6(network))) => (u128::from(ip), u128::from(network), 128),
+        _ => return false,
+    };
+    let prefix = prefix.unwrap_or(bits);
+    if prefix > bits {
+        return false;
+    }
+    let shift = bits - prefix;
+    shift == bits || (ip >> shift) == (network >> shift)
+}
+
+fn split_list(list: &str) -> impl Iterator<Item = &str> {
+    list.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn ip_address_matches_ranges() {
+        assert!(ip_in_ranges("192.0.2.10", "192.0.2.0/24"));
+        assert!(ip_in_ranges("198.51.100.7", "192.0.2.0/24, 198.51.100.7"));
+        assert!(ip_in_ranges("2001:db8::1", "2001:db8::/32"));
+        assert!(ip_in_ranges("203.0.113.1", "0.0.0.0/0"));
+        assert!(!ip_in_ranges("192.0.3.10", "192.0.2.0/24"));
+        assert!(!ip_in_ranges("192.0.2.10", "2001:db8::/32"));
+        assert!(!ip_in_ranges("invalid", "192.0.2.0/24"));
+    }
+
+    #[test]
+    fn string_is_ip_range() {
+        assert!(is_ip_range("192.0.2.0/24"));
+        assert!(is_ip_range("192.0.2.1"));
+        assert!(is_ip_range("2001:db8::/32"));
+        assert!(!is_ip_range("192.0.2.0/33"));
+        assert!(!is_ip_range("192.0.2.0/"));
+        assert!(!is_ip_range("invalid"));
+    }
+
+    #[test]
+    fn risk_rules_yield_risk_decision() {
+        let rules = HashMap::from([
+            (
+                CONFIG_SKIPPED_IP_RANGES.to_string(),
+                "198.51.100.7".to_string(),
+            ),
+            (
+                CONFIG_BLOCKED_IP_RANGES.to_string(),
+                "192.0.2.0/24".to_string(),
+            ),
+            (CONFIG_MFA_IP_RANGES.to_string(), "0.0.0.0/0".to_string()),
+            (
+                CONFIG_BLOCKED_DEVICE_KEYS.to_string(),
+                "us-east-1_blocked".to_string(),
+            ),
+            (
+                CONFIG_COMPROMISED_PASSWORDS.to_string(),
+                "password, 12345678".to_string(),
+            ),
+        ]);
+        let decision = |ip_address, device_key, password| {
+            evaluate_risk_with(
+                &rules,
+                &RiskContext {
+                    ip_address,
+                    device_key,
+                    password,
+                },
+            )
+        };
+        assert_eq!(RiskDecision::Block, decision(Some("192.0.2.1"), None, None));
+        assert_eq!(
+            RiskDecision::Block,
+            decision(None, Some("us-east-1_blocked"), None)
+        );
+        assert_eq!(RiskDecision::Block, decision(None, None, Some("12345678")));
+        assert_eq!(
+            RiskDecision::AccountTakeover,
+            decision(Some("198.51.100.1"), None, None)
+        );
+        assert_eq!(
+            RiskDecision::NoRisk,
+            decision(Some("198.51.100.7"), None, Some("password"))
+        );
+        assert_eq!(
+            RiskDecision::NoRisk,
+            decision(None, Some("us-east-1_device"), Some("Passw0rd!"))
+        );
+    }
+
+    #[test]
+    fn risk_rules_are_not_enforced_in_audit_mode() {
+        let rules = HashMap::from([
+            (
+                CONFIG_ADVANCED_SECURITY_MODE.to_string(),
+                "AUDIT".to_string(),
+            ),
+            (
+                CONFIG_BLOCKED_IP_RANGES.to_string(),
+                "0.0.0.0/0".to_string(),
+            ),
+        ]);
+        let context = RiskContext {
+            ip_address: Some("192.0.2.1"),
+            ..Default::default()
+        };
+        assert_eq!(RiskDecision::NoRisk, evaluate_risk_with(&rules, &context));
+    }
+
+    #[test]
+    fn stored_risk_configuration_overrides_risk_rules() {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool.description = serde_json::json!({
+            "UserPoolAddOns": { "AdvancedSecurityMode": "AUDIT" }
+        });
+        user_pool
+            .set_risk_configuration(
+                None,
+                serde_json::json!({
+                    "CompromisedCredentialsRiskConfiguration": {
+                        "Actions": { "EventAction": "NO_ACTION" }
+                    },
+                    "RiskExceptionConfiguration": {
+                        "BlockedIPRangeList": ["192.0.2.0/24", "203.0.113.7"],
+                        "SkippedIPRangeList": []
+                    }
+                }),
+            )
+            .unwrap();
+        assert!(user_pool
+            .set_risk_configuration(Some("unknown"), serde_json::json!({}))
+            .is_err());
+
+        let rules = user_pool.risk_rules(Some("client"));
+        assert_eq!(
+            Some("AUDIT"),
+            rules.get(CONFIG_ADVANCED_SECURITY_MODE).map(String::as_str)
+        );
+        assert_eq!(
+            Some("192.0.2.0/24,203.0.113.7"),
+            rules.get(CONFIG_BLOCKED_IP_RANGES).map(String::as_str)
+        );
+        assert!(!rules.contains_key(CONFIG_COMPROMISED_PASSWORDS));
+
+        let configuration = risk_configuration(&rules);
+        assert_eq!(
+            serde_json::json!(["192.0.2.0/24", "203.0.113.7"]),
+            configuration["RiskExceptionConfiguration"]["BlockedIPRangeList"]
+        );
+        assert_eq!(
+            "NO_ACTION",
+            configuration["CompromisedCredentialsRiskConfiguration"]["Actions"]["EventAction"]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::common::{CLIENT_ID_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const SET_RISK_CONFIGURATION_NAME: &str = "SetRiskConfiguration";
+pub const SET_RISK_CONFIGURATION_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.SetRiskConfiguration";
+
+super::gen_response_err!(
+    SetRiskConfigurationError,
+    CodeDeliveryFailureException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserPoolAddOnNotEnabledException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetRiskConfigurationRequest {
+    #[validate(nested)]
+    pub account_takeover_risk_configuration:
+        Option<super::data_types::AccountTakeoverRiskConfigurationType>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    #[validate(nested)]
+    pub compromised_credentials_risk_configuration:
+        Option<super::data_types::CompromisedCredentialsRiskConfigurationType>,
+    #[validate(nested)]
+    pub risk_exception_configuration: Option<super::data_types::RiskExceptionConfigurationType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for SetRiskConfigurationRequest {
+    fn to_action_name() -> &'static str {
+        SET_RISK_CONFIGURATION_NAME
+    }
+}
+
+impl super::ToResponse for SetRiskConfigurationRequest {
+    type E = SetRiskConfigurationError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, SET_RISK_CONFIGURATION_NAME);
+        }
+        super::to_converted_json_response(self, SET_RISK_CONFIGURATION_NAME, |value| {
+            super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool.set_risk_configuration(
+                    self.client_id.as_deref(),
+                    value["RiskConfiguration"].clone(),
+                )
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = SetRiskConfigurationRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            risk_exception_configuration: Some(crate::user_pools::RiskExceptionConfigurationType {
+                blocked_ip_range_list: Some(vec!["192.0.2.0/24".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = SetRiskConfigurationRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            risk_exception_configuration: Some(crate::user_pools::RiskExceptionConfigurationType {
+                blocked_ip_range_list: Some(vec!["192.0.2.0/33".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = SetRiskConfigurationError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = SetRiskConfigurationError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::common::{CLIENT_ID_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const SET_UI_CUSTOMIZATION_NAME: &str = "SetUICustomization";
+pub const SET_UI_CUSTOMIZATION_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.SetUICustomization";
+
+super::gen_response_err!(
+    SetUICustomizationError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetUICustomizationRequest {
+    #[serde(rename = "CSS")]
+    pub css: Option<String>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    pub image_file: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for SetUICustomizationRequest {
+    fn to_action_name() -> &'static str {
+        SET_UI_CUSTOMIZATION_NAME
+    }
+}
+
+impl super::ToResponse for SetUICustomizationRequest {
+    type E = SetUICustomizationError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, SET_UI_CUSTOMIZATION_NAME);
+        }
+        super::to_converted_json_response(self, SET_UI_CUSTOMIZATION_NAME, |mut value| {
+            let client_id = self.client_id.as_deref();
+            value["UICustomization"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .set_ui_customization(client_id, self.css.clone(), self.image_file.clone())?
+                    .to_value(user_pool_id, client_id))
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        self.css
+            .as_deref()
+            .and_then(super::check_ui_css)
+            .or_else(|| {
+                self.image_file
+                    .as_deref()
+                    .and_then(super::check_ui_image_file)
+            })
+            .map(|message| (SetUICustomizationError::InvalidParameterException, message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = SetUICustomizationRequest {
+            css: Some(".logo-customizable { max-width: 60%; }".to_string()),
+            image_file: Some("iVBORw0KGgo=".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = SetUICustomizationRequest {
+            client_id: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = SetUICustomizationError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = SetUICustomizationError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::common::{CLIENT_ID_REGEX, HASH_REGEX, NAME_REGEX, PASSWORD_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const SIGN_UP_NAME: &str = "SignUp";
+pub const SIGN_UP_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.SignUp";
+
+super::gen_response_err!(
+    SignUpError,
+    CodeDeliveryFailureException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidPasswordException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | LimitExceededException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UsernameExistsException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignUpRequest {
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *CLIENT_ID_REGEX))]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 256))]
+    #[validate(regex(path = *PASSWORD_REGEX))]
+    pub password: Option<String>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *HASH_REGEX))]
+    pub secret_hash: Option<String>,
+    #[validate(nested)]
+    pub user_attributes: Option<Vec<super::data_types::AttributeType>>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex(path = *NAME_REGEX))]
+    pub username: Option<String>,
+    #[validate(nested)]
+    pub validation_data: Option<Vec<super::data_types::AttributeType>>,
+}
+
+impl super::ToActionName for SignUpRequest {
+    fn to_action_name() -> &'static str {
+        SIGN_UP_NAME
+    }
+}
+
+impl super::ToResponse for SignUpRequest {
+    type E = SignUpError;
+    fn to_response(&self) -> super::Response {
+        let client_id = self.client_id.as_deref().unwrap_or_default();
+        if super::client_user_pool_id(client_id).is_none() {
+            return super::to_json_response(self, SIGN_UP_NAME);
+        }
+        let mut code = None;
+        let response = super::to_converted_json_response(self, SIGN_UP_NAME, |mut value| {
+            let (sub, confirmation_code) = super::with_client_user_pool(client_id, |user_pool| {
+                let result = self.sign_up(client_id, user_pool);
+                if result.is_ok() {
+                    user_pool.record_completed_event(
+                        self.username.as_deref().unwrap_or_default(),
+                        super::AuthEventType::SignUp,
+                        &result,
+                        super::EventContext::from_user_context_data(
+                            self.user_context_data.as_ref(),
+                        ),
+                    );
+                }
+                result
+            })
+            .map_err(super::to_response_error)?;
+            value["UserSub"] = sub.into();
+            code = Some(confirmation_code);
+            Ok(value)
+        });
+        super::with_code_header(response, code)
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let attribute_error = self.user_attributes.as_ref().and_then(|attributes| {
+            super::check_writable_attributes(attributes.iter().filter_map(|a| a.name.as_deref()))
+        });
+        if let Some(message) = attribute_error {
+            return Some((SignUpError::InvalidParameterException, message));
+        }
+        super::check_password(self.password.as_deref()?)
+            .map(|message| (SignUpError::InvalidPasswordException, message))
+    }
+}
+
+impl SignUpRequest {
+    /// Adds an unconfirmed user and returns the sub and the confirmation code of the user.
+    fn sign_up(
+        &self,
+        client_id: &str,
+        user_pool: &mut super::UserPoolState,
+    ) -> super::StoreResult<(String, String)> {
+        let username = self.username.as_deref().unwrap_or_default();
+        user_pool
+            .client(client_id)?
+            .check_secret_hash(username, self.secret_hash.as_deref())?;
+        let attributes = self
+            .user_attributes
+            .iter()
+            .flatten()
+            .filter_map(|a| Some((a.name.clone()?, a.value.clone()?)))
+            .collect::<Vec<_>>();
+        user_pool
+            .check_user_attributes(&attributes, super::AttributeWrite::Create { admin: false })?;
+        user_pool.check_password(self.password.as_deref().unwrap_or_default())?;
+        let code = super::new_code();
+        let mut user = super::UserState::new(username, super::UserStatus::Unconfirmed);
+        for (name, value) in attributes.iter() {
+            user.set_attribute(name, value);
+        }
+        user.password = self.password.clone();
+        user.confirmation_code = Some(code.clone());
+        let user = user_pool.add_user(user)?;
+        Ok((user.sub.clone(), code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = SignUpRequest {
+            client_id: Some("client_id".to_string()),
+            password: Some("password".to_string()),
+            username: Some("username".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = SignUpRequest {
+            client_id: Some("client_id".to_string()),
+            password: Some("".to_string()),
+            username: Some("username".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn action_error_for_unknown_attribute() {
+        use crate::responses::ToResponse;
+
+        let request = SignUpRequest {
+            client_id: Some("client_id".to_string()),
+            password: Some("password".to_string()),
+            user_attributes: Some(vec![crate::user_pools::AttributeType {
+                name: Some("unknown".to_string()),
+                value: Some("value".to_string()),
+            }]),
+            username: Some("username".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            request.action_error(),
+            Some((SignUpError::InvalidParameterException, _))
+        ));
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = SignUpError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = SignUpError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,256 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use rsa::BigUint;
+use sha2::{Digest, Sha256};
+
+/// 3072-bit group of RFC 5054.
+const N_HEX: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74",
+    "020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437",
+    "4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05",
+    "98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB",
+    "9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718",
+    "3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33",
+    "A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864",
+    "D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2",
+    "08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A93AD2CAFFFFFFFFFFFFFFFF"
+);
+const DERIVED_KEY_INFO: &[u8] = b"Caldera Derived Key";
+const PRIVATE_VALUE_BYTES: usize = 128;
+
+static N: Lazy<BigUint> = Lazy::new(|| BigUint::parse_bytes(N_HEX.as_bytes(), 16).unwrap());
+static G: Lazy<BigUint> = Lazy::new(|| BigUint::from(2u8));
+static K: Lazy<BigUint> = Lazy::new(|| hex_hash(&format!("{}{}", pad_hex(&N), pad_hex(&G))));
+
+/// Returns the even length hex of a number, with a leading zero byte if the top bit is set.
+pub fn pad_hex(value: &BigUint) -> String {
+    let hex = value.to_str_radix(16);
+    if hex.len() % 2 == 1 {
+        format!("0{}", hex)
+    } else if hex.starts_with(|c: char| "89abcdef".contains(c)) {
+        format!("00{}", hex)
+    } else {
+        hex
+    }
+}
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len() / 2)
+        .filter_map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok())
+        .collect()
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hash_hex(bytes: &[u8]) -> String {
+    bytes_to_hex(&Sha256::digest(bytes))
+}
+
+fn hex_hash(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hash_hex(&hex_to_bytes(hex)).as_bytes(), 16).unwrap_or_default()
+}
+
+fn hmac(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn random_value() -> BigUint {
+    let mut bytes = [0u8; PRIVATE_VALUE_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    BigUint::from_bytes_be(&bytes) % &*N
+}
+
+fn from_hex(hex: &str) -> Option<BigUint> {
+    BigUint::parse_bytes(hex.as_bytes(), 16)
+}
+
+/// Returns a number in base64 (`PasswordVerifier` and `Salt` of `DeviceSecretVerifierConfig`) as hex.
+pub fn base64_to_hex(value: &str) -> Option<String> {
+    STANDARD
+        .decode(value)
+        .ok()
+        .map(|bytes| bytes_to_hex(&bytes))
+}
+
+/// Returns a new random secret block of the password verifier challenge.
+pub fn new_secret_block() -> String {
+    let mut bytes = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    STANDARD.encode(bytes)
+}
+
+/// Returns the private value `b` and the public value `B` (both hex) of the server for a verifier.
+pub fn server_values(verifier_hex: &str) -> Option<(String, String)> {
+    let verifier = from_hex(verifier_hex)?;
+    let b = random_value();
+    let public_b = (&*K * &verifier + G.modpow(&b, &N)) % &*N;
+    Some((b.to_str_radix(16), pad_hex(&public_b)))
+}
+
+fn authentication_key(session_key: &BigUint, u: &BigUint) -> Vec<u8> {
+    let prk = hmac(
+        &hex_to_bytes(&pad_hex(u)),
+        &hex_to_bytes(&pad_hex(session_key)),
+    );
+    let info = [DERIVED_KEY_INFO, &[1u8]].concat();
+    hmac(&prk, &info)[..16].to_vec()
+}
+
+/// Parameters of a password claim signed by a client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordClaim<'a> {
+    pub device_group_key: &'a str,
+    pub device_key: &'a str,
+    pub secret_block: &'a str,
+    pub timestamp: &'a str,
+}
+
+impl PasswordClaim<'_> {
+    fn signature(&self, key: &[u8]) -> Option<String> {
+        let message = [
+            self.device_group_key.as_bytes(),
+            self.device_key.as_bytes(),
+            &STANDARD.decode(self.secret_block).ok()?,
+            self.timestamp.as_bytes(),
+        ]
+        .concat();
+        Some(STANDARD.encode(hmac(key, &message)))
+    }
+}
+
+/// Returns the signature which a client who knows the device password computes for a claim.
+pub fn server_signature(
+    claim: &PasswordClaim,
+    verifier_hex: &str,
+    public_a_hex: &str,
+    b_hex: &str,
+) -> Option<String> {
+    let verifier = from_hex(verifier_hex)?;
+    let public_a = from_hex(public_a_hex)?;
+    let b = from_hex(b_hex)?;
+    if (&public_a % &*N) == BigUint::default() {
+        return None;
+    }
+    let public_b = (&*K * &verifier + G.modpow(&b, &N)) % &*N;
+    let u = hex_hash(&format!("{}{}", pad_hex(&public_a), pad_hex(&public_b)));
+    let session_key = (public_a * verifier.modpow(&u, &N)).modpow(&b, &N);
+    claim.signature(&authentication_key(&session_key, &u))
+}
+
+/// Returns whether `SRP_A` of a client is valid.
+pub fn is_valid_public_value(public_a_hex: &str) -> bool {
+    from_hex(public_a_hex).is_some_and(|a| (a % &*N) != BigUint::default())
+}
+
+/// Client side of the protocol, which generates device verifiers and signs password claims.
+pub mod client {
+    use super::*;
+
+    fn hashed_password(device_group_key: &str, device_key: &str, password: &str) -> String {
+        hash_hex(format!("{}{}:{}", device_group_key, device_key, password).as_bytes())
+    }
+
+    fn x(salt_hex: &str, hashed_password: &str) -> BigUint {
+        hex_hash(&format!(
+            "{}{}",
+            pad_hex(&from_hex(salt_hex).unwrap_or_default()),
+            hashed_password
+        ))
+    }
+
+    /// Returns `PasswordVerifier` and `Salt` (base64) of `DeviceSecretVerifierConfig`.
+    pub fn device_verifier(
+        device_group_key: &str,
+        device_key: &str,
+        password: &str,
+    ) -> (String, String) {
+        let mut salt = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let salt_hex = pad_hex(&BigUint::from_bytes_be(&salt));
+        let x = x(
+            &salt_hex,
+            &hashed_password(device_group_key, device_key, password),
+        );
+        let verifier = G.modpow(&x, &N);
+        (
+            STANDARD.encode(hex_to_bytes(&pad_hex(&verifier))),
+            STANDARD.encode(hex_to_bytes(&salt_hex)),
+        )
+    }
+
+    /// Returns the private value `a` and the public value `SRP_A` (both hex).
+    pub fn client_values() -> (String, String) {
+        let a = random_value();
+        (a.to_str_radix(16), pad_hex(&G.modpow(&a, &N)))
+    }
+
+    /// Returns `PASSWORD_CLAIM_SIGNATURE` for a claim with `SRP_B` and `SALT` of the challenge.
+    pub fn password_claim_signature(
+        claim: &PasswordClaim,
+        password: &str,
+        a_hex: &str,
+        public_b_hex: &str,
+        salt_hex: &str,
+    ) -> Option<String> {
+        let a = from_hex(a_hex)?;
+        let public_a = G.modpow(&a, &N);
+        let public_b = from_hex(public_b_hex)?;
+        let u = hex_hash(&format!("{}{}", pad_hex(&public_a), pad_hex(&public_b)));
+        let x = x(
+            salt_hex,
+            &hashed_password(claim.device_group_key, claim.device_key, password),
+        );
+        let base = (&public_b + &*N * &*K - (&*K * G.modpow(&x, &N)) % &*N) % &*N;
+        let session_key = base.modpow(&(a + u.clone() * x), &N);
+        claim.signature(&authentication_key(&session_key, &u))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn pad_hex_makes_positive_even_length_hex() {
+        assert_eq!("0f", pad_hex(&BigUint::from(15u8)));
+        assert_eq!("0080", pad_hex(&BigUint::from(128u8)));
+        assert_eq!("7f", pad_hex(&BigUint::from(127u8)));
+    }
+
+    #[test]
+    fn client_and_server_compute_same_signature() {
+        let (verifier, salt) = client::device_verifier("-group", "us-east-1_device", "secret");
+        let verifier_hex = base64_to_hex(&verifier).unwrap();
+        let salt_hex = base64_to_hex(&salt).unwrap();
+        let (a, public_a) = client::client_values();
+        assert!(is_valid_public_value(&public_a));
+        let (b, public_b) = server_values(&verifier_hex).unwrap();
+        let secret_block = new_secret_block();
+        let claim = PasswordClaim {
+            device_group_key: "-group",
+            device_key: "us-east-1_device",
+            secret_block: &secret_block,
+            timestamp: "Mon Jan 1 00:00:00 UTC 2024",
+        };
+
+        let expected = server_signature(&claim, &verifier_hex, &public_a, &b).unwrap();
+        assert_eq!(
+            Some(expected.clone()),
+            client::password_claim_signature(&claim, "secret", &a, &public_b, &salt_hex)
+        );
+        assert_ne!(
+            Some(expected),
+            client::password_claim_signature(&claim, "wrong", &a, &public_b, &salt_hex)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::common::{IMPORT_JOB_ID_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const STOP_USER_IMPORT_JOB_NAME: &str = "StopUserImportJob";
+pub const STOP_USER_IMPORT_JOB_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.StopUserImportJob";
+
+super::gen_response_err!(
+    StopUserImportJobError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | PreconditionNotMetException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct StopUserImportJobRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *IMPORT_JOB_ID_REGEX))]
+    pub job_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for StopUserImportJobRequest {
+    fn to_action_name() -> &'static str {
+        STOP_USER_IMPORT_JOB_NAME
+    }
+}
+
+impl super::ToResponse for StopUserImportJobRequest {
+    type E = StopUserImportJobError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, STOP_USER_IMPORT_JOB_NAME);
+        }
+        super::to_converted_json_response(self, STOP_USER_IMPORT_JOB_NAME, |mut value| {
+            value["UserImportJob"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .stop_import_job(self.job_id.as_deref().unwrap_or_default())?
+                    .to_value(user_pool_id))
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = StopUserImportJobRequest {
+            job_id: Some("import-0123456789".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = StopUserImportJobRequest {
+            job_id: Some("0123456789".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = StopUserImportJobError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = StopUserImportJobError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
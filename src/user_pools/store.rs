@@ -0,0 +1,594 @@
+use crate::common::{self, ALPHANUMERIC, LOWERCASE_ALPHANUMERIC};
+use crate::errors::{CommonError, ResponseError};
+use crate::responses::ToStatusCode;
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::RwLock;
+use strum_macros::Display;
+
+/// Region of the user pools created by CreateUserPool.
+pub const REGION: &str = "us-east-1";
+
+/// Account id in the ARNs of the created user pools.
+pub const ACCOUNT_ID: &str = "123456789012";
+
+/// Response header which has a code (or a temporary password) sent to a user.
+pub const CODE_DEBUG_HEADER: &str = "x-fakey-cognito-code";
+
+const CLIENT_ID_LENGTH: usize = 26;
+const CLIENT_SECRET_LENGTH: usize = 51;
+const USER_POOL_ID_SUFFIX_LENGTH: usize = 9;
+const CODE_DIGITS: &str = "0123456789";
+const CODE_LENGTH: usize = 6;
+const TEMPORARY_PASSWORD_LENGTH: usize = 8;
+
+static USER_POOLS: Lazy<RwLock<HashMap<String, UserPoolState>>> = Lazy::new(Default::default);
+
+/// Errors of the actions on the stored user pools.
+///
+/// Every action converts them to its own error of the same name.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Display, PartialEq, Eq)]
+pub enum StoreError {
+    AliasExistsException,
+    CodeMismatchException,
+    DuplicateProviderException,
+    ExpiredCodeException,
+    GroupExistsException,
+    InternalErrorException,
+    InvalidParameterException,
+    InvalidPasswordException,
+    LimitExceededException,
+    NotAuthorizedException,
+    PasswordResetRequiredException,
+    PreconditionNotMetException,
+    ResourceNotFoundException,
+    ScopeDoesNotExistException,
+    UnauthorizedException,
+    UnsupportedOperationException,
+    UnsupportedTokenTypeException,
+    UnsupportedUserStateException,
+    UserNotConfirmedException,
+    UserNotFoundException,
+    UsernameExistsException,
+}
+
+pub type StoreResult<T> = Result<T, (StoreError, String)>;
+
+/// Converts a store error to the error of an action, or `InternalFailure` if the action has not it.
+pub fn to_response_error<E>((error, message): (StoreError, String)) -> (ResponseError<E>, String)
+where
+    E: std::fmt::Display + ToStatusCode + std::str::FromStr,
+{
+    let error = error
+        .to_string()
+        .parse()
+        .unwrap_or(ResponseError::CommonError(CommonError::InternalFailure));
+    (error, message)
+}
+
+/// Status of a user.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum UserStatus {
+    Unconfirmed,
+    Confirmed,
+    ForceChangePassword,
+    ResetRequired,
+    /// Federated user created by a sign-in through an identity provider.
+    ExternalProvider,
+}
+
+/// User pool created by CreateUserPool.
+#[derive(Debug, Clone)]
+pub struct UserPoolState {
+    pub id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_modified_at: DateTime<Utc>,
+    /// `UserPool` of the CreateUserPool response.
+    pub description: serde_json::Value,
+    pub clients: HashMap<String, ClientState>,
+    pub users: BTreeMap<String, UserState>,
+    /// Sessions started by authentication, by the id of their first token.
+    pub sessions: HashMap<String, SessionState>,
+    /// Pending challenges by the session of the challenge.
+    pub challenges: HashMap<String, ChallengeState>,
+    /// `RiskConfiguration` of SetRiskConfiguration by the app client id, or `None` for the user pool.
+    pub risk_configurations: HashMap<Option<String>, serde_json::Value>,
+    /// `IdentityProvider` of CreateIdentityProvider by the provider names.
+    pub identity_providers: BTreeMap<String, serde_json::Value>,
+    /// `ResourceServer` of CreateResourceServer by the identifiers.
+    pub resource_servers: BTreeMap<String, serde_json::Value>,
+    /// `Group` of CreateGroup by the group names.
+    pub groups: BTreeMap<String, serde_json::Value>,
+    /// User import jobs created by CreateUserImportJob by the job ids.
+    pub import_jobs: BTreeMap<String, super::UserImportJobState>,
+    /// Domains of CreateUserPoolDomain by the domain names.
+    pub domains: BTreeMap<String, super::DomainState>,
+    /// UI customizations of SetUICustomization by the app client id, or `None` for the user pool.
+    pub ui_customizations: HashMap<Option<String>, super::UiCustomizationState>,
+    /// Usernames signed in to the login page by the values of their session cookies.
+    pub hosted_ui_sessions: HashMap<String, String>,
+    /// Sign-ins through identity providers by the state sent to the providers.
+    pub federations: HashMap<String, super::FederationState>,
+}
+
+/// App client created by CreateUserPoolClient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientState {
+    pub client_id: String,
+    pub client_name: String,
+    pub client_secret: Option<String>,
+    pub explicit_auth_flows: Vec<String>,
+    pub access_token_validity: Duration,
+    pub id_token_validity: Duration,
+    pub refresh_token_validity: Duration,
+    pub enable_token_revocation: bool,
+    /// `AllowedOAuthFlows`, empty unless `AllowedOAuthFlowsUserPoolClient` is true.
+    pub allowed_oauth_flows: Vec<String>,
+    pub callback_urls: Vec<String>,
+    pub logout_urls: Vec<String>,
+}
+
+/// User of a user pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserState {
+    pub username: String,
+    pub sub: String,
+    /// Attributes except `sub` in the order of their creation.
+    pub attributes: Vec<(String, String)>,
+    pub password: Option<String>,
+    /// Expiry of the password set by an administrator until the user changes it.
+    pub temporary_password_expires_at: Option<DateTime<Utc>>,
+    pub status: UserStatus,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_modified_at: DateTime<Utc>,
+    pub confirmation_code: Option<String>,
+    /// Codes sent to verify attributes by the attribute names.
+    pub verification_codes: HashMap<String, super::PendingVerification>,
+    /// Devices tracked by authentications of the user by the device keys.
+    pub devices: BTreeMap<String, super::DeviceState>,
+    /// Auth events of the user, the most recent first.
+    pub auth_events: Vec<super::AuthEventState>,
+    /// Federated identities linked to the user.
+    pub provider_links: Vec<super::ProviderLink>,
+    /// Names of the groups which the user belongs to.
+    pub groups: BTreeSet<String>,
+}
+
+/// Tokens issued by an authentication and refreshed by its refresh token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionState {
+    pub origin_jti: String,
+    pub username: String,
+    pub client_id: String,
+    pub refresh_token: String,
+    pub auth_time: DateTime<Utc>,
+    pub refresh_token_expires_at: DateTime<Utc>,
+    pub revoked: bool,
+    /// Device which the session is authenticated on.
+    pub device_key: Option<String>,
+}
+
+/// Challenge which a user has to respond to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeState {
+    pub username: String,
+    pub client_id: String,
+    pub challenge_name: String,
+    pub expires_at: DateTime<Utc>,
+    /// Values which the server keeps until the response to the challenge.
+    pub values: HashMap<String, String>,
+}
+
+/// Returns a new user pool id (e.g. `us-east-1_AbCdEf123`).
+pub fn new_user_pool_id() -> String {
+    format!(
+        "{}_{}",
+        REGION,
+        common::random_string(ALPHANUMERIC, USER_POOL_ID_SUFFIX_LENGTH)
+    )
+}
+
+/// Returns a new code sent to a user.
+pub fn new_code() -> String {
+    common::random_string(CODE_DIGITS, CODE_LENGTH)
+}
+
+/// Returns a new temporary password which has every kind of characters and the minimum length.
+pub fn new_temporary_password(minimum_length: usize) -> String {
+    let length = TEMPORARY_PASSWORD_LENGTH.max(minimum_length.saturating_sub(4));
+    format!("{}Aa1!", common::random_string(ALPHANUMERIC, length))
+}
+
+/// Stores a created user pool.
+pub fn insert_user_pool(user_pool: UserPoolState) {
+    USER_POOLS
+        .write()
+        .unwrap()
+        .insert(user_pool.id.clone(), user_pool);
+}
+
+/// Returns true if the user pool is created by CreateUserPool.
+///
+/// Actions on the other user pools return responses rendered from the templates.
+pub fn user_pool_exists(user_pool_id: &str) -> bool {
+    USER_POOLS.read().unwrap().contains_key(user_pool_id)
+}
+
+/// Returns the id of the user pool which has the app client.
+pub fn client_user_pool_id(client_id: &str) -> Option<String> {
+    find_user_pool_id(|p| p.clients.contains_key(client_id))
+}
+
+/// Returns the id of the first stored user pool which satisfies a predicate.
+pub fn find_user_pool_id<P>(predicate: P) -> Option<String>
+where
+    P: Fn(&UserPoolState) -> bool,
+{
+    USER_POOLS
+        .read()
+        .unwrap()
+        .values()
+        .find(|p| predicate(p))
+        .map(|p| p.id.clone())
+}
+
+/// Applies a function to a stored user pool.
+pub fn with_user_pool<T, F>(user_pool_id: &str, f: F) -> StoreResult<T>
+where
+    F: FnOnce(&mut UserPoolState) -> StoreResult<T>,
+{
+    let mut user_pools = USER_POOLS.write().unwrap();
+    match user_pools.get_mut(user_pool_id) {
+        Some(user_pool) => f(user_pool),
+        None => Err((
+            StoreError::ResourceNotFoundException,
+            format!("User pool {} does not exist.", user_pool_id),
+        )),
+    }
+}
+
+/// Applies a function to the stored user pool which has the app client.
+pub fn with_client_user_pool<T, F>(client_id: &str, f: F) -> StoreResult<T>
+where
+    F: FnOnce(&mut UserPoolState) -> StoreResult<T>,
+{
+    match client_user_pool_id(client_id) {
+        Some(user_pool_id) => with_user_pool(&user_pool_id, f),
+        None => Err(client_not_found(client_id)),
+    }
+}
+
+fn client_not_found(client_id: &str) -> (StoreError, String) {
+    (
+        StoreError::ResourceNotFoundException,
+        format!("User pool client {} does not exist.", client_id),
+    )
+}
+
+fn user_not_found() -> (StoreError, String) {
+    (
+        StoreError::UserNotFoundException,
+        "User does not exist.".to_string(),
+    )
+}
+
+impl UserPoolState {
+    pub fn new(name: &str) -> Self {
+        let now = Utc::now();
+        UserPoolState {
+            id: new_user_pool_id(),
+            name: name.to_string(),
+            created_at: now,
+            last_modified_at: now,
+            description: serde_json::Value::Null,
+            clients: HashMap::new(),
+            users: BTreeMap::new(),
+            sessions: HashMap::new(),
+            challenges: HashMap::new(),
+            risk_configurations: HashMap::new(),
+            identity_providers: BTreeMap::new(),
+            resource_servers: BTreeMap::new(),
+            groups: BTreeMap::new(),
+            import_jobs: BTreeMap::new(),
+            domains: BTreeMap::new(),
+            ui_customizations: HashMap::new(),
+            hosted_ui_sessions: HashMap::new(),
+            federations: HashMap::new(),
+        }
+    }
+
+    pub fn arn(&self) -> String {
+        format!(
+            "arn:aws:cognito-idp:{}:{}:userpool/{}",
+            REGION, ACCOUNT_ID, self.id
+        )
+    }
+
+    pub fn client(&self, client_id: &str) -> StoreResult<&ClientState> {
+        self.clients
+            .get(client_id)
+            .ok_or_else(|| client_not_found(client_id))
+    }
+
+    /// Returns a user by the username or an alias.
+    pub fn user(&self, username: &str) -> StoreResult<&UserState> {
+        let username = self.resolve_username(username).ok_or_else(user_not_found)?;
+        self.users.get(&username).ok_or_else(user_not_found)
+    }
+
+    /// Returns a user by the username or an alias.
+    pub fn user_mut(&mut self, username: &str) -> StoreResult<&mut UserState> {
+        let username = self.resolve_username(username).ok_or_else(user_not_found)?;
+        self.users.get_mut(&username).ok_or_else(user_not_found)
+    }
+
+    /// Adds a new user, whose username is generated if the user pool signs in by username attributes.
+    pub fn add_user(&mut self, user: UserState) -> StoreResult<&mut UserState> {
+        self.add_user_with_aliases(user, false)
+    }
+
+    /// Adds a new user, and takes its aliases from other users if `force_alias_creation` is given.
+    pub fn add_user_with_aliases(
+        &mut self,
+        mut user: UserState,
+        force_alias_creation: bool,
+    ) -> StoreResult<&mut UserState> {
+        self.assign_username(&mut user)?;
+        self.claim_aliases(&user.username, &user.attributes, force_alias_creation)?;
+        Ok(self.users.entry(user.username.clone()).or_insert(user))
+    }
+
+    /// Removes a user and the sessions of the user.
+    pub fn remove_user(&mut self, username: &str) -> StoreResult<UserState> {
+        let username = self.resolve_username(username).ok_or_else(user_not_found)?;
+        let user = self.users.remove(&username).ok_or_else(user_not_found)?;
+        self.sessions.retain(|_, s| s.username != username);
+        self.challenges.retain(|_, c| c.username != username);
+        Ok(user)
+    }
+
+    /// Revokes every refresh token and access token issued for a user.
+    pub fn revoke_user_sessions(&mut self, username: &str) {
+        let username = self
+            .resolve_username(username)
+            .unwrap_or_else(|| username.to_string());
+        for session in self.sessions.values_mut() {
+            if session.username == username {
+                session.revoked = true;
+            }
+        }
+    }
+}
+
+impl ClientState {
+    pub fn new(client_name: &str, generate_secret: bool) -> Self {
+        ClientState {
+            client_id: common::random_string(LOWERCASE_ALPHANUMERIC, CLIENT_ID_LENGTH),
+            client_name: client_name.to_string(),
+            client_secret: generate_secret
+                .then(|| common::random_string(LOWERCASE_ALPHANUMERIC, CLIENT_SECRET_LENGTH)),
+            explicit_auth_flows: vec![],
+            access_token_validity: Duration::hours(1),
+            id_token_validity: Duration::hours(1),
+            refresh_token_validity: Duration::days(30),
+            enable_token_revocation: true,
+            allowed_oauth_flows: vec![],
+            callback_urls: vec![],
+            logout_urls: vec![],
+        }
+    }
+}
+
+impl UserState {
+    pub fn new(username: &str, status: UserStatus) -> Self {
+        let now = Utc::now();
+        UserState {
+            username: username.to_string(),
+            sub: common::new_uuid(),
+            attributes: vec![],
+            password: None,
+            temporary_password_expires_at: None,
+            status,
+            enabled: true,
+            created_at: now,
+            last_modified_at: now,
+            confirmation_code: None,
+            verification_codes: HashMap::new(),
+            devices: BTreeMap::new(),
+            auth_events: Vec::new(),
+            provider_links: Vec::new(),
+            groups: BTreeSet::new(),
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        if name == "sub" {
+            return Some(&self.sub);
+        }
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        if name == "sub" {
+            return;
+        }
+        match self.attributes.iter_mut().find(|(n, _)| n == name) {
+            Some(attribute) => attribute.1 = value.to_string(),
+            None => self.attributes.push((name.to_string(), value.to_string())),
+        }
+        self.last_modified_at = Utc::now();
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) {
+        self.attributes.retain(|(n, _)| n != name);
+        self.last_modified_at = Utc::now();
+    }
+
+    /// Returns the attributes as a list of `{"Name": ..., "Value": ...}`.
+    pub fn attributes_value(&self) -> serde_json::Value {
+        let identities = (!self.provider_links.is_empty())
+            .then(|| super::ProviderLink::identities_value(&self.provider_links));
+        std::iter::once(("sub", self.sub.as_str()))
+            .chain(
+                self.attributes
+                    .iter()
+                    .map(|(n, v)| (n.as_str(), v.as_str())),
+            )
+            .chain(identities.as_deref().map(|v| ("identities", v)))
+            .map(|(name, value)| serde_json::json!({ "Name": name, "Value": value }))
+            .collect()
+    }
+
+    /// Returns the user as a `UserType` of the responses.
+    pub fn to_value(&self, attributes_key: &str) -> serde_json::Value {
+        serde_json::json!({
+            attributes_key: self.attributes_value(),
+            "Enabled": self.enabled,
+            "UserCreateDate": self.created_at.timestamp(),
+            "UserLastModifiedDate": self.last_modified_at.timestamp(),
+            "UserStatus": self.status.to_string(),
+            "Username": self.username,
+        })
+    }
+
+    /// Returns an error if the user cannot sign in.
+    pub fn check_enabled(&self) -> StoreResult<()> {
+        if !self.enabled {
+            return Err((
+                StoreError::NotAuthorizedException,
+                "User is disabled.".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the fields given in a request.
+pub fn request_value<T: serde::Serialize>(request: &T) -> serde_json::Value {
+    match serde_json::to_value(request) {
+        Ok(serde_json::Value::Object(mut fields)) => {
+            fields.retain(|_, v| !v.is_null());
+            serde_json::Value::Object(fields)
+        }
+        _ => serde_json::json!({}),
+    }
+}
+
+/// Adds a code sent to a user to the debug header of a response.
+pub fn with_code_header(
+    mut response: crate::responses::Response,
+    code: Option<String>,
+) -> crate::responses::Response {
+    if let Some(code) = code.and_then(|c| warp::http::HeaderValue::from_str(&c).ok()) {
+        response.headers_mut().insert(CODE_DEBUG_HEADER, code);
+    }
+    response
+}
+
+/// Merges stored values to a response rendered from the template.
+pub fn merge_value(value: &mut serde_json::Value, stored: serde_json::Value) {
+    match (value, stored) {
+        (serde_json::Value::Object(object), serde_json::Value::Object(stored)) => {
+            for (key, stored) in stored {
+                merge_value(object.entry(key).or_insert(serde_json::Value::Null), stored);
+            }
+        }
+        (value, stored) => *value = stored,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::AdminGetUserError;
+    use pretty_assertions::assert_eq;
+
+    fn user_pool() -> UserPoolState {
+        let mut user_pool = UserPoolState::new("pool");
+        let mut user = UserState::new("user", UserStatus::Confirmed);
+        user.set_attribute("email", "user@example.com");
+        user_pool.add_user(user).unwrap();
+        user_pool
+    }
+
+    #[test]
+    fn user_pool_id_has_region_prefix() {
+        let id = new_user_pool_id();
+        assert!(id.starts_with("us-east-1_"));
+        assert!(crate::common::USER_POOL_ID_REGEX.is_match(&id));
+        assert_eq!(
+            "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example",
+            UserPoolState {
+                id: "us-east-1_example".to_string(),
+                ..UserPoolState::new("pool")
+            }
+            .arn()
+        );
+    }
+
+    #[test]
+    fn users_are_unique() {
+        let mut user_pool = user_pool();
+        assert_eq!(
+            Err(StoreError::UsernameExistsException),
+            user_pool
+                .add_user(UserState::new("user", UserStatus::Unconfirmed))
+                .map(|_| ())
+                .map_err(|(e, _)| e)
+        );
+        assert!(user_pool.remove_user("user").is_ok());
+        assert_eq!(
+            Err(StoreError::UserNotFoundException),
+            user_pool.user("user").map(|_| ()).map_err(|(e, _)| e)
+        );
+    }
+
+    #[test]
+    fn user_has_sub_and_attributes() {
+        let user_pool = user_pool();
+        let user = user_pool.user("user").unwrap();
+        let attributes = user.attributes_value();
+        assert_eq!("sub", attributes[0]["Name"]);
+        assert_eq!(user.sub, attributes[0]["Value"]);
+        assert_eq!("user@example.com", attributes[1]["Value"]);
+        assert_eq!("CONFIRMED", user.to_value("Attributes")["UserStatus"]);
+        assert_eq!(Some("user@example.com"), user.attribute("email"));
+    }
+
+    #[test]
+    fn store_error_converts_to_action_error() {
+        let (error, message) = to_response_error::<AdminGetUserError>((
+            StoreError::UserNotFoundException,
+            "User does not exist.".to_string(),
+        ));
+        assert_eq!("UserNotFoundException", error.to_string());
+        assert_eq!("User does not exist.", message);
+
+        let (error, _) = to_response_error::<AdminGetUserError>((
+            StoreError::CodeMismatchException,
+            "".to_string(),
+        ));
+        assert_eq!("InternalFailure", error.to_string());
+    }
+
+    #[test]
+    fn stored_values_are_merged() {
+        let mut value = serde_json::json!({ "User": { "Username": "string", "Enabled": true } });
+        merge_value(
+            &mut value,
+            serde_json::json!({ "User": { "Username": "user" } }),
+        );
+        assert_eq!(
+            serde_json::json!({ "User": { "Username": "user", "Enabled": true } }),
+            value
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::common::ARN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const TAG_RESOURCE_NAME: &str = "TagResource";
+pub const TAG_RESOURCE_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.TagResource";
+
+super::gen_response_err!(
+    TagResourceError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct TagResourceRequest {
+    #[validate(required)]
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex(path = *ARN_REGEX))]
+    pub resource_arn: Option<String>,
+    #[validate(required)]
+    pub tags: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for TagResourceRequest {
+    fn to_action_name() -> &'static str {
+        TAG_RESOURCE_NAME
+    }
+}
+
+impl super::ToResponse for TagResourceRequest {
+    type E = TagResourceError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::tag_resource(
+                self.resource_arn.as_deref().unwrap_or_default(),
+                self.tags.as_ref().unwrap_or(&Default::default()),
+            )
+            .map_err(super::to_response_error)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_tags(self.tags.as_ref()?)
+            .map(|message| (TagResourceError::InvalidParameterException, message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = TagResourceRequest {
+            resource_arn: Some(
+                "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example".to_string(),
+            ),
+            tags: Some(std::collections::HashMap::from([(
+                "env".to_string(),
+                "dev".to_string(),
+            )])),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = TagResourceRequest {
+            resource_arn: Some(
+                "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example".to_string(),
+            ),
+            tags: None,
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = TagResourceError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = TagResourceError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
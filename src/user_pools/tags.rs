@@ -0,0 +1,177 @@
+use super::{StoreError, StoreResult};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+
+/// Maximum number of tags of a resource.
+pub const MAX_TAGS: usize = 50;
+
+/// Maximum length of a tag key.
+pub const MAX_TAG_KEY_LENGTH: usize = 128;
+
+/// Maximum length of a tag value.
+pub const MAX_TAG_VALUE_LENGTH: usize = 256;
+
+const RESERVED_TAG_KEY_PREFIX: &str = "aws:";
+
+/// Tags of TagResource and CreateUserPool by the resource ARNs.
+static RESOURCE_TAGS: Lazy<RwLock<HashMap<String, BTreeMap<String, String>>>> =
+    Lazy::new(Default::default);
+
+static TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[\p{L}\p{Z}\p{N}_.:/=+\-@]*$").unwrap());
+
+/// Returns a message for the first tag which breaks the tag limits.
+pub fn check_tags(tags: &HashMap<String, String>) -> Option<String> {
+    if tags.len() > MAX_TAGS {
+        return Some(format!(
+            "Number of tags {} exceeds the maximum of {}.",
+            tags.len(),
+            MAX_TAGS
+        ));
+    }
+    let mut keys = tags.keys().map(String::as_str).collect::<Vec<_>>();
+    keys.sort_unstable();
+    keys.into_iter().find_map(|key| {
+        check_tag_key(key).or_else(|| {
+            let value = &tags[key];
+            (value.chars().count() > MAX_TAG_VALUE_LENGTH || !TAG_REGEX.is_match(value))
+                .then(|| format!("Invalid tag value for key: {}", key))
+        })
+    })
+}
+
+/// Returns a message for the first invalid tag key.
+pub fn check_tag_keys(keys: &[String]) -> Option<String> {
+    keys.iter().find_map(|key| check_tag_key(key))
+}
+
+/// Adds or replaces tags of a resource unless the resource would have more than `MAX_TAGS` tags.
+pub fn tag_resource(resource_arn: &str, tags: &HashMap<String, String>) -> StoreResult<()> {
+    let mut resource_tags = RESOURCE_TAGS.write().unwrap();
+    let stored = resource_tags.entry(resource_arn.to_string()).or_default();
+    let count = stored.len() + tags.keys().filter(|k| !stored.contains_key(*k)).count();
+    if count > MAX_TAGS {
+        return Err((
+            StoreError::InvalidParameterException,
+            format!(
+                "Number of tags {} exceeds the maximum of {}.",
+                count, MAX_TAGS
+            ),
+        ));
+    }
+    stored.extend(tags.iter().map(|(k, v)| (k.clone(), v.clone())));
+    Ok(())
+}
+
+/// Removes tags of a resource by the keys.
+pub fn untag_resource(resource_arn: &str, tag_keys: &[String]) {
+    let mut resource_tags = RESOURCE_TAGS.write().unwrap();
+    let stored = resource_tags.entry(resource_arn.to_string()).or_default();
+    for key in tag_keys {
+        stored.remove(key);
+    }
+}
+
+/// Returns the tags of a resource, or `None` if the resource has never been tagged nor created.
+pub fn resource_tags(resource_arn: &str) -> Option<BTreeMap<String, String>> {
+    RESOURCE_TAGS.read().unwrap().get(resource_arn).cloned()
+}
+
+fn check_tag_key(key: &str) -> Option<String> {
+    let length = key.chars().count();
+    if length == 0 || length > MAX_TAG_KEY_LENGTH || !TAG_REGEX.is_match(key) {
+        Some(format!("Invalid tag key: {}", key))
+    } else if key.starts_with(RESERVED_TAG_KEY_PREFIX) {
+        Some(format!("Tag keys must not start with aws: {}", key))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn tags(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn success_to_check_tags() {
+        assert_eq!(None, check_tags(&tags(&[])));
+        assert_eq!(
+            None,
+            check_tags(&tags(&[("env", "dev"), ("team", ""), ("owner", "a@b.c")]))
+        );
+        let max = (0..MAX_TAGS)
+            .map(|i| (format!("key{}", i), "value".to_string()))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(None, check_tags(&max));
+    }
+
+    #[test]
+    fn failure_to_check_tags() {
+        let too_many = (0..=MAX_TAGS)
+            .map(|i| (format!("key{}", i), "value".to_string()))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(
+            Some("Number of tags 51 exceeds the maximum of 50.".to_string()),
+            check_tags(&too_many)
+        );
+        assert_eq!(
+            Some("Invalid tag key: ".to_string()),
+            check_tags(&tags(&[("", "value")]))
+        );
+        let long_key = "k".repeat(MAX_TAG_KEY_LENGTH + 1);
+        assert_eq!(
+            Some(format!("Invalid tag key: {}", long_key)),
+            check_tags(&tags(&[(&long_key, "value")]))
+        );
+        assert_eq!(
+            Some("Invalid tag value for key: env".to_string()),
+            check_tags(&tags(&[("env", &"v".repeat(MAX_TAG_VALUE_LENGTH + 1))]))
+        );
+        assert_eq!(
+            Some("Tag keys must not start with aws: aws:env".to_string()),
+            check_tags(&tags(&[("aws:env", "dev")]))
+        );
+    }
+
+    #[test]
+    fn tags_are_kept_by_resource() {
+        let arn = "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_tags";
+        let other = "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_other";
+        assert_eq!(None, resource_tags(arn));
+        tag_resource(arn, &tags(&[("env", "dev"), ("team", "a")])).unwrap();
+        tag_resource(arn, &tags(&[("env", "prod")])).unwrap();
+        untag_resource(arn, &["team".to_string(), "owner".to_string()]);
+        assert_eq!(
+            Some(BTreeMap::from([("env".to_string(), "prod".to_string())])),
+            resource_tags(arn)
+        );
+        assert_eq!(None, resource_tags(other));
+
+        let too_many = (1..MAX_TAGS)
+            .map(|i| (format!("key{}", i), "value".to_string()))
+            .collect::<HashMap<_, _>>();
+        tag_resource(arn, &too_many).unwrap();
+        assert_eq!(
+            Err(StoreError::InvalidParameterException),
+            tag_resource(arn, &tags(&[("one", "more")])).map_err(|(e, _)| e)
+        );
+    }
+
+    #[test]
+    fn failure_to_check_tag_keys() {
+        assert_eq!(None, check_tag_keys(&["env".to_string()]));
+        assert_eq!(
+            Some("Invalid tag key: env!".to_string()),
+            check_tag_keys(&["env".to_string(), "env!".to_string()])
+        );
+    }
+}
@@ -0,0 +1,390 @@
+use super::{StoreError, StoreResult, UserPoolState};
+use crate::common::{self, ALPHANUMERIC};
+use crate::jwt;
+use chrono::{DateTime, Utc};
+
+/// Scope of the access tokens issued by the authentication API.
+pub const ACCESS_TOKEN_SCOPE: &str = "aws.cognito.signin.user.admin";
+
+const REFRESH_TOKEN_LENGTH: usize = 128;
+
+/// Tokens of a successful authentication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticationResult {
+    pub access_token: String,
+    pub id_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_in: i64,
+    pub new_device_metadata: Option<super::NewDeviceMetadata>,
+}
+
+/// User of a valid access token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessTokenUser {
+    pub user_pool_id: String,
+    pub username: String,
+    pub client_id: String,
+}
+
+impl AuthenticationResult {
+    /// Returns the `AuthenticationResult` of the responses.
+    pub fn to_value(&self) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "AccessToken": self.access_token,
+            "ExpiresIn": self.expires_in,
+            "IdToken": self.id_token,
+            "TokenType": "Bearer",
+        });
+        if let Some(refresh_token) = self.refresh_token.as_ref() {
+            value["RefreshToken"] = refresh_token.as_str().into();
+        }
+        if let Some(metadata) = self.new_device_metadata.as_ref() {
+            value["NewDeviceMetadata"] = metadata.to_value();
+        }
+        value
+    }
+}
+
+/// Returns the issuer of the tokens of a user pool.
+pub fn user_pool_issuer(user_pool_id: &str) -> String {
+    format!("{}/{}", jwt::token_signer().issuer, user_pool_id)
+}
+
+/// Returns the user of an access token if the token is valid, not expired and not revoked.
+pub fn access_token_user(access_token: &str) -> StoreResult<AccessTokenUser> {
+    let invalid = |message: &str| (StoreError::NotAuthorizedException, message.to_string());
+    let claims = jwt::token_signer()
+        .verify(access_token)
+        .filter(|c| c.get("token_use").and_then(|t| t.as_str()) == Some("access"))
+        .ok_or_else(|| invalid("Invalid Access Token"))?;
+    let claim = |name: &str| {
+        claims
+            .get(name)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+    };
+    let user_pool_id = claim("iss").rsplit('/').next().unwrap_or_default();
+    if claims
+        .get("exp")
+        .and_then(|e| e.as_i64())
+        .unwrap_or_default()
+        <= Utc::now().timestamp()
+    {
+        return Err(invalid("Access Token has expired"));
+    }
+    super::with_user_pool(user_pool_id, |user_pool| {
+        match user_pool.sessions.get(claim("origin_jti")) {
+            Some(session) if !session.revoked => {
+                user_pool.user(&session.username)?.check_enabled()?;
+                Ok(AccessTokenUser {
+                    user_pool_id: user_pool.id.clone(),
+                    username: session.username.clone(),
+                    client_id: session.client_id.clone(),
+                })
+            }
+            _ => Err(invalid("Access Token has been revoked")),
+        }
+    })
+    .map_err(|(e, message)| match e {
+        StoreError::ResourceNotFoundException => invalid("Invalid Access Token"),
+        _ => (e, message),
+    })
+}
+
+/// Applies a function to the user pool of a valid access token and the user of the token.
+pub fn with_access_token_user<T, F>(access_token: &str, f: F) -> StoreResult<T>
+where
+    F: FnOnce(&mut UserPoolState, &AccessTokenUser) -> StoreResult<T>,
+{
+    let user = access_token_user(access_token)?;
+    super::with_user_pool(&user.user_pool_id, |user_pool| f(user_pool, &user))
+}
+
+impl UserPoolState {
+    /// Starts a session of a user and returns tokens with a new refresh token.
+    pub fn start_session(
+        &mut self,
+        client_id: &str,
+        username: &str,
+    ) -> StoreResult<AuthenticationResult> {
+        self.start_device_session(client_id, username, None)
+    }
+
+    /// Starts a session of a user on a device and returns tokens with a new refresh token.
+    pub fn start_device_session(
+        &mut self,
+        client_id: &str,
+        username: &str,
+        device_key: Option<String>,
+    ) -> StoreResult<AuthenticationResult> {
+        let client = self.client(client_id)?;
+        let now = Utc::now();
+        let session = super::SessionState {
+            origin_jti: common::new_uuid(),
+            username: username.to_string(),
+            client_id: client_id.to_string(),
+            refresh_token: common::random_string(ALPHANUMERIC, REFRESH_TOKEN_LENGTH),
+            auth_time: now,
+            refresh_token_expires_at: now + client.refresh_token_validity,
+            revoked: false,
+            device_key,
+        };
+        let mut result = self.issue_tokens(&session, now)?;
+        result.refresh_token = Some(session.refresh_token.clone());
+        self.sessions.insert(session.origin_jti.clone(), session);
+        Ok(result)
+    }
+
+    /// Returns new tokens of the session of a refresh token.
+    pub fn refresh_session(
+        &self,
+        client_id: &str,
+        refresh_token: &str,
+    ) -> StoreResult<AuthenticationResult> {
+        let invalid = |message: &str| (StoreError::NotAuthorizedException, message.to_string());
+        let now = Utc::now();
+        let session = self
+            .session_of_refresh_token(refresh_token)
+            .filter(|s| s.client_id == client_id)
+            .ok_or_else(|| invalid("Invalid Refresh Token"))?;
+        if session.revoked {
+            return Err(invalid("Refresh Token has been revoked"));
+        }
+        if session.refresh_token_expires_at <= now {
+            return Err(invalid("Refresh Token has expired"));
+        }
+        self.user(&session.username)?.check_enabled()?;
+        self.issue_tokens(session, now)
+    }
+
+    /// Revokes a refresh token and the access tokens issued with it.
+    ///
+    /// Unknown tokens are ignored as Cognito does.
+    pub fn revoke_refresh_token(&mut self, client_id: &str, token: &str) -> StoreResult<()> {
+        let client = self.client(client_id)?;
+        if !client.enable_token_revocation {
+            return Err((
+                StoreError::UnsupportedOperationException,
+                "Token revocation is not enabled for the app client.".to_string(),
+            ));
+        }
+        if jwt::token_claims(token).is_some() {
+            return Err((
+                StoreError::UnsupportedTokenTypeException,
+                "Only refresh tokens can be revoked.".to_string(),
+            ));
+        }
+        let origin_jti = match self.session_of_refresh_token(token) {
+            Some(session) if session.client_id != client_id => {
+                return Err((
+                    StoreError::UnauthorizedException,
+                    "The refresh token was not issued to the app client.".to_string(),
+                ))
+            }
+            Some(session) => session.origin_jti.clone(),
+            None => return Ok(()),
+        };
+        if let Some(session) = self.sessions.get_mut(&origin_jti) {
+            session.revoked = true;
+        }
+        Ok(())
+    }
+
+    fn session_of_refresh_token(&self, refresh_token: &str) -> Option<&super::SessionState> {
+        self.sessions
+            .values()
+            .find(|s| s.refresh_token == refresh_token)
+    }
+
+    fn issue_tokens(
+        &self,
+        session: &super::SessionState,
+        now: DateTime<Utc>,
+    ) -> StoreResult<AuthenticationResult> {
+        let client = self.client(&session.client_id)?;
+        let user = self.user(&session.username)?;
+        let group_claims = self.group_claims(&user.username)?;
+        let issuer = user_pool_issuer(&self.id);
+        let event_id = common::new_uuid();
+        let common_claims = serde_json::json!({
+            "auth_time": session.auth_time.timestamp(),
+            "event_id": event_id,
+            "iat": now.timestamp(),
+            "iss": issuer,
+            "origin_jti": session.origin_jti,
+            "sub": user.sub,
+        });
+
+        let mut access_claims = serde_json::json!({
+            "client_id": client.client_id,
+            "exp": (now + client.access_token_validity).timestamp(),
+            "jti": common::new_uuid(),
+            "scope": ACCESS_TOKEN_SCOPE,
+            "token_use": "access",
+            "username": user.username,
+        });
+        if let Some(device_key) = session.device_key.as_ref() {
+            access_claims["device_key"] = device_key.as_str().into();
+        }
+        if !group_claims.groups.is_empty() {
+            access_claims["cognito:groups"] = group_claims.groups.clone().into();
+        }
+        super::merge_value(&mut access_claims, common_claims.clone());
+
+        let mut id_claims = user
+            .attributes
+            .iter()
+            .map(|(name, value)| (name.clone(), id_token_claim(name, value)))
+            .collect::<serde_json::Value>();
+        super::merge_value(
+            &mut id_claims,
+            serde_json::json!({
+                "aud": client.client_id,
+                "cognito:username": user.username,
+                "exp": (now + client.id_token_validity).timestamp(),
+                "jti": common::new_uuid(),
+                "token_use": "id",
+            }),
+        );
+        super::merge_value(&mut id_claims, common_claims);
+        group_claims.merge_into(&mut id_claims);
+
+        let signer = jwt::token_signer();
+        Ok(AuthenticationResult {
+            access_token: signer.sign(&access_claims),
+            id_token: signer.sign(&id_claims),
+            refresh_token: None,
+            expires_in: client.access_token_validity.num_seconds(),
+            new_device_metadata: None,
+        })
+    }
+}
+
+/// Returns a claim of an attribute, which is a boolean for the verified attributes.
+fn id_token_claim(name: &str, value: &str) -> serde_json::Value {
+    match (name.ends_with("_verified"), value) {
+        (true, "true") => true.into(),
+        (true, "false") => false.into(),
+        _ => value.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{ClientState, UserState, UserStatus};
+    use pretty_assertions::assert_eq;
+
+    fn user_pool() -> (UserPoolState, String) {
+        let mut user_pool = UserPoolState::new("pool");
+        let client = ClientState::new("client", false);
+        let client_id = client.client_id.clone();
+        user_pool.clients.insert(client_id.clone(), client);
+        let mut user = UserState::new("user", UserStatus::Confirmed);
+        user.set_attribute("email_verified", "true");
+        user_pool.add_user(user).unwrap();
+        (user_pool, client_id)
+    }
+
+    #[test]
+    fn session_tokens_have_claims() {
+        let (mut user_pool, client_id) = user_pool();
+        let result = user_pool.start_session(&client_id, "user").unwrap();
+        assert_eq!(3600, result.expires_in);
+
+        let access = jwt::token_claims(&result.access_token).unwrap();
+        assert_eq!("access", access["token_use"]);
+        assert_eq!("user", access["username"]);
+        assert_eq!(client_id.as_str(), access["client_id"]);
+        assert_eq!(user_pool_issuer(&user_pool.id), access["iss"]);
+
+        let id = jwt::token_claims(&result.id_token).unwrap();
+        assert_eq!("id", id["token_use"]);
+        assert_eq!(client_id.as_str(), id["aud"]);
+        assert_eq!(true, id["email_verified"]);
+        assert_eq!(access["origin_jti"], id["origin_jti"]);
+        assert!(id.get("cognito:groups").is_none());
+    }
+
+    #[test]
+    fn session_tokens_have_group_claims() {
+        let (mut user_pool, client_id) = user_pool();
+        user_pool
+            .add_group(serde_json::json!({
+                "GroupName": "admins",
+                "Precedence": 1,
+                "RoleArn": "arn:aws:iam::123456789012:role/Admin",
+            }))
+            .unwrap();
+        user_pool.add_user_to_group("user", "admins").unwrap();
+        let result = user_pool.start_session(&client_id, "user").unwrap();
+
+        let access = jwt::token_claims(&result.access_token).unwrap();
+        assert_eq!(serde_json::json!(["admins"]), access["cognito:groups"]);
+        let id = jwt::token_claims(&result.id_token).unwrap();
+        assert_eq!(serde_json::json!(["admins"]), id["cognito:groups"]);
+        assert_eq!(
+            serde_json::json!(["arn:aws:iam::123456789012:role/Admin"]),
+            id["cognito:roles"]
+        );
+        assert_eq!(
+            "arn:aws:iam::123456789012:role/Admin",
+            id["cognito:preferred_role"]
+        );
+    }
+
+    #[test]
+    fn refresh_token_returns_new_tokens_until_revoked() {
+        let (mut user_pool, client_id) = user_pool();
+        let result = user_pool.start_session(&client_id, "user").unwrap();
+        let refresh_token = result.refresh_token.unwrap();
+
+        let refreshed = user_pool
+            .refresh_session(&client_id, &refresh_token)
+            .unwrap();
+        assert_eq!(None, refreshed.refresh_token);
+        assert!(user_pool.refresh_session("other", &refresh_token).is_err());
+
+        user_pool
+            .revoke_refresh_token(&client_id, &refresh_token)
+            .unwrap();
+        assert_eq!(
+            Err("Refresh Token has been revoked".to_string()),
+            user_pool
+                .refresh_session(&client_id, &refresh_token)
+                .map_err(|(_, m)| m)
+        );
+    }
+
+    #[test]
+    fn expired_refresh_token_is_rejected() {
+        let (mut user_pool, client_id) = user_pool();
+        user_pool
+            .clients
+            .get_mut(&client_id)
+            .unwrap()
+            .refresh_token_validity = chrono::Duration::zero();
+        let result = user_pool.start_session(&client_id, "user").unwrap();
+        assert_eq!(
+            Err("Refresh Token has expired".to_string()),
+            user_pool
+                .refresh_session(&client_id, &result.refresh_token.unwrap())
+                .map_err(|(_, m)| m)
+        );
+    }
+
+    #[test]
+    fn only_refresh_tokens_can_be_revoked() {
+        let (mut user_pool, client_id) = user_pool();
+        let result = user_pool.start_session(&client_id, "user").unwrap();
+        assert_eq!(
+            Err(StoreError::UnsupportedTokenTypeException),
+            user_pool
+                .revoke_refresh_token(&client_id, &result.access_token)
+                .map_err(|(e, _)| e)
+        );
+        assert!(user_pool
+            .revoke_refresh_token(&client_id, "unknown")
+            .is_ok());
+    }
+}
@@ -0,0 +1,269 @@
+use super::{StoreResult, UserPoolState};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// CSS classes of the Hosted UI which can be customized.
+pub const UI_CUSTOMIZATION_CSS_CLASSES: [&str; 13] = [
+    "background-customizable",
+    "banner-customizable",
+    "errorMessage-customizable",
+    "idpButton-customizable",
+    "idpButton-customizable:hover",
+    "inputField-customizable",
+    "inputField-customizable:focus",
+    "label-customizable",
+    "legalText-customizable",
+    "logo-customizable",
+    "submitButton-customizable",
+    "submitButton-customizable:hover",
+    "textDescription-customizable",
+];
+
+/// Maximum size of a logo image file in bytes.
+pub const MAX_UI_IMAGE_FILE_SIZE: usize = 100 * 1024;
+
+/// Path segment of the URLs which serve the logo images of the UI customizations.
+pub const UI_CUSTOMIZATION_PATH: &str = "ui";
+
+/// `ClientId` of the UI customization of a whole user pool.
+pub const ALL_CLIENTS: &str = "ALL";
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const JPEG_SIGNATURE: [u8; 3] = [0xff, 0xd8, 0xff];
+
+static CSS_SELECTOR_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"([^{}]*)\{[^}]*\}").unwrap());
+
+/// Returns a message for the first selector of the CSS which is not a customizable class.
+pub fn check_ui_css(css: &str) -> Option<String> {
+    CSS_SELECTOR_REGEX
+        .captures_iter(css)
+        .flat_map(|captures| {
+            captures[1]
+                .split(',')
+                .map(|selector| selector.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .find(|selector| {
+            !selector
+                .strip_prefix('.')
+                .is_some_and(|class| UI_CUSTOMIZATION_CSS_CLASSES.contains(&class))
+        })
+        .map(|selector| format!("CSS class is not allowed: {}", selector))
+}
+
+/// Returns a message if the base64 encoded logo image is not a PNG or JPEG image within the size limit.
+pub fn check_ui_image_file(image_file: &str) -> Option<String> {
+    let image = match STANDARD.decode(image_file) {
+        Ok(image) => image,
+        Err(_) => return Some("Image file is not base64 encoded.".to_string()),
+    };
+    if image.len() > MAX_UI_IMAGE_FILE_SIZE {
+        Some("Image file exceeds the maximum size of 100KB.".to_string())
+    } else if !image.starts_with(&PNG_SIGNATURE) && !image.starts_with(&JPEG_SIGNATURE) {
+        Some("Image file must be a PNG or JPEG image.".to_string())
+    } else {
+        None
+    }
+}
+
+/// UI customization set by SetUICustomization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UiCustomizationState {
+    pub css: Option<String>,
+    /// Base64 encoded logo image.
+    pub image_file: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_modified_at: DateTime<Utc>,
+}
+
+impl UiCustomizationState {
+    /// Returns `CSSVersion`, which changes with every update.
+    pub fn css_version(&self) -> String {
+        self.last_modified_at.format("%Y%m%d%H%M%S%3f").to_string()
+    }
+
+    /// Returns the decoded logo image and its content type.
+    pub fn image(&self) -> Option<(Vec<u8>, &'static str)> {
+        let image = STANDARD.decode(self.image_file.as_deref()?).ok()?;
+        let content_type = if image.starts_with(&PNG_SIGNATURE) {
+            "image/png"
+        } else {
+            "image/jpeg"
+        };
+        Some((image, content_type))
+    }
+
+    /// Returns `UICustomization` of the responses, with `ClientId` of `ALL` for the user pool.
+    pub fn to_value(&self, user_pool_id: &str, client_id: Option<&str>) -> serde_json::Value {
+        let client_id = client_id.unwrap_or(ALL_CLIENTS);
+        let mut value = serde_json::json!({
+            "ClientId": client_id,
+            "CreationDate": self.created_at.timestamp(),
+            "CSSVersion": self.css_version(),
+            "LastModifiedDate": self.last_modified_at.timestamp(),
+            "UserPoolId": user_pool_id,
+        });
+        if let Some(css) = self.css.as_deref() {
+            value["CSS"] = css.into();
+        }
+        if self.image_file.is_some() {
+            value["ImageUrl"] = ui_image_url(user_pool_id, client_id).into();
+        }
+        value
+    }
+}
+
+/// Returns the URL of the logo image of the UI customization of an app client or `ALL`.
+pub fn ui_image_url(user_pool_id: &str, client_id: &str) -> String {
+    format!(
+        "{}/{}/{}",
+        super::user_pool_issuer(user_pool_id),
+        UI_CUSTOMIZATION_PATH,
+        client_id
+    )
+}
+
+/// Returns the decoded logo image of the UI customization of an app client or `ALL`.
+pub fn ui_image(user_pool_id: &str, client_id: &str) -> Option<(Vec<u8>, &'static str)> {
+    let key = (client_id != ALL_CLIENTS).then(|| client_id.to_string());
+    super::with_user_pool(user_pool_id, |user_pool| {
+        Ok(user_pool
+            .ui_customizations
+            .get(&key)
+            .and_then(UiCustomizationState::image))
+    })
+    .ok()
+    .flatten()
+}
+
+impl UserPoolState {
+    /// Stores the UI customization of an app client, or of the user pool if `client_id` is `None`.
+    pub fn set_ui_customization(
+        &mut self,
+        client_id: Option<&str>,
+        css: Option<String>,
+        image_file: Option<String>,
+    ) -> StoreResult<&UiCustomizationState> {
+        if let Some(client_id) = client_id {
+            self.client(client_id)?;
+        }
+        let now = Utc::now();
+        let customization = self
+            .ui_customizations
+            .entry(client_id.map(str::to_string))
+            .or_insert_with(|| UiCustomizationState {
+                css: None,
+                image_file: None,
+                created_at: now,
+                last_modified_at: now,
+            });
+        customization.css = css;
+        customization.image_file = image_file;
+        customization.last_modified_at = now;
+        Ok(customization)
+    }
+
+    /// Returns the UI customization of an app client, falling back to the one of the user pool.
+    ///
+    /// The returned `ClientId` is `None` when the customization is the one of the user pool.
+    pub fn ui_customization<'a>(
+        &'a self,
+        client_id: Option<&'a str>,
+    ) -> Option<(Option<&'a str>, &'a UiCustomizationState)> {
+        client_id
+            .and_then(|id| {
+                self.ui_customizations
+                    .get(&Some(id.to_string()))
+                    .map(|c| (Some(id), c))
+            })
+            .or_else(|| self.ui_customizations.get(&None).map(|c| (None, c)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn css_allows_customizable_classes() {
+        assert_eq!(None, check_ui_css(""));
+        assert_eq!(
+            None,
+            check_ui_css(
+                ".logo-customizable { max-width: 60%; }\n\
+                 .submitButton-customizable, .submitButton-customizable:hover { color: red; }"
+            )
+        );
+        assert_eq!(
+            Some("CSS class is not allowed: body".to_string()),
+            check_ui_css(".logo-customizable { max-width: 60%; } body { color: red; }")
+        );
+        assert_eq!(
+            Some("CSS class is not allowed: .banner".to_string()),
+            check_ui_css(".banner { color: red; }")
+        );
+    }
+
+    #[test]
+    fn client_ui_customization_falls_back_to_user_pool() {
+        let mut user_pool = UserPoolState::new("pool");
+        let client = crate::user_pools::ClientState::new("client", false);
+        let client_id = client.client_id.clone();
+        user_pool.clients.insert(client_id.clone(), client);
+        assert!(user_pool
+            .set_ui_customization(Some("unknown"), None, None)
+            .is_err());
+
+        let css = Some(".logo-customizable { max-width: 60%; }".to_string());
+        user_pool
+            .set_ui_customization(None, css.clone(), None)
+            .unwrap();
+        let (customized_client, customization) =
+            user_pool.ui_customization(Some(&client_id)).unwrap();
+        assert_eq!(None, customized_client);
+        assert_eq!(css, customization.css);
+
+        let image_file = Some(STANDARD.encode(PNG_SIGNATURE));
+        user_pool
+            .set_ui_customization(Some(&client_id), None, image_file)
+            .unwrap();
+        let (customized_client, customization) =
+            user_pool.ui_customization(Some(&client_id)).unwrap();
+        assert_eq!(Some(client_id.as_str()), customized_client);
+        assert_eq!(None, customization.css);
+        assert_eq!(
+            Some((PNG_SIGNATURE.to_vec(), "image/png")),
+            customization.image()
+        );
+        let value = customization.to_value(&user_pool.id, customized_client);
+        assert!(value["ImageUrl"]
+            .as_str()
+            .unwrap()
+            .ends_with(&format!("/ui/{}", client_id)));
+    }
+
+    #[test]
+    fn image_file_is_png_or_jpeg() {
+        let encode = |image: &[u8]| STANDARD.encode(image);
+        assert_eq!(None, check_ui_image_file(&encode(&PNG_SIGNATURE)));
+        assert_eq!(
+            None,
+            check_ui_image_file(&encode(&[0xff, 0xd8, 0xff, 0xe0]))
+        );
+        assert_eq!(
+            Some("Image file must be a PNG or JPEG image.".to_string()),
+            check_ui_image_file(&encode(b"GIF89a"))
+        );
+        assert_eq!(
+            Some("Image file exceeds the maximum size of 100KB.".to_string()),
+            check_ui_image_file(&encode(&[0xff; MAX_UI_IMAGE_FILE_SIZE + 1]))
+        );
+        assert_eq!(
+            Some("Image file is not base64 encoded.".to_string()),
+            check_ui_image_file("not base64")
+        );
+    }
+}
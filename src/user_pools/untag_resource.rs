@@ -0,0 +1,92 @@
+use crate::common::ARN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const UNTAG_RESOURCE_NAME: &str = "UntagResource";
+pub const UNTAG_RESOURCE_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.UntagResource";
+
+super::gen_response_err!(
+    UntagResourceError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UntagResourceRequest {
+    #[validate(required)]
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex(path = *ARN_REGEX))]
+    pub resource_arn: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 50))]
+    pub tag_keys: Option<Vec<String>>,
+}
+
+impl super::ToActionName for UntagResourceRequest {
+    fn to_action_name() -> &'static str {
+        UNTAG_RESOURCE_NAME
+    }
+}
+
+impl super::ToResponse for UntagResourceRequest {
+    type E = UntagResourceError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::untag_resource(
+                self.resource_arn.as_deref().unwrap_or_default(),
+                self.tag_keys.as_deref().unwrap_or_default(),
+            );
+            Ok(())
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_tag_keys(self.tag_keys.as_ref()?)
+            .map(|message| (UntagResourceError::InvalidParameterException, message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UntagResourceRequest {
+            resource_arn: Some(
+                "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example".to_string(),
+            ),
+            tag_keys: Some(vec!["env".to_string()]),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UntagResourceRequest {
+            resource_arn: Some(
+                "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example".to_string(),
+            ),
+            tag_keys: Some(vec![]),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = UntagResourceError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UntagResourceError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
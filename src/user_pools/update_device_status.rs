@@ -0,0 +1,104 @@
+use crate::common::{DEVICE_KEY_REGEX, TOKEN_REGEX};
+use crate::{http, validator::includes};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const UPDATE_DEVICE_STATUS_NAME: &str = "UpdateDeviceStatus";
+pub const UPDATE_DEVICE_STATUS_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.UpdateDeviceStatus";
+
+fn validate_device_remembered_status(value: &str) -> Result<(), ValidationError> {
+    includes(value, vec!["remembered", "not_remembered"])
+}
+
+super::gen_response_err!(
+    UpdateDeviceStatusError,
+    InvalidParameterException
+    | InvalidUserPoolConfigurationException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateDeviceStatusRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *DEVICE_KEY_REGEX))]
+    pub device_key: Option<String>,
+    #[validate(custom(function = validate_device_remembered_status))]
+    pub device_remembered_status: Option<String>,
+}
+
+impl super::ToActionName for UpdateDeviceStatusRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_DEVICE_STATUS_NAME
+    }
+}
+
+impl super::ToResponse for UpdateDeviceStatusRequest {
+    type E = UpdateDeviceStatusError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    user_pool.update_device_status(
+                        &user.username,
+                        self.device_key.as_deref().unwrap_or_default(),
+                        self.device_remembered_status.as_deref(),
+                    )
+                },
+            )
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateDeviceStatusRequest {
+            access_token: Some("access_token".to_string()),
+            device_key: Some("us-east-1_device".to_string()),
+            device_remembered_status: Some("remembered".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateDeviceStatusRequest {
+            access_token: Some("access_token".to_string()),
+            device_key: Some("us-east-1_device".to_string()),
+            device_remembered_status: Some("forgotten".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = UpdateDeviceStatusError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateDeviceStatusError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::common::{IDP_IDENTIFIER_REGEX, NAME_REGEX, USER_POOL_ID_REGEX};
+use crate::{http, validator::regex_in_array};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const UPDATE_IDENTITY_PROVIDER_NAME: &str = "UpdateIdentityProvider";
+pub const UPDATE_IDENTITY_PROVIDER_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.UpdateIdentityProvider";
+
+fn validate_idp_identifiers(value: &[String]) -> Result<(), ValidationError> {
+    regex_in_array(value, &IDP_IDENTIFIER_REGEX)
+}
+
+super::gen_response_err!(
+    UpdateIdentityProviderError,
+    ConcurrentModificationException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnsupportedIdentityProviderException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateIdentityProviderRequest {
+    pub attribute_mapping: Option<std::collections::HashMap<String, String>>,
+    #[validate(length(min = 0, max = 50))]
+    #[validate(custom(function = validate_idp_identifiers))]
+    pub idp_identifiers: Option<Vec<String>>,
+    pub provider_details: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 32))]
+    #[validate(regex(path = *NAME_REGEX))]
+    pub provider_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for UpdateIdentityProviderRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_IDENTITY_PROVIDER_NAME
+    }
+}
+
+impl super::ToResponse for UpdateIdentityProviderRequest {
+    type E = UpdateIdentityProviderError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, UPDATE_IDENTITY_PROVIDER_NAME);
+        }
+        super::to_converted_json_response(self, UPDATE_IDENTITY_PROVIDER_NAME, |mut value| {
+            let fields = serde_json::to_value(self).unwrap_or_default();
+            value["IdentityProvider"] = super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool
+                    .update_identity_provider(
+                        self.provider_name.as_deref().unwrap_or_default(),
+                        fields,
+                    )
+                    .cloned()
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateIdentityProviderRequest {
+            provider_name: Some("provider_name".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateIdentityProviderRequest {
+            provider_name: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = UpdateIdentityProviderError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateIdentityProviderError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::common::{
+    RESOURCE_SERVER_IDENTIFIER_REGEX, RESOURCE_SERVER_NAME_REGEX, USER_POOL_ID_REGEX,
+};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const UPDATE_RESOURCE_SERVER_NAME: &str = "UpdateResourceServer";
+pub const UPDATE_RESOURCE_SERVER_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.UpdateResourceServer";
+
+super::gen_response_err!(
+    UpdateResourceServerError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateResourceServerRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 256))]
+    #[validate(regex(path = *RESOURCE_SERVER_IDENTIFIER_REGEX))]
+    pub identifier: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 256))]
+    #[validate(regex(path = *RESOURCE_SERVER_NAME_REGEX))]
+    pub name: Option<String>,
+    #[validate(length(max = 100))]
+    #[validate(nested)]
+    pub scopes: Option<Vec<super::data_types::ResourceServerScopeType>>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for UpdateResourceServerRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_RESOURCE_SERVER_NAME
+    }
+}
+
+impl super::ToResponse for UpdateResourceServerRequest {
+    type E = UpdateResourceServerError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, UPDATE_RESOURCE_SERVER_NAME);
+        }
+        super::to_converted_json_response(self, UPDATE_RESOURCE_SERVER_NAME, |mut value| {
+            value["ResourceServer"] = super::with_user_pool(user_pool_id, |user_pool| {
+                user_pool
+                    .update_resource_server(value["ResourceServer"].clone())
+                    .cloned()
+            })
+            .map_err(super::to_response_error)?;
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_scope_names(self.scopes.as_ref()?).map(|message| {
+            (
+                UpdateResourceServerError::InvalidParameterException,
+                message,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateResourceServerRequest {
+            identifier: Some("identifier".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            name: Some("name".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateResourceServerRequest {
+            identifier: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            name: Some("name".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn action_error_for_duplicated_scopes() {
+        use crate::user_pools::{ResourceServerScopeType, ToResponse};
+
+        let scope = || ResourceServerScopeType {
+            scope_description: Some("description".to_string()),
+            scope_name: Some("read".to_string()),
+        };
+        let request = UpdateResourceServerRequest {
+            identifier: Some("identifier".to_string()),
+            name: Some("name".to_string()),
+            scopes: Some(vec![scope(), scope()]),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.action_error().is_some());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = UpdateResourceServerError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateResourceServerError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
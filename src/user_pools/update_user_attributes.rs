@@ -0,0 +1,143 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const UPDATE_USER_ATTRIBUTES_NAME: &str = "UpdateUserAttributes";
+pub const UPDATE_USER_ATTRIBUTES_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.UpdateUserAttributes";
+
+super::gen_response_err!(
+    UpdateUserAttributesError,
+    AliasExistsException
+    | CodeDeliveryFailureException
+    | CodeMismatchException
+    | ExpiredCodeException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUserAttributesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(nested)]
+    pub user_attributes: Option<Vec<super::data_types::AttributeType>>,
+}
+
+impl super::ToActionName for UpdateUserAttributesRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_USER_ATTRIBUTES_NAME
+    }
+}
+
+impl super::ToResponse for UpdateUserAttributesRequest {
+    type E = UpdateUserAttributesError;
+    fn to_response(&self) -> super::Response {
+        let mut codes = None;
+        let response =
+            super::to_converted_json_response(self, UPDATE_USER_ATTRIBUTES_NAME, |mut value| {
+                let deliveries = super::with_access_token_user(
+                    self.access_token.as_deref().unwrap_or_default(),
+                    |user_pool, user| {
+                        let attributes = self.attribute_pairs();
+                        user_pool.check_user_attributes(
+                            &attributes,
+                            super::AttributeWrite::Update { admin: false },
+                        )?;
+                        user_pool.update_user_attributes(&user.username, &attributes)
+                    },
+                )
+                .map_err(super::to_response_error)?;
+                value["CodeDeliveryDetailsList"] = deliveries
+                    .iter()
+                    .map(super::CodeDelivery::to_value)
+                    .collect();
+                codes = super::delivery_codes(&deliveries);
+                Ok(value)
+            });
+        super::with_code_header(response, codes)
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        let attributes = self.user_attributes.as_ref()?;
+        super::check_writable_attributes(attributes.iter().filter_map(|a| a.name.as_deref())).map(
+            |message| {
+                (
+                    UpdateUserAttributesError::InvalidParameterException,
+                    message,
+                )
+            },
+        )
+    }
+}
+
+impl UpdateUserAttributesRequest {
+    fn attribute_pairs(&self) -> Vec<(String, String)> {
+        self.user_attributes
+            .iter()
+            .flatten()
+            .filter_map(|a| Some((a.name.clone()?, a.value.clone()?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            user_attributes: Some(vec![crate::user_pools::AttributeType {
+                name: Some("name".to_string()),
+                value: Some("value".to_string()),
+            }]),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            user_attributes: Some(vec![]),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = UpdateUserAttributesError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateUserAttributesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
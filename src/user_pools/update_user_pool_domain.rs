@@ -0,0 +1,118 @@
+use crate::common::{DOMAIN_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const UPDATE_USER_POOL_DOMAIN_NAME: &str = "UpdateUserPoolDomain";
+pub const UPDATE_USER_POOL_DOMAIN_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.UpdateUserPoolDomain";
+
+super::gen_response_err!(
+    UpdateUserPoolDomainError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUserPoolDomainRequest {
+    #[validate(required)]
+    #[validate(nested)]
+    pub custom_domain_config: Option<super::CustomDomainConfigType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 63))]
+    #[validate(regex(path = *DOMAIN_REGEX))]
+    pub domain: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex(path = *USER_POOL_ID_REGEX))]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for UpdateUserPoolDomainRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_USER_POOL_DOMAIN_NAME
+    }
+}
+
+impl super::ToResponse for UpdateUserPoolDomainRequest {
+    type E = UpdateUserPoolDomainError;
+    fn to_response(&self) -> super::Response {
+        let user_pool_id = self.user_pool_id.as_deref().unwrap_or_default();
+        if !super::user_pool_exists(user_pool_id) {
+            return super::to_json_response(self, UPDATE_USER_POOL_DOMAIN_NAME);
+        }
+        super::to_converted_json_response(self, UPDATE_USER_POOL_DOMAIN_NAME, |mut value| {
+            let certificate_arn = self
+                .custom_domain_config
+                .as_ref()
+                .and_then(|c| c.certificate_arn.clone());
+            value["CloudFrontDomain"] = super::with_user_pool(user_pool_id, |user_pool| {
+                Ok(user_pool
+                    .update_domain(self.domain.as_deref().unwrap_or_default(), certificate_arn)?
+                    .cloud_front_domain
+                    .clone())
+            })
+            .map_err(super::to_response_error)?
+            .into();
+            Ok(value)
+        })
+    }
+
+    fn action_error(&self) -> Option<(Self::E, String)> {
+        super::check_domain_in_use(self.domain.as_deref()?, self.user_pool_id.as_deref()).map(
+            |message| {
+                (
+                    UpdateUserPoolDomainError::InvalidParameterException,
+                    message,
+                )
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::CustomDomainConfigType;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateUserPoolDomainRequest {
+            custom_domain_config: Some(CustomDomainConfigType {
+                certificate_arn: Some(
+                    "arn:aws:acm:us-east-1:123456789012:certificate/certificate_id".to_string(),
+                ),
+            }),
+            domain: Some("auth.example.com".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateUserPoolDomainRequest {
+            domain: Some("auth.example.com".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = UpdateUserPoolDomainError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateUserPoolDomainError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,311 @@
+use super::{StoreError, StoreResult, UserPoolState};
+use chrono::{DateTime, Duration, Utc};
+
+/// Attributes which are verified by codes.
+pub const VERIFIABLE_ATTRIBUTES: [&str; 2] = ["email", "phone_number"];
+
+const CODE_VALIDITY_HOURS: i64 = 24;
+
+/// Code sent to verify an attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingVerification {
+    pub code: String,
+    /// New value which is updated after the verification, if the user pool keeps the old one.
+    pub value: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Code sent to a user to verify an attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeDelivery {
+    pub attribute_name: String,
+    pub destination: String,
+    pub code: String,
+}
+
+impl CodeDelivery {
+    /// Returns the `CodeDeliveryDetailsType` of the responses.
+    pub fn to_value(&self) -> serde_json::Value {
+        let medium = match self.attribute_name.as_str() {
+            "phone_number" => "SMS",
+            _ => "EMAIL",
+        };
+        serde_json::json!({
+            "AttributeName": self.attribute_name,
+            "DeliveryMedium": medium,
+            "Destination": self.destination,
+        })
+    }
+}
+
+/// Returns the codes of deliveries for the debug header.
+pub fn delivery_codes(deliveries: &[CodeDelivery]) -> Option<String> {
+    (!deliveries.is_empty()).then(|| {
+        deliveries
+            .iter()
+            .map(|d| d.code.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    })
+}
+
+fn verified_attribute(name: &str) -> String {
+    format!("{}_verified", name)
+}
+
+impl UserPoolState {
+    pub(super) fn described_attributes(&self, pointer: &str) -> Vec<String> {
+        self.description
+            .pointer(pointer)
+            .and_then(|v| v.as_array())
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|n| n.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns `AutoVerifiedAttributes` of the user pool.
+    pub fn auto_verified_attributes(&self) -> Vec<String> {
+        self.described_attributes("/AutoVerifiedAttributes")
+    }
+
+    fn attributes_require_verification_before_update(&self) -> Vec<String> {
+        self.described_attributes(
+            "/UserAttributeUpdateSettings/AttributesRequireVerificationBeforeUpdate",
+        )
+    }
+
+    /// Updates attributes of a user and returns the codes sent to verify changed attributes.
+    ///
+    /// A changed `email` or `phone_number` becomes unverified unless its `*_verified` is given.
+    pub fn update_user_attributes(
+        &mut self,
+        username: &str,
+        attributes: &[(String, String)],
+    ) -> StoreResult<Vec<CodeDelivery>> {
+        let auto_verified = self.auto_verified_attributes();
+        let keep_old_values = self.attributes_require_verification_before_update();
+        let mut user = self.user(username)?.clone();
+        let mut deliveries = vec![];
+        for (name, value) in attributes {
+            let verifiable = VERIFIABLE_ATTRIBUTES.contains(&name.as_str());
+            if !verifiable || user.attribute(name) == Some(value.as_str()) {
+                user.set_attribute(name, value);
+                continue;
+            }
+            let verified = verified_attribute(name);
+            if attributes
+                .iter()
+                .any(|(n, v)| *n == verified && v == "true")
+            {
+                user.set_attribute(name, value);
+                user.verification_codes.remove(name);
+                continue;
+            }
+            if !auto_verified.contains(name) {
+                user.set_attribute(name, value);
+                user.set_attribute(&verified, "false");
+                continue;
+            }
+            let keep_old_value = keep_old_values.contains(name) && user.attribute(name).is_some();
+            if !keep_old_value {
+                user.set_attribute(name, value);
+                user.set_attribute(&verified, "false");
+            }
+            let code = super::new_code();
+            user.verification_codes.insert(
+                name.clone(),
+                PendingVerification {
+                    code: code.clone(),
+                    value: keep_old_value.then(|| value.clone()),
+                    expires_at: Utc::now() + Duration::hours(CODE_VALIDITY_HOURS),
+                },
+            );
+            deliveries.push(CodeDelivery {
+                attribute_name: name.clone(),
+                destination: value.clone(),
+                code,
+            });
+        }
+        self.claim_aliases(&user.username, &user.attributes, false)?;
+        *self.user_mut(username)? = user;
+        Ok(deliveries)
+    }
+
+    /// Sends a new code to verify an attribute of a user.
+    pub fn send_verification_code(
+        &mut self,
+        username: &str,
+        attribute_name: &str,
+    ) -> StoreResult<CodeDelivery> {
+        let user = self.user_mut(username)?;
+        let pending_value = user
+            .verification_codes
+            .get(attribute_name)
+            .and_then(|p| p.value.clone());
+        let destination = match pending_value
+            .clone()
+            .or_else(|| user.attribute(attribute_name).map(str::to_string))
+        {
+            Some(value) if VERIFIABLE_ATTRIBUTES.contains(&attribute_name) => value,
+            _ => {
+                return Err((
+                    StoreError::InvalidParameterException,
+                    format!("User does not have a verifiable {}.", attribute_name),
+                ))
+            }
+        };
+        let code = super::new_code();
+        user.verification_codes.insert(
+            attribute_name.to_string(),
+            PendingVerification {
+                code: code.clone(),
+                value: pending_value,
+                expires_at: Utc::now() + Duration::hours(CODE_VALIDITY_HOURS),
+            },
+        );
+        Ok(CodeDelivery {
+            attribute_name: attribute_name.to_string(),
+            destination,
+            code,
+        })
+    }
+
+    /// Verifies an attribute of a user by a code, and updates its pending value.
+    pub fn verify_user_attribute(
+        &mut self,
+        username: &str,
+        attribute_name: &str,
+        code: &str,
+    ) -> StoreResult<()> {
+        let user = self.user_mut(username)?;
+        let pending = match user.verification_codes.get(attribute_name) {
+            Some(pending) if pending.code == code => pending.clone(),
+            _ => {
+                return Err((
+                    StoreError::CodeMismatchException,
+                    "Invalid verification code provided, please try again.".to_string(),
+                ))
+            }
+        };
+        if pending.expires_at <= Utc::now() {
+            return Err((
+                StoreError::ExpiredCodeException,
+                "Invalid code provided, please request a code again.".to_string(),
+            ));
+        }
+        let mut verified = user.clone();
+        verified.verification_codes.remove(attribute_name);
+        if let Some(value) = pending.value.as_ref() {
+            verified.set_attribute(attribute_name, value);
+        }
+        verified.set_attribute(&verified_attribute(attribute_name), "true");
+        self.claim_aliases(&verified.username, &verified.attributes, false)?;
+        *self.user_mut(username)? = verified;
+        Ok(())
+    }
+
+    /// Marks the auto-verified attributes of a user as verified when the user is confirmed by a code.
+    pub fn verify_auto_verified_attributes(
+        &mut self,
+        username: &str,
+        force_alias_creation: bool,
+    ) -> StoreResult<()> {
+        let auto_verified = self.auto_verified_attributes();
+        let mut user = self.user(username)?.clone();
+        for name in auto_verified {
+            if user.attribute(&name).is_some() {
+                user.set_attribute(&verified_attribute(&name), "true");
+            }
+        }
+        self.claim_aliases(&user.username, &user.attributes, force_alias_creation)?;
+        *self.user_mut(username)? = user;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{UserState, UserStatus};
+    use pretty_assertions::assert_eq;
+
+    fn user_pool(description: serde_json::Value) -> UserPoolState {
+        let mut user_pool = UserPoolState::new("pool");
+        user_pool.description = description;
+        let mut user = UserState::new("user", UserStatus::Confirmed);
+        user.set_attribute("email", "old@example.com");
+        user.set_attribute("email_verified", "true");
+        user_pool.add_user(user).unwrap();
+        user_pool
+    }
+
+    fn email(value: &str) -> Vec<(String, String)> {
+        vec![("email".to_string(), value.to_string())]
+    }
+
+    #[test]
+    fn changed_email_is_unverified_until_verified_by_code() {
+        let mut user_pool = user_pool(serde_json::json!({ "AutoVerifiedAttributes": ["email"] }));
+        let deliveries = user_pool
+            .update_user_attributes("user", &email("new@example.com"))
+            .unwrap();
+        assert_eq!(1, deliveries.len());
+        assert_eq!("new@example.com", deliveries[0].destination);
+        let user = user_pool.user("user").unwrap();
+        assert_eq!(Some("new@example.com"), user.attribute("email"));
+        assert_eq!(Some("false"), user.attribute("email_verified"));
+
+        assert_eq!(
+            Err(StoreError::CodeMismatchException),
+            user_pool
+                .verify_user_attribute("user", "email", "wrong")
+                .map_err(|(e, _)| e)
+        );
+        user_pool
+            .verify_user_attribute("user", "email", &deliveries[0].code)
+            .unwrap();
+        let user = user_pool.user("user").unwrap();
+        assert_eq!(Some("true"), user.attribute("email_verified"));
+        assert!(user.verification_codes.is_empty());
+    }
+
+    #[test]
+    fn old_email_is_kept_until_new_one_is_verified() {
+        let mut user_pool = user_pool(serde_json::json!({
+            "AutoVerifiedAttributes": ["email"],
+            "UserAttributeUpdateSettings": {
+                "AttributesRequireVerificationBeforeUpdate": ["email"]
+            }
+        }));
+        user_pool
+            .update_user_attributes("user", &email("new@example.com"))
+            .unwrap();
+        let user = user_pool.user("user").unwrap();
+        assert_eq!(Some("old@example.com"), user.attribute("email"));
+        assert_eq!(Some("true"), user.attribute("email_verified"));
+
+        let delivery = user_pool.send_verification_code("user", "email").unwrap();
+        assert_eq!("new@example.com", delivery.destination);
+        user_pool
+            .verify_user_attribute("user", "email", &delivery.code)
+            .unwrap();
+        let user = user_pool.user("user").unwrap();
+        assert_eq!(Some("new@example.com"), user.attribute("email"));
+    }
+
+    #[test]
+    fn email_without_auto_verification_has_no_code() {
+        let mut user_pool = user_pool(serde_json::json!({}));
+        let deliveries = user_pool
+            .update_user_attributes("user", &email("new@example.com"))
+            .unwrap();
+        assert!(deliveries.is_empty());
+        let user = user_pool.user("user").unwrap();
+        assert_eq!(Some("false"), user.attribute("email_verified"));
+        assert_eq!(None, delivery_codes(&deliveries));
+    }
+}
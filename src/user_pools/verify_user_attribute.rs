@@ -0,0 +1,105 @@
+use crate::common::{CODE_REGEX, NAME_REGEX, TOKEN_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const VERIFY_USER_ATTRIBUTE_NAME: &str = "VerifyUserAttribute";
+pub const VERIFY_USER_ATTRIBUTE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.VerifyUserAttribute";
+
+super::gen_response_err!(
+    VerifyUserAttributeError,
+    AliasExistsException
+    | CodeMismatchException
+    | ExpiredCodeException
+    | InvalidParameterException
+    | LimitExceededException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    ForbiddenException => http::status_code(403),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct VerifyUserAttributeRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex(path = *TOKEN_REGEX))]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 32))]
+    #[validate(regex(path = *NAME_REGEX))]
+    pub attribute_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 2048))]
+    #[validate(regex(path = *CODE_REGEX))]
+    pub code: Option<String>,
+}
+
+impl super::ToActionName for VerifyUserAttributeRequest {
+    fn to_action_name() -> &'static str {
+        VERIFY_USER_ATTRIBUTE_NAME
+    }
+}
+
+impl super::ToResponse for VerifyUserAttributeRequest {
+    type E = VerifyUserAttributeError;
+    fn to_response(&self) -> super::Response {
+        super::to_applied_empty_response(self, || {
+            super::with_access_token_user(
+                self.access_token.as_deref().unwrap_or_default(),
+                |user_pool, user| {
+                    user_pool.verify_user_attribute(
+                        &user.username,
+                        self.attribute_name.as_deref().unwrap_or_default(),
+                        self.code.as_deref().unwrap_or_default(),
+                    )
+                },
+            )
+            .map_err(super::to_response_error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = VerifyUserAttributeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("email".to_string()),
+            code: Some("123456".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = VerifyUserAttributeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("email".to_string()),
+            code: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::responses::ToStatusCode;
+
+        let error = VerifyUserAttributeError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = VerifyUserAttributeError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
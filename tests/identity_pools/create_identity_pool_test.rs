@@ -0,0 +1,70 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.CreateIdentityPool",
+        )
+        .body(
+            r#"{
+            "AllowClassicFlow": false,
+            "AllowUnauthenticatedIdentities": true,
+            "CognitoIdentityProviders": [ 
+               { 
+                  "ClientId": "client_id",
+                  "ProviderName": "cognito-idp.us-east-1.amazonaws.com/us-east-1_example",
+                  "ServerSideTokenCheck": false
+               }
+            ],
+            "DeveloperProviderName": "login.example.com",
+            "IdentityPoolName": "identity_pool_name",
+            "IdentityPoolTags": { 
+               "env" : "dev" 
+            },
+            "SupportedLoginProviders": { 
+               "graph.facebook.com" : "7346241598935552" 
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.CreateIdentityPool",
+        )
+        .body(
+            r#"{
+            "AllowUnauthenticatedIdentities": true,
+            "IdentityPoolName": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
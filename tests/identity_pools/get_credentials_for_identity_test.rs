@@ -0,0 +1,260 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.GetCredentialsForIdentity",
+        )
+        .body(
+            r#"{
+            "IdentityId": "us-east-1:11111111-1111-1111-1111-111111111111",
+            "Logins": { 
+               "cognito-idp.us-east-1.amazonaws.com/us-east-1_example" : "id_token" 
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.GetCredentialsForIdentity",
+        )
+        .body(
+            r#"{
+            "IdentityId": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_with_credentials_expiration() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.GetCredentialsForIdentity",
+        )
+        .body(r#"{"IdentityId":"us-east-1:11111111-1111-1111-1111-111111111111"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let expiration = body["Credentials"]["Expiration"].as_i64().unwrap();
+    assert!(expiration > chrono::Utc::now().timestamp());
+}
+
+#[tokio::test]
+async fn test_success_to_request_with_resolved_role() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.GetCredentialsForIdentity",
+        )
+        .body(r#"{"IdentityId":"us-east-1:11111111-1111-1111-1111-111111111111"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let role_arn = "arn:aws:iam::123456789012:role/Cognito_UnauthRole";
+    assert_eq!(
+        role_arn,
+        res.headers().get("x-fakey-cognito-role-arn").unwrap()
+    );
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(role_arn, body["RoleArn"]);
+}
+
+#[tokio::test]
+async fn test_success_to_resolve_role_of_stored_identity_pool() {
+    super::setup().await;
+    let identity_pool_id = super::create_identity_pool().await;
+    let other_identity_pool_id = super::create_identity_pool().await;
+    let roles = |name: &str| {
+        serde_json::json!({
+            "authenticated": format!("arn:aws:iam::123456789012:role/{}_AuthRole", name),
+            "unauthenticated": format!("arn:aws:iam::123456789012:role/{}_UnauthRole", name),
+        })
+    };
+    for (id, name) in [
+        (&identity_pool_id, "Stored"),
+        (&other_identity_pool_id, "Other"),
+    ] {
+        let res = super::request(
+            "SetIdentityPoolRoles",
+            serde_json::json!({ "IdentityPoolId": id, "Roles": roles(name) }),
+        )
+        .await;
+        assert_eq!(200, res.status());
+    }
+    let res = super::request(
+        "GetIdentityPoolRoles",
+        serde_json::json!({ "IdentityPoolId": identity_pool_id }),
+    )
+    .await;
+    assert_eq!(roles("Stored"), super::body(&res)["Roles"]);
+
+    let res = super::request(
+        "GetId",
+        serde_json::json!({ "IdentityPoolId": identity_pool_id }),
+    )
+    .await;
+    let identity_id = super::body(&res)["IdentityId"].clone();
+    let res = super::request(
+        "GetCredentialsForIdentity",
+        serde_json::json!({ "IdentityId": identity_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!(
+        "arn:aws:iam::123456789012:role/Stored_UnauthRole",
+        super::body(&res)["RoleArn"]
+    );
+
+    let login = |subject: &str| {
+        super::request(
+            "GetId",
+            serde_json::json!({
+                "IdentityPoolId": identity_pool_id,
+                "Logins": { "login.example.com": subject },
+            }),
+        )
+    };
+    let identity_id = super::body(&login("user01").await)["IdentityId"].clone();
+    assert_eq!(
+        identity_id,
+        super::body(&login("user01").await)["IdentityId"]
+    );
+    let res = super::request(
+        "GetCredentialsForIdentity",
+        serde_json::json!({
+            "IdentityId": identity_id,
+            "Logins": { "login.example.com": "user01" },
+        }),
+    )
+    .await;
+    assert_eq!(
+        "arn:aws:iam::123456789012:role/Stored_AuthRole",
+        super::body(&res)["RoleArn"]
+    );
+}
+
+#[tokio::test]
+async fn test_success_to_resolve_role_of_user_pool_groups() {
+    super::setup().await;
+    let user_pool_id = crate::user_pools::create_user_pool().await;
+    let client_id = crate::user_pools::create_user_pool_client(&user_pool_id).await;
+    crate::user_pools::create_user(&user_pool_id, "grouped_user", "Passw0rd!").await;
+    for (group_name, precedence) in [("Admin", 1), ("Reader", 5)] {
+        let res = crate::user_pools::request(
+            "CreateGroup",
+            serde_json::json!({
+                "GroupName": group_name,
+                "Precedence": precedence,
+                "RoleArn": format!("arn:aws:iam::123456789012:role/{}", group_name),
+                "UserPoolId": user_pool_id,
+            }),
+        )
+        .await;
+        assert_eq!(200, res.status());
+        let res = crate::user_pools::request(
+            "AdminAddUserToGroup",
+            serde_json::json!({
+                "GroupName": group_name,
+                "Username": "grouped_user",
+                "UserPoolId": user_pool_id,
+            }),
+        )
+        .await;
+        assert_eq!(200, res.status());
+    }
+    let tokens =
+        crate::user_pools::sign_in(&user_pool_id, &client_id, "grouped_user", "Passw0rd!").await;
+    let id_token = tokens["IdToken"].as_str().unwrap();
+
+    let identity_pool_id = super::create_identity_pool().await;
+    let provider = format!("cognito-idp.us-east-1.amazonaws.com/{}", user_pool_id);
+    let res = super::request(
+        "SetIdentityPoolRoles",
+        serde_json::json!({
+            "IdentityPoolId": identity_pool_id,
+            "RoleMappings": {
+                format!("{}:{}", provider, client_id): {
+                    "AmbiguousRoleResolution": "Deny",
+                    "Type": "Token",
+                },
+            },
+            "Roles": { "authenticated": "arn:aws:iam::123456789012:role/Cognito_AuthRole" },
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let logins = serde_json::json!({ provider: id_token });
+    let res = super::request(
+        "GetId",
+        serde_json::json!({ "IdentityPoolId": identity_pool_id, "Logins": logins }),
+    )
+    .await;
+    let identity_id = super::body(&res)["IdentityId"].clone();
+
+    let credentials = |custom_role_arn: Option<&str>| {
+        super::request(
+            "GetCredentialsForIdentity",
+            serde_json::json!({
+                "CustomRoleArn": custom_role_arn,
+                "IdentityId": identity_id,
+                "Logins": logins,
+            }),
+        )
+    };
+    let res = credentials(None).await;
+    assert_eq!(200, res.status());
+    assert_eq!(
+        "arn:aws:iam::123456789012:role/Admin",
+        super::body(&res)["RoleArn"]
+    );
+    let res = credentials(Some("arn:aws:iam::123456789012:role/Reader")).await;
+    assert_eq!(200, res.status());
+    assert_eq!(
+        "arn:aws:iam::123456789012:role/Reader",
+        super::body(&res)["RoleArn"]
+    );
+}
@@ -0,0 +1,72 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityService.GetId")
+        .body(
+            r#"{
+            "AccountId": "123456789012",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "Logins": { 
+               "cognito-idp.us-east-1.amazonaws.com/us-east-1_example" : "id_token" 
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityService.GetId")
+        .body(
+            r#"{
+            "IdentityPoolId": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_with_identity_pool_region() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityService.GetId")
+        .body(r#"{"IdentityPoolId":"ap-northeast-1:00000000-0000-0000-0000-000000000000"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["IdentityId"]
+        .as_str()
+        .unwrap()
+        .starts_with("ap-northeast-1:"));
+}
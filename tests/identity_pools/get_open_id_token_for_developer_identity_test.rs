@@ -0,0 +1,91 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.GetOpenIdTokenForDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "Logins": { 
+               "login.example.com" : "user01" 
+            },
+            "TokenDuration": 3600
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.GetOpenIdTokenForDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "Logins": {}
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_with_signed_token() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let request = || {
+        warp::test::request()
+            .method("POST")
+            .path("/")
+            .header(
+                "x-amz-target",
+                "AWSCognitoIdentityService.GetOpenIdTokenForDeveloperIdentity",
+            )
+            .body(
+                r#"{
+                "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+                "Logins": { "login.example.com" : "user01" }
+             }"#,
+            )
+    };
+    let res = request().reply(&filter).await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let token = body["Token"].as_str().unwrap();
+    let claims = jwt::token_signer().verify(token).unwrap();
+    assert_eq!(body["IdentityId"], claims["sub"]);
+
+    let res = request().reply(&filter).await;
+    let again: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(body["IdentityId"], again["IdentityId"]);
+}
@@ -0,0 +1,111 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.LookupDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "DeveloperUserIdentifier": "user01",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "MaxResults": 10
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.LookupDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "DeveloperUserIdentifier": "",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_without_identity() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.LookupDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_unlinked_identity() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.LookupDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "IdentityId": "us-east-1:11111111-1111-1111-1111-111111111111",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
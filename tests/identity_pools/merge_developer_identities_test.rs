@@ -0,0 +1,160 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.MergeDeveloperIdentities",
+        )
+        .body(
+            r#"{
+            "DestinationUserIdentifier": "user01",
+            "DeveloperProviderName": "login.example.com",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "SourceUserIdentifier": "user02"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.MergeDeveloperIdentities",
+        )
+        .body(
+            r#"{
+            "DestinationUserIdentifier": "user01",
+            "DeveloperProviderName": "",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "SourceUserIdentifier": "user02"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_same_user_identifiers() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.MergeDeveloperIdentities",
+        )
+        .body(
+            r#"{
+            "DestinationUserIdentifier": "user01",
+            "DeveloperProviderName": "login.example.com",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "SourceUserIdentifier": "user01"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_merge_and_unlink_developer_identities() {
+    super::setup().await;
+    let identity_pool_id = "us-east-1:33333333-3333-3333-3333-333333333333";
+    let sign_in = |user_identifier: &str| {
+        super::request(
+            "GetOpenIdTokenForDeveloperIdentity",
+            serde_json::json!({
+                "IdentityPoolId": identity_pool_id,
+                "Logins": { "login.example.com": user_identifier },
+            }),
+        )
+    };
+    let lookup = |identity_id: serde_json::Value| {
+        super::request(
+            "LookupDeveloperIdentity",
+            serde_json::json!({ "IdentityId": identity_id, "IdentityPoolId": identity_pool_id }),
+        )
+    };
+    let unlink = |identity_id: &serde_json::Value| {
+        super::request(
+            "UnlinkDeveloperIdentity",
+            serde_json::json!({
+                "DeveloperProviderName": "login.example.com",
+                "DeveloperUserIdentifier": "source",
+                "IdentityId": identity_id,
+                "IdentityPoolId": identity_pool_id,
+            }),
+        )
+    };
+    let destination = super::body(&sign_in("destination").await)["IdentityId"].clone();
+    let source = super::body(&sign_in("source").await)["IdentityId"].clone();
+    assert_ne!(destination, source);
+
+    let res = super::request(
+        "MergeDeveloperIdentities",
+        serde_json::json!({
+            "DestinationUserIdentifier": "destination",
+            "DeveloperProviderName": "login.example.com",
+            "IdentityPoolId": identity_pool_id,
+            "SourceUserIdentifier": "source",
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!(destination, super::body(&res)["IdentityId"]);
+    assert_eq!(
+        destination,
+        super::body(&sign_in("source").await)["IdentityId"]
+    );
+    let res = lookup(destination.clone()).await;
+    assert_eq!(
+        serde_json::json!(["destination", "source"]),
+        super::body(&res)["DeveloperUserIdentifierList"]
+    );
+
+    let res = unlink(&source).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceConflictException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    let res = unlink(&destination).await;
+    assert_eq!(200, res.status());
+    let res = lookup(destination.clone()).await;
+    assert_eq!(
+        serde_json::json!(["destination"]),
+        super::body(&res)["DeveloperUserIdentifierList"]
+    );
+}
@@ -0,0 +1,59 @@
+use fakey_cognito::*;
+
+mod create_identity_pool_test;
+mod describe_identity_pool_test;
+mod get_credentials_for_identity_test;
+mod get_id_test;
+mod get_identity_pool_roles_test;
+mod get_open_id_token_for_developer_identity_test;
+mod get_open_id_token_test;
+mod lookup_developer_identity_test;
+mod merge_developer_identities_test;
+mod set_identity_pool_roles_test;
+mod unlink_developer_identity_test;
+mod well_known_test;
+
+pub async fn setup() {
+    opts::init_fake_opt().await;
+    let templates_opt = opts::get_opt_templates();
+    tokio::join!(
+        configs::init_config(opts::get_opt_config()),
+        templates::init_template(templates_opt.map(String::as_str)),
+        templates::init_default_template()
+    );
+}
+
+pub type Response = warp::http::Response<warp::hyper::body::Bytes>;
+
+/// Sends a request of an identity pool action to the routes.
+pub async fn request(action: &str, body: serde_json::Value) -> Response {
+    warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            format!("AWSCognitoIdentityService.{}", action),
+        )
+        .body(body.to_string())
+        .reply(&routes::user_pools_routes())
+        .await
+}
+
+/// Returns the json body of a response.
+pub fn body(res: &Response) -> serde_json::Value {
+    serde_json::from_slice(res.body()).unwrap()
+}
+
+/// Creates a stored identity pool and returns its id.
+pub async fn create_identity_pool() -> String {
+    let res = request(
+        "CreateIdentityPool",
+        serde_json::json!({
+            "AllowUnauthenticatedIdentities": true,
+            "IdentityPoolName": "identity_pool",
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    body(&res)["IdentityPoolId"].as_str().unwrap().to_string()
+}
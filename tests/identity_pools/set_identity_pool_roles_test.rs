@@ -0,0 +1,129 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.SetIdentityPoolRoles",
+        )
+        .body(
+            r#"{
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "RoleMappings": { 
+               "cognito-idp.us-east-1.amazonaws.com/us-east-1_example:client_id" : { 
+                  "AmbiguousRoleResolution": "AuthenticatedRole",
+                  "Type": "Token"
+               }
+            },
+            "Roles": { 
+               "authenticated": "arn:aws:iam::123456789012:role/Cognito_AuthRole",
+               "unauthenticated": "arn:aws:iam::123456789012:role/Cognito_UnauthRole"
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.SetIdentityPoolRoles",
+        )
+        .body(
+            r#"{
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_role_key() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.SetIdentityPoolRoles",
+        )
+        .body(
+            r#"{
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "Roles": { 
+               "admin": "arn:aws:iam::123456789012:role/Cognito_AdminRole"
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_rules_mapping_without_rules() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.SetIdentityPoolRoles",
+        )
+        .body(
+            r#"{
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000",
+            "RoleMappings": { 
+               "cognito-idp.us-east-1.amazonaws.com/us-east-1_example:client_id" : { 
+                  "AmbiguousRoleResolution": "Deny",
+                  "Type": "Rules"
+               }
+            },
+            "Roles": { 
+               "authenticated": "arn:aws:iam::123456789012:role/Cognito_AuthRole"
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
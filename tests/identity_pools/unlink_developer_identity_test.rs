@@ -0,0 +1,58 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.UnlinkDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "DeveloperProviderName": "login.example.com",
+            "DeveloperUserIdentifier": "unlinked_user",
+            "IdentityId": "us-east-1:11111111-1111-1111-1111-111111111111",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityService.UnlinkDeveloperIdentity",
+        )
+        .body(
+            r#"{
+            "DeveloperProviderName": "login.example.com",
+            "DeveloperUserIdentifier": "user01",
+            "IdentityPoolId": "us-east-1:00000000-0000-0000-0000-000000000000"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
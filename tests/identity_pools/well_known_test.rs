@@ -0,0 +1,56 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request_openid_configuration() {
+    super::setup().await;
+
+    let filter = routes::well_known_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path("/.well-known/openid-configuration")
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["jwks_uri"]
+        .as_str()
+        .unwrap()
+        .ends_with("/.well-known/jwks.json"));
+}
+
+#[tokio::test]
+async fn test_success_to_request_jwks() {
+    super::setup().await;
+
+    let filter = routes::well_known_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path("/.well-known/jwks.json")
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let key = body["keys"][0].as_object().unwrap();
+    assert_eq!("RS256", key["alg"]);
+    assert_eq!("RSA", key["kty"]);
+    assert!(key.contains_key("n"));
+    assert!(key.contains_key("e"));
+    assert!(!key.contains_key("k"));
+}
+
+#[tokio::test]
+async fn test_failure_to_request_unknown_path() {
+    super::setup().await;
+
+    let filter = routes::well_known_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path("/.well-known/unknown")
+        .reply(&filter)
+        .await;
+
+    assert_eq!(404, res.status());
+}
@@ -1 +1,2 @@
+mod identity_pools;
 mod user_pools;
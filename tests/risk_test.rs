@@ -0,0 +1,162 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+use std::path::PathBuf;
+
+const RISK_RULES_CONFIG: &str = "resources/test/test_risk_rules.yml";
+const CODE_DEBUG_HEADER: &str = "x-fakey-cognito-code";
+
+type Response = warp::http::Response<warp::hyper::body::Bytes>;
+
+async fn setup() {
+    opts::init_fake_opt().await;
+    let config = PathBuf::from(RISK_RULES_CONFIG);
+    tokio::join!(
+        configs::init_config(Some(&config)),
+        templates::init_default_template()
+    );
+}
+
+async fn request(action: &str, body: serde_json::Value) -> Response {
+    warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            format!("AWSCognitoIdentityProviderService.{}", action),
+        )
+        .body(body.to_string())
+        .reply(&routes::user_pools_routes())
+        .await
+}
+
+fn body(res: &Response) -> serde_json::Value {
+    serde_json::from_slice(res.body()).unwrap()
+}
+
+/// Creates a user pool, an app client and a user with a password, and returns the ids.
+async fn user_with_password(password: &str) -> (String, String) {
+    let res = request("CreateUserPool", serde_json::json!({ "PoolName": "pool" })).await;
+    let user_pool_id = body(&res)["UserPool"]["Id"].as_str().unwrap().to_string();
+    let res = request(
+        "CreateUserPoolClient",
+        serde_json::json!({
+            "ClientName": "client",
+            "ExplicitAuthFlows": ["ALLOW_ADMIN_USER_PASSWORD_AUTH", "ALLOW_USER_PASSWORD_AUTH"],
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    let client_id = body(&res)["UserPoolClient"]["ClientId"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let res = request(
+        "AdminCreateUser",
+        serde_json::json!({
+            "UserAttributes": [{ "Name": "phone_number", "Value": "+12065551212" }],
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = request(
+        "AdminSetUserPassword",
+        serde_json::json!({
+            "Password": password,
+            "Permanent": true,
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    (user_pool_id, client_id)
+}
+
+#[tokio::test]
+async fn test_failure_to_initiate_auth_with_compromised_password() {
+    setup().await;
+
+    let (user_pool_id, client_id) = user_with_password("Compromised0!").await;
+    let res = request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Compromised0!" },
+            "ClientId": client_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = request(
+        "AdminListUserAuthEvents",
+        serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    let event = body(&res)["AuthEvents"][0].clone();
+    assert_eq!("Fail", event["EventResponse"]);
+    assert_eq!("Block", event["EventRisk"]["RiskDecision"]);
+    assert_eq!(true, event["EventRisk"]["CompromisedCredentialsDetected"]);
+}
+
+#[tokio::test]
+async fn test_success_to_initiate_auth_with_sms_mfa_required_by_risk() {
+    setup().await;
+
+    let (_, client_id) = user_with_password("Passw0rd!").await;
+    let res = request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+            "UserContextData": { "IpAddress": "203.0.113.1" },
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let code = res
+        .headers()
+        .get(CODE_DEBUG_HEADER)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let challenge = body(&res);
+    assert_eq!("SMS_MFA", challenge["ChallengeName"]);
+    assert_eq!(
+        "+12065551212",
+        challenge["ChallengeParameters"]["CODE_DELIVERY_DESTINATION"]
+    );
+    assert!(challenge.get("AuthenticationResult").is_none());
+
+    let respond = |code: &str| {
+        request(
+            "RespondToAuthChallenge",
+            serde_json::json!({
+                "ChallengeName": "SMS_MFA",
+                "ChallengeResponses": { "USERNAME": "user", "SMS_MFA_CODE": code },
+                "ClientId": client_id,
+                "Session": challenge["Session"],
+            }),
+        )
+    };
+    let res = respond("000000x").await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "CodeMismatchException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = respond(&code).await;
+    assert_eq!(200, res.status());
+    assert!(body(&res)["AuthenticationResult"]
+        .get("AccessToken")
+        .is_some());
+}
@@ -0,0 +1,168 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+use std::path::PathBuf;
+
+const REQUEST_SIGNATURE_CONFIG: &str = "resources/test/test_request_signature.yml";
+const ADMIN_GET_USER_BODY: &str = r#"{"UserPoolId":"user_pool_id","Username":"user"}"#;
+
+async fn setup() {
+    opts::init_fake_opt().await;
+    let config = PathBuf::from(REQUEST_SIGNATURE_CONFIG);
+    tokio::join!(
+        configs::init_config(Some(&config)),
+        templates::init_default_template()
+    );
+}
+
+fn amz_date(now: chrono::DateTime<chrono::Utc>) -> String {
+    now.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn authorization(access_key_id: &str, now: chrono::DateTime<chrono::Utc>) -> String {
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}/us-east-1/cognito-idp/aws4_request, SignedHeaders=host;x-amz-date;x-amz-target, Signature=0000",
+        access_key_id,
+        now.format("%Y%m%d")
+    )
+}
+
+fn assert_error(res: &warp::http::Response<bytes::Bytes>, status: u16, error_type: &str) {
+    assert_eq!(status, res.status());
+    assert_eq!(error_type, res.headers().get("x-amzn-ErrorType").unwrap());
+}
+
+#[tokio::test]
+async fn test_failure_to_request_without_signature() {
+    setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetUser",
+        )
+        .body(ADMIN_GET_USER_BODY)
+        .reply(&filter)
+        .await;
+
+    assert_error(&res, 403, "MissingAuthenticationToken");
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_unknown_access_key() {
+    setup().await;
+
+    let now = chrono::Utc::now();
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("host", "localhost:8080")
+        .header("x-amz-date", amz_date(now))
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetUser",
+        )
+        .header("authorization", authorization("AKIDUNKNOWN", now))
+        .body(ADMIN_GET_USER_BODY)
+        .reply(&filter)
+        .await;
+
+    assert_error(&res, 403, "InvalidClientTokenId");
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_incomplete_signature() {
+    setup().await;
+
+    let now = chrono::Utc::now();
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("host", "localhost:8080")
+        .header("x-amz-date", amz_date(now))
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetUser",
+        )
+        .header(
+            "authorization",
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/cognito-idp/aws4_request",
+        )
+        .body(ADMIN_GET_USER_BODY)
+        .reply(&filter)
+        .await;
+    assert_error(&res, 400, "IncompleteSignature");
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_signature() {
+    setup().await;
+
+    let now = chrono::Utc::now();
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("host", "localhost:8080")
+        .header("x-amz-date", amz_date(now))
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetUser",
+        )
+        .header("authorization", authorization("AKIDEXAMPLE", now))
+        .body(ADMIN_GET_USER_BODY)
+        .reply(&filter)
+        .await;
+    assert_error(&res, 400, "InvalidSignatureException");
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_expired_signature() {
+    setup().await;
+
+    let signed_at = chrono::Utc::now() - chrono::Duration::seconds(901);
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("host", "localhost:8080")
+        .header("x-amz-date", amz_date(signed_at))
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminGetUser",
+        )
+        .header("authorization", authorization("AKIDEXAMPLE", signed_at))
+        .body(ADMIN_GET_USER_BODY)
+        .reply(&filter)
+        .await;
+
+    assert_error(&res, 400, "RequestExpired");
+}
+
+#[tokio::test]
+async fn test_success_to_request_public_action_without_signature() {
+    setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityService.GetId")
+        .body(r#"{"IdentityPoolId":"us-east-1:00000000-0000-0000-0000-000000000000"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(200, res.status());
+
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.GetId")
+        .body(r#"{"IdentityPoolId":"us-east-1:00000000-0000-0000-0000-000000000000"}"#)
+        .reply(&filter)
+        .await;
+    assert_error(&res, 403, "MissingAuthenticationToken");
+}
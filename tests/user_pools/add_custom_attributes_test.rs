@@ -43,3 +43,69 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_request_with_duplicate_names() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AddCustomAttributes",
+        )
+        .body(
+            r#"{"CustomAttributes":[{"Name":"tenant"},{"Name":"tenant"}],"UserPoolId":"user_pool_id"}"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_add_custom_attributes_to_stored_user_pool() {
+    super::setup().await;
+
+    let (user_pool_id, _, tokens) = super::signed_in_user().await;
+    let update = || {
+        super::request(
+            "UpdateUserAttributes",
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "UserAttributes": [{ "Name": "custom:plan", "Value": "pro" }],
+            }),
+        )
+    };
+    let res = update().await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let add = || {
+        super::request(
+            "AddCustomAttributes",
+            serde_json::json!({
+                "CustomAttributes": [{ "Name": "plan", "AttributeDataType": "String" }],
+                "UserPoolId": user_pool_id,
+            }),
+        )
+    };
+    assert_eq!(200, add().await.status());
+    assert_eq!(200, update().await.status());
+
+    let res = add().await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
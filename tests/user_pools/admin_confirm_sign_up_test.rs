@@ -43,3 +43,27 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_confirm_user_of_created_user_pool() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "SignUp",
+        serde_json::json!({ "ClientId": client_id, "Password": "Passw0rd!", "Username": "user" }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let user = serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id });
+    let res = super::request("AdminConfirmSignUp", user.clone()).await;
+    assert_eq!(200, res.status());
+    let res = super::request("AdminConfirmSignUp", user).await;
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    super::sign_in(&user_pool_id, &client_id, "user", "Passw0rd!").await;
+}
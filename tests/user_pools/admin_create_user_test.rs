@@ -23,7 +23,7 @@ async fn test_success_to_request() {
             "ForceAliasCreation": true,
             "MessageAction": "RESEND",
             "TemporaryPassword": "aaaaaa",
-            "UserAttributes": [{"Name": "string","Value": "string"}],
+            "UserAttributes": [{"Name": "name","Value": "string"}],
             "Username":"username",
             "UserPoolId":"user_pool_id",
             "ValidationData": []
@@ -55,7 +55,7 @@ async fn test_failure_to_request() {
             "ForceAliasCreation": true,
             "MessageAction": "RESEND",
             "TemporaryPassword": "aaaaaa",
-            "UserAttributes": [{"Name": "string","Value": "string"}],
+            "UserAttributes": [{"Name": "name","Value": "string"}],
             "Username":"",
             "UserPoolId":"user_pool_id",
             "ValidationData": []
@@ -70,3 +70,118 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_request_with_unknown_attribute() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminCreateUser",
+        )
+        .body(
+            r#"{
+            "UserAttributes": [{"Name": "tenant","Value": "string"}],
+            "Username":"username",
+            "UserPoolId":"user_pool_id"
+        }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_create_user_in_created_user_pool() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let create = || {
+        super::request(
+            "AdminCreateUser",
+            serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+        )
+    };
+    let res = create().await;
+    assert_eq!(200, res.status());
+    assert!(res.headers().get(super::CODE_DEBUG_HEADER).is_some());
+    let user = super::body(&res)["User"].clone();
+    assert_eq!("FORCE_CHANGE_PASSWORD", user["UserStatus"]);
+    assert_eq!("sub", user["Attributes"][0]["Name"]);
+
+    let res = create().await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UsernameExistsException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_create_user_against_stored_schema() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "Schema": [
+            { "Name": "email", "Required": true },
+            { "Name": "tenant", "Mutable": false,
+              "StringAttributeConstraints": { "MaxLength": "5" } },
+        ],
+    }))
+    .await;
+    let create = |attributes: serde_json::Value| {
+        super::request(
+            "AdminCreateUser",
+            serde_json::json!({
+                "UserAttributes": attributes,
+                "Username": "user",
+                "UserPoolId": user_pool_id,
+            }),
+        )
+    };
+    for attributes in [
+        serde_json::json!([{ "Name": "custom:tenant", "Value": "acme" }]),
+        serde_json::json!([
+            { "Name": "email", "Value": "user@example.com" },
+            { "Name": "custom:tenant", "Value": "acme-corp" },
+        ]),
+    ] {
+        let res = create(attributes).await;
+        assert_eq!(400, res.status());
+        assert_eq!(
+            "InvalidParameterException",
+            res.headers().get("x-amzn-ErrorType").unwrap()
+        );
+    }
+
+    let res = create(serde_json::json!([
+        { "Name": "email", "Value": "user@example.com" },
+        { "Name": "custom:tenant", "Value": "acme" },
+    ]))
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "AdminUpdateUserAttributes",
+        serde_json::json!({
+            "UserAttributes": [{ "Name": "custom:tenant", "Value": "other" }],
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert!(super::body(&res)["message"]
+        .as_str()
+        .unwrap()
+        .contains("changing an immutable attribute"));
+}
@@ -43,3 +43,23 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_delete_user_of_created_user_pool() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+    let user = serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id });
+    let res = super::request("AdminDeleteUser", user.clone()).await;
+    assert_eq!(200, res.status());
+
+    for action in ["AdminGetUser", "AdminDeleteUser"] {
+        let res = super::request(action, user.clone()).await;
+        assert_eq!(400, res.status());
+        assert_eq!(
+            "UserNotFoundException",
+            res.headers().get("x-amzn-ErrorType").unwrap()
+        );
+    }
+}
@@ -43,3 +43,27 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_disable_user_of_created_user_pool() {
+    super::setup().await;
+
+    let (user_pool_id, client_id, tokens) = super::signed_in_user().await;
+    let user = serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id });
+    let res = super::request("AdminDisableUser", user.clone()).await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "GlobalSignOut",
+        serde_json::json!({ "AccessToken": tokens["AccessToken"] }),
+    )
+    .await;
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::request("AdminEnableUser", user).await;
+    assert_eq!(200, res.status());
+    super::sign_in(&user_pool_id, &client_id, "user", "Passw0rd!").await;
+}
@@ -97,3 +97,233 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_refresh_token_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminInitiateAuth",
+        )
+        .body(
+            r#"{
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "AuthParameters": { 
+               "REFRESH_TOKEN" : "refresh_token" 
+            },
+            "ClientId": "string",
+            "UserPoolId": "string_09"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["AuthenticationResult"].get("IdToken").is_some());
+    assert!(body["AuthenticationResult"].get("RefreshToken").is_none());
+}
+
+#[tokio::test]
+async fn test_failure_to_refresh_token_request_without_refresh_token() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminInitiateAuth",
+        )
+        .body(
+            r#"{
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "ClientId": "string",
+            "UserPoolId": "string_09"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_sign_in_user_of_created_user_pool() {
+    super::setup().await;
+
+    let (user_pool_id, client_id, tokens) = super::signed_in_user().await;
+    let claims = jwt::token_claims(tokens["AccessToken"].as_str().unwrap()).unwrap();
+    assert_eq!("access", claims["token_use"]);
+    assert_eq!(client_id.as_str(), claims["client_id"]);
+    assert_eq!(user_pools::user_pool_issuer(&user_pool_id), claims["iss"]);
+
+    let res = super::request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "AuthParameters": { "REFRESH_TOKEN": "unknown" },
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_sign_in_unknown_user_of_created_user_pool() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "nobody", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UserNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_sign_in_with_expired_temporary_password() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "Policies": { "PasswordPolicy": { "TemporaryPasswordValidityDays": 0 } },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "AdminCreateUser",
+        serde_json::json!({
+            "TemporaryPassword": "Passw0rd!",
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = super::request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_success_to_sign_in_by_email_of_username_attributes() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "UsernameAttributes": ["email"],
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "AdminCreateUser",
+        serde_json::json!({
+            "Username": "User@example.com",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let username = super::body(&res)["User"]["Username"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_ne!("User@example.com", username);
+    let res = super::request(
+        "AdminSetUserPassword",
+        serde_json::json!({
+            "Password": "Passw0rd!",
+            "Permanent": true,
+            "Username": "user@example.com",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let tokens = super::sign_in(&user_pool_id, &client_id, "user@example.com", "Passw0rd!").await;
+    let res = super::request(
+        "GetUser",
+        serde_json::json!({ "AccessToken": tokens["AccessToken"] }),
+    )
+    .await;
+    assert_eq!(username, super::body(&res)["Username"]);
+}
+
+#[tokio::test]
+async fn test_failure_to_create_user_with_existing_alias() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "AliasAttributes": ["email"],
+    }))
+    .await;
+    let create = |username: &str, force: bool| {
+        super::request(
+            "AdminCreateUser",
+            serde_json::json!({
+                "ForceAliasCreation": force,
+                "UserAttributes": [
+                    { "Name": "email", "Value": "user@example.com" },
+                    { "Name": "email_verified", "Value": "true" },
+                ],
+                "Username": username,
+                "UserPoolId": user_pool_id,
+            }),
+        )
+    };
+    assert_eq!(200, create("first", false).await.status());
+    let res = create("second", false).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "AliasExistsException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    assert_eq!(200, create("second", true).await.status());
+
+    let res = super::request(
+        "AdminGetUser",
+        serde_json::json!({ "Username": "user@example.com", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!("second", super::body(&res)["Username"]);
+}
@@ -18,7 +18,7 @@ async fn test_success_to_request() {
             "DestinationUser": { 
                "ProviderAttributeName": "string",
                "ProviderAttributeValue": "string",
-               "ProviderName": "string"
+               "ProviderName": "Cognito"
             },
             "SourceUser": { 
                "ProviderAttributeName": "string",
@@ -71,3 +71,99 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_request_with_non_cognito_destination_user() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminLinkProviderForUser",
+        )
+        .body(
+            r#"{
+            "DestinationUser": { 
+               "ProviderAttributeValue": "username",
+               "ProviderName": "Google"
+            },
+            "SourceUser": { 
+               "ProviderAttributeName": "Cognito_Subject",
+               "ProviderAttributeValue": "123456789",
+               "ProviderName": "Facebook"
+            },
+            "UserPoolId": "string"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_link_and_disable_provider() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+    let google_user = serde_json::json!({
+        "ProviderAttributeName": "Cognito_Subject",
+        "ProviderAttributeValue": "1234",
+        "ProviderName": "Google"
+    });
+    let get_user = |username: &str| {
+        super::request(
+            "AdminGetUser",
+            serde_json::json!({ "Username": username, "UserPoolId": user_pool_id }),
+        )
+    };
+
+    let res = super::request(
+        "AdminLinkProviderForUser",
+        serde_json::json!({
+            "DestinationUser": {
+                "ProviderAttributeValue": "user",
+                "ProviderName": "Cognito"
+            },
+            "SourceUser": google_user,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = get_user("Google_1234").await;
+    assert_eq!(200, res.status());
+    let user = super::body(&res);
+    assert_eq!("user", user["Username"]);
+    let identities = user["UserAttributes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|a| a["Name"] == "identities")
+        .map(|a| a["Value"].as_str().unwrap().to_string())
+        .unwrap();
+    let identities: serde_json::Value = serde_json::from_str(&identities).unwrap();
+    assert_eq!("Google", identities[0]["providerName"]);
+    assert_eq!("1234", identities[0]["userId"]);
+
+    let res = super::request(
+        "AdminDisableProviderForUser",
+        serde_json::json!({ "User": google_user, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = get_user("Google_1234").await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UserNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
@@ -16,7 +16,6 @@ async fn test_success_to_request() {
         .body(
             r#"{
             "Limit": 10,
-            "PaginationToken": "string",
             "Username": "string",
             "UserPoolId": "string_00"
          }"#,
@@ -25,7 +24,8 @@ async fn test_success_to_request() {
         .await;
 
     assert_eq!(200, res.status());
-    assert!(!res.body().is_empty());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body.get("PaginationToken").is_none());
 }
 
 #[tokio::test]
@@ -57,3 +57,33 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_token() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminListDevices",
+        )
+        .body(
+            r#"{
+            "Limit": 10,
+            "PaginationToken": "string",
+            "Username": "string",
+            "UserPoolId": "string_00"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
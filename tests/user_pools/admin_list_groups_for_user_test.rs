@@ -16,7 +16,6 @@ async fn test_success_to_request() {
         .body(
             r#"{
             "Limit": 10,
-            "NextToken": "string",
             "Username": "string",
             "UserPoolId": "string_00"
          }"#,
@@ -25,7 +24,8 @@ async fn test_success_to_request() {
         .await;
 
     assert_eq!(200, res.status());
-    assert!(!res.body().is_empty());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body.get("NextToken").is_none());
 }
 
 #[tokio::test]
@@ -57,3 +57,84 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_token() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminListGroupsForUser",
+        )
+        .body(
+            r#"{
+            "Limit": 10,
+            "NextToken": "string",
+            "Username": "string",
+            "UserPoolId": "string_00"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_list_stored_groups_of_user() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+    let res = super::request(
+        "CreateGroup",
+        serde_json::json!({ "GroupName": "admins", "Precedence": 1, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!("admins", super::body(&res)["Group"]["GroupName"]);
+    let membership = serde_json::json!({
+        "GroupName": "admins",
+        "Username": "user",
+        "UserPoolId": user_pool_id,
+    });
+    let res = super::request("AdminAddUserToGroup", membership.clone()).await;
+    assert_eq!(200, res.status());
+
+    let list = || {
+        super::request(
+            "AdminListGroupsForUser",
+            serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+        )
+    };
+    let groups = super::body(&list().await)["Groups"].clone();
+    assert_eq!(1, groups.as_array().unwrap().len());
+    assert_eq!("admins", groups[0]["GroupName"]);
+    assert_eq!(1, groups[0]["Precedence"]);
+
+    let res = super::request("AdminRemoveUserFromGroup", membership).await;
+    assert_eq!(200, res.status());
+    assert_eq!(serde_json::json!([]), super::body(&list().await)["Groups"]);
+
+    let res = super::request(
+        "AdminAddUserToGroup",
+        serde_json::json!({
+            "GroupName": "unknown",
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
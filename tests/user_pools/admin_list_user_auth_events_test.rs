@@ -16,7 +16,6 @@ async fn test_success_to_request() {
         .body(
             r#"{
             "MaxResults": 10,
-            "NextToken": "string",
             "Username": "string",
             "UserPoolId": "string_00"
          }"#,
@@ -25,7 +24,8 @@ async fn test_success_to_request() {
         .await;
 
     assert_eq!(200, res.status());
-    assert!(!res.body().is_empty());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body.get("NextToken").is_none());
 }
 
 #[tokio::test]
@@ -57,3 +57,72 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_token() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.AdminListUserAuthEvents",
+        )
+        .body(
+            r#"{
+            "MaxResults": 10,
+            "NextToken": "string",
+            "Username": "string",
+            "UserPoolId": "string_00"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_list_recorded_auth_events() {
+    super::setup().await;
+    let (user_pool_id, client_id, _) = super::signed_in_user().await;
+
+    let res = super::request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "wrong" },
+            "ClientId": client_id,
+            "ContextData": {
+                "HttpHeaders": [{ "headerName": "User-Agent", "headerValue": "Firefox" }],
+                "IpAddress": "192.0.2.1",
+                "ServerName": "example.com",
+                "ServerPath": "/login"
+            },
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+
+    let res = super::request(
+        "AdminListUserAuthEvents",
+        serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let events = super::body(&res)["AuthEvents"].clone();
+    assert_eq!(2, events.as_array().unwrap().len());
+    assert_eq!("SignIn", events[0]["EventType"]);
+    assert_eq!("Fail", events[0]["EventResponse"]);
+    assert_eq!("192.0.2.1", events[0]["EventContextData"]["IpAddress"]);
+    assert_eq!("Firefox", events[0]["EventContextData"]["DeviceName"]);
+    assert_eq!("NoRisk", events[0]["EventRisk"]["RiskDecision"]);
+    assert_eq!("Pass", events[1]["EventResponse"]);
+}
@@ -99,3 +99,59 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_change_temporary_password_of_created_user() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "AdminCreateUser",
+        serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    let temporary_password = res
+        .headers()
+        .get(super::CODE_DEBUG_HEADER)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let res = super::request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": temporary_password },
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    let body = super::body(&res);
+    assert_eq!("NEW_PASSWORD_REQUIRED", body["ChallengeName"]);
+
+    let res = super::request(
+        "AdminRespondToAuthChallenge",
+        serde_json::json!({
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
+            "ChallengeResponses": { "USERNAME": "user", "NEW_PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+            "Session": body["Session"],
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert!(super::body(&res)["AuthenticationResult"]
+        .get("IdToken")
+        .is_some());
+
+    let res = super::request(
+        "AdminGetUser",
+        serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!("CONFIRMED", super::body(&res)["UserStatus"]);
+}
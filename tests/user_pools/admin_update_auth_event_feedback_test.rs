@@ -57,3 +57,38 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_update_feedback_of_auth_event() {
+    super::setup().await;
+    let (user_pool_id, _, _) = super::signed_in_user().await;
+    let list = || {
+        super::request(
+            "AdminListUserAuthEvents",
+            serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+        )
+    };
+    let event_id = super::body(&list().await)["AuthEvents"][0]["EventId"].clone();
+    let feedback = |event_id: serde_json::Value| {
+        super::request(
+            "AdminUpdateAuthEventFeedback",
+            serde_json::json!({
+                "EventId": event_id,
+                "FeedbackValue": "Invalid",
+                "Username": "user",
+                "UserPoolId": user_pool_id,
+            }),
+        )
+    };
+
+    let res = feedback("unknown".into()).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    assert_eq!(200, feedback(event_id).await.status());
+    let event = super::body(&list().await)["AuthEvents"][0].clone();
+    assert_eq!("Invalid", event["EventFeedback"]["FeedbackValue"]);
+}
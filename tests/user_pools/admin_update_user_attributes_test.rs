@@ -20,7 +20,7 @@ async fn test_success_to_request() {
             },
             "UserAttributes": [ 
                { 
-                  "Name": "string",
+                  "Name": "name",
                   "Value": "string"
                }
             ],
@@ -54,7 +54,7 @@ async fn test_failure_to_request() {
             },
             "UserAttributes": [ 
                { 
-                  "Name": "string",
+                  "Name": "name",
                   "Value": "string"
                }
             ],
@@ -71,3 +71,56 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_keep_old_email_until_verified() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "AutoVerifiedAttributes": ["email"],
+        "UserAttributeUpdateSettings": {
+            "AttributesRequireVerificationBeforeUpdate": ["email"]
+        },
+    }))
+    .await;
+    let (_, _, tokens) = super::signed_in_user_of(user_pool_id.clone()).await;
+    let res = super::request(
+        "AdminUpdateUserAttributes",
+        serde_json::json!({
+            "UserAttributes": [{ "Name": "email", "Value": "new@example.com" }],
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let code = res.headers().get(super::CODE_DEBUG_HEADER).unwrap().clone();
+
+    let email = || async {
+        let res = super::request(
+            "GetUser",
+            serde_json::json!({ "AccessToken": tokens["AccessToken"] }),
+        )
+        .await;
+        super::body(&res)["UserAttributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["Name"] == "email")
+            .map(|a| a["Value"].clone())
+            .unwrap()
+    };
+    assert_eq!("user@example.com", email().await);
+
+    let res = super::request(
+        "VerifyUserAttribute",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "AttributeName": "email",
+            "Code": code.to_str().unwrap(),
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!("new@example.com", email().await);
+}
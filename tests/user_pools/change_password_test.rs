@@ -4,6 +4,7 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let (_, _, tokens) = super::signed_in_user().await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,7 +14,14 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ChangePassword",
         )
-        .body(r#"{"AccessToken":"access_token01234","PreviousPassword":"previous_password","ProposedPassword":"proposed_password"}"#)
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "PreviousPassword": "Passw0rd!",
+                "ProposedPassword": "Pr0posed_password",
+            })
+            .to_string(),
+        )
         .reply(&filter)
         .await;
 
@@ -43,3 +51,29 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_change_password_with_wrong_previous_password() {
+    super::setup().await;
+
+    let (user_pool_id, client_id, tokens) = super::signed_in_user().await;
+    let change = |previous_password: &str| {
+        super::request(
+            "ChangePassword",
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "PreviousPassword": previous_password,
+                "ProposedPassword": "NewPassw0rd!",
+            }),
+        )
+    };
+    let res = change("wrong").await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    assert_eq!(200, change("Passw0rd!").await.status());
+    super::sign_in(&user_pool_id, &client_id, "user", "NewPassw0rd!").await;
+}
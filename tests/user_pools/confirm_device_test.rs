@@ -4,28 +4,21 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let (_, _, tokens) = super::signed_in_device_user().await;
 
-    let filter = routes::user_pools_routes();
-    let res = warp::test::request()
-        .method("POST")
-        .path("/")
-        .header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.ConfirmDevice",
-        )
-        .body(
-            r#"{
-            "AccessToken": "access_token",
-            "DeviceKey": "device_key",
+    let res = super::request(
+        "ConfirmDevice",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "DeviceKey": tokens["NewDeviceMetadata"]["DeviceKey"],
             "DeviceName": "device_name",
-            "DeviceSecretVerifierConfig": { 
-                "PasswordVerifier": "password_verifier",
-                "Salt": "salt"
-            }
-        }"#,
-        )
-        .reply(&filter)
-        .await;
+            "DeviceSecretVerifierConfig": {
+                "PasswordVerifier": "cGFzc3dvcmRfdmVyaWZpZXI=",
+                "Salt": "c2FsdA=="
+            },
+        }),
+    )
+    .await;
 
     assert_eq!(200, res.status());
     assert!(!res.body().is_empty());
@@ -69,3 +69,94 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_store_identity_provider() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let create = |provider_name: &str, idp_identifier: &str| {
+        super::request(
+            "CreateIdentityProvider",
+            serde_json::json!({
+                "AttributeMapping": { "email": "email" },
+                "IdpIdentifiers": [idp_identifier],
+                "ProviderDetails": { "client_id": "client" },
+                "ProviderName": provider_name,
+                "ProviderType": "Google",
+                "UserPoolId": user_pool_id,
+            }),
+        )
+    };
+
+    let res = create("Google", "google.com").await;
+    assert_eq!(200, res.status());
+    assert_eq!(
+        serde_json::json!({ "email": "email" }),
+        super::body(&res)["IdentityProvider"]["AttributeMapping"]
+    );
+
+    let res = create("Google", "example.com").await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "DuplicateProviderException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::request(
+        "UpdateIdentityProvider",
+        serde_json::json!({
+            "IdpIdentifiers": ["example.com"],
+            "ProviderName": "Google",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let provider = &super::body(&res)["IdentityProvider"];
+    assert_eq!(
+        serde_json::json!({ "email": "email" }),
+        provider["AttributeMapping"]
+    );
+    assert_eq!(
+        serde_json::json!(["example.com"]),
+        provider["IdpIdentifiers"]
+    );
+
+    let res = super::request(
+        "GetIdentityProviderByIdentifier",
+        serde_json::json!({ "IdpIdentifier": "example.com", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!(
+        "Google",
+        super::body(&res)["IdentityProvider"]["ProviderName"]
+    );
+
+    let res = super::request(
+        "ListIdentityProviders",
+        serde_json::json!({ "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let providers = &super::body(&res)["Providers"];
+    assert_eq!(1, providers.as_array().unwrap().len());
+    assert_eq!("Google", providers[0]["ProviderType"]);
+
+    let res = super::request(
+        "DeleteIdentityProvider",
+        serde_json::json!({ "ProviderName": "Google", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = super::request(
+        "DescribeIdentityProvider",
+        serde_json::json!({ "ProviderName": "Google", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
@@ -67,3 +67,69 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_allow_scopes_of_stored_resource_server() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let create = || {
+        super::request(
+            "CreateResourceServer",
+            serde_json::json!({
+                "Identifier": "https://api.example.com",
+                "Name": "api",
+                "Scopes": [{ "ScopeDescription": "read", "ScopeName": "read" }],
+                "UserPoolId": user_pool_id,
+            }),
+        )
+    };
+    let create_client = |scope: &str| {
+        super::request(
+            "CreateUserPoolClient",
+            serde_json::json!({
+                "AllowedOAuthScopes": ["openid", scope],
+                "ClientName": "client",
+                "UserPoolId": user_pool_id,
+            }),
+        )
+    };
+
+    let res = create().await;
+    assert_eq!(200, res.status());
+    assert_eq!(
+        "read",
+        super::body(&res)["ResourceServer"]["Scopes"][0]["ScopeName"]
+    );
+    let res = create().await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = create_client("https://api.example.com/read").await;
+    assert_eq!(200, res.status());
+    let res = create_client("https://api.example.com/write").await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ScopeDoesNotExistException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::request(
+        "DeleteResourceServer",
+        serde_json::json!({ "Identifier": "https://api.example.com", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = super::request(
+        "DescribeResourceServer",
+        serde_json::json!({ "Identifier": "https://api.example.com", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
@@ -109,3 +109,88 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_failure_to_request_with_unknown_scope() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPoolClient",
+        )
+        .body(
+            r#"{
+            "AllowedOAuthScopes": [ "openid", "admin" ],
+            "ClientName": "string",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ScopeDoesNotExistException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_create_client_of_created_user_pool() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::request(
+        "CreateUserPoolClient",
+        serde_json::json!({
+            "ClientName": "client",
+            "GenerateSecret": true,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let client = super::body(&res)["UserPoolClient"].clone();
+    assert_eq!("client", client["ClientName"]);
+    assert_eq!(user_pool_id.as_str(), client["UserPoolId"]);
+    assert_eq!(26, client["ClientId"].as_str().unwrap().len());
+    assert!(client["ClientSecret"].as_str().is_some());
+}
+
+#[tokio::test]
+async fn test_failure_to_create_client_of_created_user_pool_with_secret_hash() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::request(
+        "CreateUserPoolClient",
+        serde_json::json!({
+            "ClientName": "client",
+            "ExplicitAuthFlows": ["ALLOW_USER_PASSWORD_AUTH"],
+            "GenerateSecret": true,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    let client_id = super::body(&res)["UserPoolClient"]["ClientId"].clone();
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+
+    let res = super::request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
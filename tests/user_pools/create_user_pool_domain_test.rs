@@ -0,0 +1,120 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPoolDomain",
+        )
+        .body(
+            r#"{
+            "CustomDomainConfig": { 
+               "CertificateArn": "arn:aws:acm:us-east-1:123456789012:certificate/certificate_id"
+            },
+            "Domain": "auth.example.com",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPoolDomain",
+        )
+        .body(
+            r#"{
+            "Domain": "",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_route_host_of_stored_domain() {
+    super::setup().await;
+    let (user_pool_id, _, tokens) = super::signed_in_user().await;
+    let other_user_pool_id = super::create_user_pool().await;
+    let domain = format!(
+        "auth-{}",
+        user_pool_id.split('_').next_back().unwrap().to_lowercase()
+    );
+    let create = |user_pool_id: &str| {
+        super::request(
+            "CreateUserPoolDomain",
+            serde_json::json!({ "Domain": domain, "UserPoolId": user_pool_id }),
+        )
+    };
+
+    let res = create(&user_pool_id).await;
+    assert_eq!(200, res.status());
+    let cloud_front_domain = super::body(&res)["CloudFrontDomain"].clone();
+    let res = create(&other_user_pool_id).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::request(
+        "DescribeUserPoolDomain",
+        serde_json::json!({ "Domain": domain }),
+    )
+    .await;
+    let description = &super::body(&res)["DomainDescription"];
+    assert_eq!(user_pool_id, description["UserPoolId"]);
+    assert_eq!(cloud_front_domain, description["CloudFrontDomain"]);
+    assert_eq!("ACTIVE", description["Status"]);
+
+    let host = format!("{}.auth.us-east-1.amazoncognito.com:8080", domain);
+    let filter = routes::hosted_ui_routes();
+    let user_info = |access_token: &str| {
+        warp::test::request()
+            .method("GET")
+            .path("/oauth2/userInfo")
+            .header("host", host.as_str())
+            .header("authorization", format!("Bearer {}", access_token))
+            .reply(&filter)
+    };
+    let res = user_info(tokens["AccessToken"].as_str().unwrap()).await;
+    assert_eq!(200, res.status());
+    assert_eq!("user", super::body(&res)["username"]);
+    let res = user_info("invalid").await;
+    assert_eq!(401, res.status());
+
+    let res = super::request(
+        "DeleteUserPoolDomain",
+        serde_json::json!({ "Domain": domain, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = user_info(tokens["AccessToken"].as_str().unwrap()).await;
+    assert_eq!(404, res.status());
+}
@@ -104,7 +104,6 @@ async fn test_success_to_request() {
                "SnsCallerArn": "string"
             },
             "SmsVerificationMessage": "{####}",
-            "UsernameAttributes": [ "phone_number" ],
             "UsernameConfiguration": { 
                "CaseSensitive": false
             },
@@ -265,3 +264,129 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_success_to_request_with_username_attributes() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPool",
+        )
+        .body(
+            r#"{
+            "PoolName": "string",
+            "UsernameAttributes": [ "email" ],
+            "UsernameConfiguration": { 
+               "CaseSensitive": true
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let user_pool = &body["UserPool"];
+    assert_eq!(serde_json::json!([]), user_pool["AliasAttributes"]);
+    assert_eq!(
+        serde_json::json!(["email"]),
+        user_pool["UsernameAttributes"]
+    );
+    assert_eq!(true, user_pool["UsernameConfiguration"]["CaseSensitive"]);
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_alias_and_username_attributes() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPool",
+        )
+        .body(
+            r#"{
+            "AliasAttributes": [ "preferred_username" ],
+            "PoolName": "string",
+            "UsernameAttributes": [ "email" ]
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_user_pool_tags() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.CreateUserPool",
+        )
+        .body(
+            r#"{
+            "PoolName": "string",
+            "UserPoolTags": { "aws:env": "dev" }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UserPoolTaggingException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_describe_created_user_pool() {
+    super::setup().await;
+
+    let res = super::request(
+        "CreateUserPool",
+        serde_json::json!({ "PoolName": "pool", "MfaConfiguration": "OFF" }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let user_pool = super::body(&res)["UserPool"].clone();
+    let user_pool_id = user_pool["Id"].as_str().unwrap();
+    assert!(user_pool_id.starts_with("us-east-1_"));
+    assert_eq!("pool", user_pool["Name"]);
+    assert_eq!(
+        format!(
+            "arn:aws:cognito-idp:us-east-1:123456789012:userpool/{}",
+            user_pool_id
+        ),
+        user_pool["Arn"]
+    );
+
+    let res = super::request(
+        "DescribeUserPool",
+        serde_json::json!({ "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let described = super::body(&res)["UserPool"].clone();
+    assert_eq!(user_pool["Id"], described["Id"]);
+    assert_eq!("OFF", described["MfaConfiguration"]);
+    assert_eq!(0, described["EstimatedNumberOfUsers"]);
+}
@@ -0,0 +1,80 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let (_, _, tokens) = super::signed_in_user().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUser",
+        )
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUser",
+        )
+        .body(r#"{"AccessToken":""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_delete_signed_in_user() {
+    super::setup().await;
+
+    let (user_pool_id, _, tokens) = super::signed_in_user().await;
+    let delete = || {
+        super::request(
+            "DeleteUser",
+            serde_json::json!({ "AccessToken": tokens["AccessToken"] }),
+        )
+    };
+    assert_eq!(200, delete().await.status());
+
+    let res = delete().await;
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    let res = super::request(
+        "AdminGetUser",
+        serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(
+        "UserNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
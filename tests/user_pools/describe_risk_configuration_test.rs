@@ -0,0 +1,125 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeRiskConfiguration",
+        )
+        .body(
+            r#"{
+            "ClientId": "string",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeRiskConfiguration",
+        )
+        .body(
+            r#"{
+            "ClientId": "string",
+            "UserPoolId": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_describe_stored_risk_configuration() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "UserPoolAddOns": { "AdvancedSecurityMode": "ENFORCED" }
+    }))
+    .await;
+    let (user_pool_id, client_id, _) = super::signed_in_user_of(user_pool_id).await;
+
+    let res = super::request(
+        "SetRiskConfiguration",
+        serde_json::json!({
+            "RiskExceptionConfiguration": { "BlockedIPRangeList": ["192.0.2.0/24"] },
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "DescribeRiskConfiguration",
+        serde_json::json!({ "ClientId": client_id, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!(
+        serde_json::json!(["192.0.2.0/24"]),
+        super::body(&res)["RiskConfiguration"]["RiskExceptionConfiguration"]["BlockedIPRangeList"]
+    );
+
+    let res = super::request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+            "ContextData": {
+                "HttpHeaders": [],
+                "IpAddress": "192.0.2.1",
+                "ServerName": "example.com",
+                "ServerPath": "/login"
+            },
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_describe_risk_configuration_of_unknown_client() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let res = super::request(
+        "DescribeRiskConfiguration",
+        serde_json::json!({ "ClientId": "unknown", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
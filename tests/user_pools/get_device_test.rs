@@ -0,0 +1,48 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let (_, _, tokens) = super::signed_in_device_user().await;
+
+    let res = super::request(
+        "GetDevice",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "DeviceKey": tokens["NewDeviceMetadata"]["DeviceKey"],
+        }),
+    )
+    .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetDevice",
+        )
+        .body(
+            r#"{
+            "AccessToken": "access_token01234",
+            "DeviceKey": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,102 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let (_, _, tokens) = super::signed_in_user().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
+        )
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "AttributeName": "email",
+                "ClientMetadata": { "string": "string" },
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+    assert_eq!(
+        6,
+        res.headers().get(super::CODE_DEBUG_HEADER).unwrap().len()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
+        )
+        .body(
+            r#"{
+            "AccessToken": "access_token01234",
+            "AttributeName": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_phone_number() {
+    super::setup().await;
+    let (user_pool_id, _, tokens) = super::signed_in_user().await;
+    let res = super::request(
+        "AdminUpdateUserAttributes",
+        serde_json::json!({
+            "UserAttributes": [{ "Name": "phone_number", "Value": "+15555550100" }],
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
+        )
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "AttributeName": "phone_number",
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("phone_number", body["CodeDeliveryDetails"]["AttributeName"]);
+    assert_eq!("SMS", body["CodeDeliveryDetails"]["DeliveryMedium"]);
+    assert_eq!("+15555550100", body["CodeDeliveryDetails"]["Destination"]);
+}
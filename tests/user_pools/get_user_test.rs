@@ -0,0 +1,103 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let (_, _, tokens) = super::signed_in_user().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser")
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser")
+        .body(r#"{"AccessToken":""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_access_token() {
+    super::setup().await;
+
+    let res = super::request(
+        "GetUser",
+        serde_json::json!({ "AccessToken": "access_token01234" }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_success_to_get_updated_attributes() {
+    super::setup().await;
+
+    let (_, _, tokens) = super::signed_in_user().await;
+    let res = super::request(
+        "UpdateUserAttributes",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "UserAttributes": [{ "Name": "name", "Value": "User" }],
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = super::request(
+        "DeleteUserAttributes",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "UserAttributeNames": ["email"],
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "GetUser",
+        serde_json::json!({ "AccessToken": tokens["AccessToken"] }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body = super::body(&res);
+    assert_eq!("user", body["Username"]);
+    let names = body["UserAttributes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| (a["Name"].as_str().unwrap(), a["Value"].as_str().unwrap()))
+        .collect::<Vec<_>>();
+    assert!(names.contains(&("name", "User")));
+    assert!(!names.iter().any(|(name, _)| *name == "email"));
+}
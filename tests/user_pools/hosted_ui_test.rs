@@ -0,0 +1,170 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_sign_in_again_after_logout() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (host, client_id) = super::create_hosted_ui_client(&user_pool_id).await;
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+
+    let filter = routes::hosted_ui_routes();
+    let query = format!(
+        "client_id={}&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&response_type=token",
+        client_id
+    );
+    let authorize = |cookie: Option<String>| {
+        let request = warp::test::request()
+            .method("GET")
+            .path(&format!("/oauth2/authorize?{}", query))
+            .header("host", host.as_str());
+        match cookie {
+            Some(cookie) => request.header("cookie", cookie),
+            None => request,
+        }
+        .reply(&filter)
+    };
+    let res = authorize(None).await;
+    assert_eq!(302, res.status());
+    assert_eq!(
+        format!("/login?{}", query),
+        res.headers().get("location").unwrap().to_str().unwrap()
+    );
+
+    let res = warp::test::request()
+        .method("POST")
+        .path(&format!("/login?{}", query))
+        .header("host", host.as_str())
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body("username=user&password=Passw0rd%21")
+        .reply(&filter)
+        .await;
+    assert_eq!(302, res.status());
+    let set_cookie = res.headers().get("set-cookie").unwrap().to_str().unwrap();
+    let cookie = set_cookie.split(';').next().unwrap().to_string();
+    assert!(cookie.starts_with("cognito="));
+
+    let res = authorize(Some(cookie.clone())).await;
+    assert_eq!(302, res.status());
+    let location = res.headers().get("location").unwrap().to_str().unwrap();
+    assert!(location.starts_with("https://example.com/callback#id_token="));
+
+    let logout = |logout_uri: &str| {
+        warp::test::request()
+            .method("GET")
+            .path(&format!(
+                "/logout?client_id={}&logout_uri={}",
+                client_id, logout_uri
+            ))
+            .header("host", host.as_str())
+            .header("cookie", cookie.as_str())
+            .reply(&filter)
+    };
+    let res = logout("https%3A%2F%2Fexample.org%2F").await;
+    assert_eq!(400, res.status());
+    let page = String::from_utf8_lossy(res.body()).into_owned();
+    assert!(page.contains("An error was encountered with the requested page."));
+    assert!(page.contains("redirect_mismatch"));
+
+    let res = logout("https%3A%2F%2Fexample.com%2Fsigned-out").await;
+    assert_eq!(302, res.status());
+    assert_eq!(
+        "https://example.com/signed-out",
+        res.headers().get("location").unwrap()
+    );
+    assert!(res
+        .headers()
+        .get("set-cookie")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("cognito=;"));
+
+    let res = authorize(Some(cookie)).await;
+    assert_eq!(302, res.status());
+    assert_eq!(
+        format!("/login?{}", query),
+        res.headers().get("location").unwrap().to_str().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_success_to_sign_in_through_identity_provider() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (host, client_id) = super::create_hosted_ui_client(&user_pool_id).await;
+    let (idp_address, idp_server) =
+        warp::serve(routes::mock_idp_routes()).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(idp_server);
+    let idp_url = format!("http://{}/mock-idp", idp_address);
+    let res = super::request(
+        "CreateIdentityProvider",
+        serde_json::json!({
+            "AttributeMapping": { "email": "email", "name": "name" },
+            "ProviderDetails": {
+                "authorize_scopes": "openid email",
+                "authorize_url": format!("{}/authorize", idp_url),
+                "client_id": "idp-client",
+                "oidc_issuer": mock_idp::issuer(),
+                "token_url": format!("{}/token", idp_url),
+            },
+            "ProviderName": "Mock",
+            "ProviderType": "OIDC",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let filter = routes::hosted_ui_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path(&format!(
+            "/oauth2/authorize?identity_provider=Mock&client_id={}&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&response_type=token&state=xyz",
+            client_id
+        ))
+        .header("host", host.as_str())
+        .reply(&filter)
+        .await;
+    assert_eq!(302, res.status());
+    let location = res.headers().get("location").unwrap().to_str().unwrap();
+    assert!(location.starts_with(&format!("{}/authorize?client_id=idp-client&", idp_url)));
+
+    let res = warp::test::request()
+        .method("POST")
+        .path(&location[location.find("/mock-idp/").unwrap()..])
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body("sub=1234&email=user%40example.com&name=User")
+        .reply(&routes::mock_idp_routes())
+        .await;
+    assert_eq!(302, res.status());
+    let location = res.headers().get("location").unwrap().to_str().unwrap();
+    let idp_response = format!("http://{}/oauth2/idpresponse?", host);
+    assert!(location.starts_with(&idp_response));
+
+    let res = warp::test::request()
+        .method("GET")
+        .path(&location[location.find("/oauth2/").unwrap()..])
+        .header("host", host.as_str())
+        .reply(&filter)
+        .await;
+    assert_eq!(302, res.status());
+    let location = res.headers().get("location").unwrap().to_str().unwrap();
+    assert!(location.starts_with("https://example.com/callback#id_token="));
+    assert!(location.ends_with("&state=xyz"));
+    assert!(res.headers().get("set-cookie").is_some());
+
+    let res = super::request(
+        "AdminGetUser",
+        serde_json::json!({ "UserPoolId": user_pool_id, "Username": "Mock_1234" }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let user = super::body(&res);
+    assert_eq!("EXTERNAL_PROVIDER", user["UserStatus"]);
+    let attributes = user["UserAttributes"].as_array().unwrap();
+    assert!(
+        attributes.contains(&serde_json::json!({ "Name": "email", "Value": "user@example.com" }))
+    );
+    assert!(attributes.contains(&serde_json::json!({ "Name": "name", "Value": "User" })));
+}
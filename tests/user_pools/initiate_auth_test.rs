@@ -0,0 +1,206 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.InitiateAuth",
+        )
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { 
+               "USERNAME" : "user",
+               "PASSWORD" : "password"
+            },
+            "ClientId": "string",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "UserContextData": { 
+               "EncodedData": "string"
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.InitiateAuth",
+        )
+        .body(
+            r#"{
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "ClientId": "string"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_sign_in_and_refresh_tokens() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+
+    let res = super::request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let result = super::body(&res)["AuthenticationResult"].clone();
+    let claims = jwt::token_claims(result["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!("user", claims["cognito:username"]);
+    assert_eq!("user@example.com", claims["email"]);
+    assert_eq!(client_id.as_str(), claims["aud"]);
+    assert_eq!(3600, result["ExpiresIn"]);
+
+    let res = super::request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "AuthParameters": { "REFRESH_TOKEN": result["RefreshToken"] },
+            "ClientId": client_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let refreshed = super::body(&res)["AuthenticationResult"].clone();
+    assert!(refreshed.get("AccessToken").is_some());
+    assert!(refreshed.get("RefreshToken").is_none());
+}
+
+#[tokio::test]
+async fn test_failure_to_sign_in_with_wrong_password() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+
+    let res = super::request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "wrong" },
+            "ClientId": client_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_sign_in_by_disabled_auth_flow() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::request(
+        "CreateUserPoolClient",
+        serde_json::json!({ "ClientName": "client", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    let client_id = super::body(&res)["UserPoolClient"]["ClientId"].clone();
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+
+    let res = super::request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_sign_in_from_blocked_ip_address() {
+    super::setup().await;
+
+    let (user_pool_id, client_id, _) = super::signed_in_user().await;
+    let res = super::request(
+        "SetRiskConfiguration",
+        serde_json::json!({
+            "ClientId": client_id,
+            "RiskExceptionConfiguration": { "BlockedIPRangeList": ["192.0.2.0/24"] },
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let initiate_auth = |ip_address: &str| {
+        super::request(
+            "InitiateAuth",
+            serde_json::json!({
+                "AuthFlow": "USER_PASSWORD_AUTH",
+                "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+                "ClientId": client_id,
+                "UserContextData": { "IpAddress": ip_address },
+            }),
+        )
+    };
+    let res = initiate_auth("192.0.2.1").await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    assert_eq!(
+        "Unable to login because of security reasons.",
+        super::body(&res)["message"]
+    );
+
+    let res = initiate_auth("198.51.100.1").await;
+    assert_eq!(200, res.status());
+    assert!(super::body(&res)["AuthenticationResult"]
+        .get("AccessToken")
+        .is_some());
+}
@@ -0,0 +1,114 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let (_, _, tokens) = super::signed_in_device_user().await;
+
+    let res = super::request(
+        "ListDevices",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "Limit": 10,
+        }),
+    )
+    .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListDevices",
+        )
+        .body(
+            r#"{
+            "AccessToken": "access_token01234",
+            "Limit": 61
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_token() {
+    super::setup().await;
+    let (_, _, tokens) = super::signed_in_device_user().await;
+
+    let res = super::request(
+        "ListDevices",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "PaginationToken": "string",
+        }),
+    )
+    .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_list_confirmed_devices() {
+    super::setup().await;
+    let (user_pool_id, _, tokens) = super::signed_in_device_user().await;
+    let device_key = tokens["NewDeviceMetadata"]["DeviceKey"].clone();
+
+    let res = super::request(
+        "ConfirmDevice",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "DeviceKey": device_key,
+            "DeviceName": "phone",
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!(true, super::body(&res)["UserConfirmationNecessary"]);
+
+    let res = super::request(
+        "ListDevices",
+        serde_json::json!({ "AccessToken": tokens["AccessToken"] }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let devices = super::body(&res)["Devices"].clone();
+    assert_eq!(1, devices.as_array().unwrap().len());
+    assert_eq!(device_key, devices[0]["DeviceKey"]);
+
+    let res = super::request(
+        "AdminGetDevice",
+        serde_json::json!({
+            "DeviceKey": device_key,
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let attributes = super::body(&res)["Device"]["DeviceAttributes"].clone();
+    assert!(attributes
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!({ "Name": "device_name", "Value": "phone" })));
+}
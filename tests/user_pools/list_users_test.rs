@@ -0,0 +1,155 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsers",
+        )
+        .body(
+            r#"{
+            "AttributesToGet": [ "email" ],
+            "Filter": "email ^= \"str\"",
+            "Limit": 10,
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsers",
+        )
+        .body(
+            r#"{
+            "Limit": 10,
+            "UserPoolId": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_with_attributes_to_get() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsers",
+        )
+        .body(r#"{"AttributesToGet":["email"],"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let attributes = body["Users"][0]["Attributes"].as_array().unwrap();
+    assert_eq!(1, attributes.len());
+    assert_eq!("email", attributes[0]["Name"]);
+    assert!(body.get("PaginationToken").is_none());
+}
+
+#[tokio::test]
+async fn test_success_to_request_with_unmatched_filter() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsers",
+        )
+        .body(r#"{"Filter":"username = \"unknown\"","UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["Users"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_unsupported_filter() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsers",
+        )
+        .body(r#"{"Filter":"custom:tenant = \"tenant\"","UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("Invalid search attribute: custom:tenant", body["message"]);
+}
+
+#[tokio::test]
+async fn test_success_to_list_stored_users() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "alice", "Passw0rd!").await;
+    super::create_user(&user_pool_id, "bob", "Passw0rd!").await;
+    let res = super::request(
+        "ListUsers",
+        serde_json::json!({
+            "AttributesToGet": ["email"],
+            "Filter": "username ^= \"al\"",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+
+    assert_eq!(200, res.status());
+    let body = super::body(&res);
+    let users = body["Users"].as_array().unwrap();
+    assert_eq!(1, users.len());
+    assert_eq!("alice", users[0]["Username"]);
+    assert_eq!(
+        serde_json::json!([{ "Name": "email", "Value": "user@example.com" }]),
+        users[0]["Attributes"]
+    );
+}
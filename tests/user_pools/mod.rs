@@ -1,4 +1,5 @@
 use fakey_cognito::*;
+use pretty_assertions::assert_eq;
 
 mod add_custom_attributes_test;
 mod admin_add_user_to_group_test;
@@ -37,14 +38,228 @@ mod create_identity_provider_test;
 mod create_resource_server_test;
 mod create_user_import_job_test;
 mod create_user_pool_client_test;
+mod create_user_pool_domain_test;
 mod create_user_pool_test;
+mod delete_identity_provider_test;
+mod delete_resource_server_test;
+mod delete_user_attributes_test;
+mod delete_user_pool_domain_test;
+mod delete_user_test;
+mod describe_identity_provider_test;
+mod describe_resource_server_test;
+mod describe_risk_configuration_test;
+mod describe_user_import_job_test;
+mod describe_user_pool_domain_test;
+mod describe_user_pool_test;
+mod forget_device_test;
+mod get_csv_header_test;
+mod get_device_test;
+mod get_identity_provider_by_identifier_test;
+mod get_ui_customization_test;
+mod get_user_attribute_verification_code_test;
+mod get_user_test;
+mod global_sign_out_test;
+mod hosted_ui_test;
+mod initiate_auth_test;
+mod list_devices_test;
+mod list_identity_providers_test;
+mod list_resource_servers_test;
+mod list_tags_for_resource_test;
+mod list_user_import_jobs_test;
+mod list_users_test;
+mod respond_to_auth_challenge_test;
+mod revoke_token_test;
+mod set_risk_configuration_test;
+mod set_ui_customization_test;
+mod sign_up_test;
+mod start_user_import_job_test;
+mod stop_user_import_job_test;
+mod tag_resource_test;
+mod untag_resource_test;
+mod update_device_status_test;
+mod update_identity_provider_test;
+mod update_resource_server_test;
+mod update_user_attributes_test;
+mod update_user_pool_domain_test;
+mod verify_user_attribute_test;
+mod well_known_test;
 
 pub async fn setup() {
     opts::init_fake_opt().await;
     let templates_opt = opts::get_opt_templates();
     tokio::join!(
-        user_pools::init_config(opts::get_opt_config()),
+        configs::init_config(opts::get_opt_config()),
         templates::init_template(templates_opt.map(String::as_str)),
         templates::init_default_template()
     );
 }
+
+/// Response header which has a code (or a temporary password) sent to a user.
+pub const CODE_DEBUG_HEADER: &str = "x-fakey-cognito-code";
+
+pub type Response = warp::http::Response<warp::hyper::body::Bytes>;
+
+/// Sends a request of an action to the user pools routes.
+pub async fn request(action: &str, body: serde_json::Value) -> Response {
+    warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            format!("AWSCognitoIdentityProviderService.{}", action),
+        )
+        .body(body.to_string())
+        .reply(&routes::user_pools_routes())
+        .await
+}
+
+/// Returns the json body of a response.
+pub fn body(res: &Response) -> serde_json::Value {
+    serde_json::from_slice(res.body()).unwrap()
+}
+
+/// Creates a stored user pool and returns its id.
+pub async fn create_user_pool() -> String {
+    create_user_pool_with(serde_json::json!({})).await
+}
+
+/// Creates a stored user pool with settings of `CreateUserPool` and returns its id.
+pub async fn create_user_pool_with(mut settings: serde_json::Value) -> String {
+    settings["PoolName"] = "pool".into();
+    let res = request("CreateUserPool", settings).await;
+    assert_eq!(200, res.status());
+    body(&res)["UserPool"]["Id"].as_str().unwrap().to_string()
+}
+
+/// Creates an app client with the admin and user password auth flows and returns its id.
+pub async fn create_user_pool_client(user_pool_id: &str) -> String {
+    let res = request(
+        "CreateUserPoolClient",
+        serde_json::json!({
+            "ClientName": "client",
+            "ExplicitAuthFlows": [
+                "ALLOW_ADMIN_USER_PASSWORD_AUTH",
+                "ALLOW_REFRESH_TOKEN_AUTH",
+                "ALLOW_USER_PASSWORD_AUTH"
+            ],
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    body(&res)["UserPoolClient"]["ClientId"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+/// Creates a confirmed user with a permanent password.
+pub async fn create_user(user_pool_id: &str, username: &str, password: &str) {
+    let res = request(
+        "AdminCreateUser",
+        serde_json::json!({
+            "UserAttributes": [{ "Name": "email", "Value": "user@example.com" }],
+            "Username": username,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = request(
+        "AdminSetUserPassword",
+        serde_json::json!({
+            "Password": password,
+            "Permanent": true,
+            "Username": username,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+}
+
+/// Signs in a user by the admin password auth flow and returns the `AuthenticationResult`.
+pub async fn sign_in(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    password: &str,
+) -> serde_json::Value {
+    let res = request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": username, "PASSWORD": password },
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    body(&res)["AuthenticationResult"].clone()
+}
+
+/// Creates a user pool, an app client and a signed in user, and returns them with the tokens.
+pub async fn signed_in_user() -> (String, String, serde_json::Value) {
+    signed_in_user_of(create_user_pool().await).await
+}
+
+/// Creates a user pool tracking devices, an app client and a signed in user, and returns them with the tokens.
+///
+/// The tokens include `NewDeviceMetadata` of the device the user signed in on.
+pub async fn signed_in_device_user() -> (String, String, serde_json::Value) {
+    let user_pool_id = create_user_pool_with(serde_json::json!({
+        "DeviceConfiguration": {
+            "ChallengeRequiredOnNewDevice": false,
+            "DeviceOnlyRememberedOnUserPrompt": true
+        }
+    }))
+    .await;
+    signed_in_user_of(user_pool_id).await
+}
+
+/// Creates an app client and a signed in user of a user pool, and returns them with the tokens.
+pub async fn signed_in_user_of(user_pool_id: String) -> (String, String, serde_json::Value) {
+    let client_id = create_user_pool_client(&user_pool_id).await;
+    create_user(&user_pool_id, "user", "Passw0rd!").await;
+    let tokens = sign_in(&user_pool_id, &client_id, "user", "Passw0rd!").await;
+    (user_pool_id, client_id, tokens)
+}
+
+/// Creates a domain and an app client of the implicit grant, and returns the host and the client id.
+///
+/// The client's callback URL is `https://example.com/callback`.
+pub async fn create_hosted_ui_client(user_pool_id: &str) -> (String, String) {
+    let domain = format!(
+        "auth-{}",
+        user_pool_id.split('_').next_back().unwrap().to_lowercase()
+    );
+    let res = request(
+        "CreateUserPoolDomain",
+        serde_json::json!({ "Domain": domain, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = request(
+        "CreateUserPoolClient",
+        serde_json::json!({
+            "AllowedOAuthFlows": ["implicit"],
+            "AllowedOAuthFlowsUserPoolClient": true,
+            "AllowedOAuthScopes": ["openid"],
+            "CallbackURLs": ["https://example.com/callback"],
+            "ClientName": "client",
+            "LogoutURLs": ["https://example.com/signed-out"],
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let client_id = body(&res)["UserPoolClient"]["ClientId"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    (
+        format!("{}.auth.us-east-1.amazoncognito.com", domain),
+        client_id,
+    )
+}
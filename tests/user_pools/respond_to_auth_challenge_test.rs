@@ -0,0 +1,118 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+        )
+        .body(
+            r#"{
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
+            "ChallengeResponses": { 
+               "USERNAME" : "user",
+               "NEW_PASSWORD" : "password"
+            },
+            "ClientId": "string",
+            "Session": "session0123456789012345"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+        )
+        .body(
+            r#"{
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
+            "ClientId": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_change_temporary_password() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "AdminCreateUser",
+        serde_json::json!({
+            "TemporaryPassword": "Temp0rary!",
+            "Username": "user",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "InitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Temp0rary!" },
+            "ClientId": client_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body = super::body(&res);
+    assert_eq!("NEW_PASSWORD_REQUIRED", body["ChallengeName"]);
+    assert!(body.get("AuthenticationResult").is_none());
+
+    let respond = |session: serde_json::Value| {
+        super::request(
+            "RespondToAuthChallenge",
+            serde_json::json!({
+                "ChallengeName": "NEW_PASSWORD_REQUIRED",
+                "ChallengeResponses": { "USERNAME": "user", "NEW_PASSWORD": "Passw0rd!" },
+                "ClientId": client_id,
+                "Session": session,
+            }),
+        )
+    };
+    let res = respond(body["Session"].clone()).await;
+    assert_eq!(200, res.status());
+    assert!(super::body(&res)["AuthenticationResult"]
+        .get("RefreshToken")
+        .is_some());
+
+    let res = respond(body["Session"].clone()).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    super::sign_in(&user_pool_id, &client_id, "user", "Passw0rd!").await;
+}
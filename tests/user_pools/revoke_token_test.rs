@@ -0,0 +1,95 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RevokeToken",
+        )
+        .body(
+            r#"{
+            "ClientId": "client_id",
+            "ClientSecret": "client_secret",
+            "Token": "refresh_token01234"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RevokeToken",
+        )
+        .body(
+            r#"{
+            "ClientId": "client_id",
+            "Token": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_revoke_refresh_token() {
+    super::setup().await;
+
+    let (user_pool_id, client_id, tokens) = super::signed_in_user().await;
+    let res = super::request(
+        "RevokeToken",
+        serde_json::json!({ "ClientId": client_id, "Token": tokens["AccessToken"] }),
+    )
+    .await;
+    assert_eq!(
+        "UnsupportedTokenTypeException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::request(
+        "RevokeToken",
+        serde_json::json!({ "ClientId": client_id, "Token": tokens["RefreshToken"] }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "GlobalSignOut",
+        serde_json::json!({ "AccessToken": tokens["AccessToken"] }),
+    )
+    .await;
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    let res = super::request(
+        "AdminUserGlobalSignOut",
+        serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+}
@@ -0,0 +1,127 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetRiskConfiguration",
+        )
+        .body(
+            r#"{
+            "AccountTakeoverRiskConfiguration": { 
+               "Actions": { 
+                  "HighAction": { 
+                     "EventAction": "BLOCK",
+                     "Notify": true
+                  },
+                  "LowAction": { 
+                     "EventAction": "NO_ACTION",
+                     "Notify": false
+                  },
+                  "MediumAction": { 
+                     "EventAction": "MFA_IF_CONFIGURED",
+                     "Notify": true
+                  }
+               },
+               "NotifyConfiguration": { 
+                  "BlockEmail": { 
+                     "HtmlBody": "<p>blocked</p>",
+                     "Subject": "Blocked sign-in",
+                     "TextBody": "blocked"
+                  },
+                  "From": "noreply@example.com",
+                  "SourceArn": "arn:aws:ses:us-east-1:123456789012:identity/example.com"
+               }
+            },
+            "ClientId": "string",
+            "CompromisedCredentialsRiskConfiguration": { 
+               "Actions": { 
+                  "EventAction": "BLOCK"
+               },
+               "EventFilter": [ "SIGN_IN" ]
+            },
+            "RiskExceptionConfiguration": { 
+               "BlockedIPRangeList": [ "192.0.2.0/24" ],
+               "SkippedIPRangeList": [ "198.51.100.7" ]
+            },
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetRiskConfiguration",
+        )
+        .body(
+            r#"{
+            "CompromisedCredentialsRiskConfiguration": { 
+               "Actions": { 
+                  "EventAction": "MFA_REQUIRED"
+               }
+            },
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_echoes_configuration() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetRiskConfiguration",
+        )
+        .body(
+            r#"{
+            "RiskExceptionConfiguration": { 
+               "BlockedIPRangeList": [ "192.0.2.0/24" ]
+            },
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let configuration = &body["RiskConfiguration"];
+    assert_eq!(
+        "192.0.2.0/24",
+        configuration["RiskExceptionConfiguration"]["BlockedIPRangeList"][0]
+    );
+    assert!(configuration["ClientId"].is_null());
+}
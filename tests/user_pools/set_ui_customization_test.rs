@@ -0,0 +1,202 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUICustomization",
+        )
+        .body(
+            r#"{
+            "ClientId": "client_id",
+            "CSS": ".banner-customizable { background-color: #ffffff; }",
+            "ImageFile": "iVBORw0KGgo=",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUICustomization",
+        )
+        .body(
+            r#"{
+            "ClientId": "",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_css() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUICustomization",
+        )
+        .body(
+            r#"{
+            "CSS": "body { background-color: #ffffff; }",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_image_file() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUICustomization",
+        )
+        .body(
+            r#"{
+            "ImageFile": "R0lGODlh",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_customize_login_page() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (host, client_id) = super::create_hosted_ui_client(&user_pool_id).await;
+    super::create_user(&user_pool_id, "user", "Passw0rd!").await;
+    let css = ".submitButton-customizable { background-color: #123456; }";
+
+    let res = super::request(
+        "SetUICustomization",
+        serde_json::json!({
+            "ClientId": client_id,
+            "CSS": css,
+            "ImageFile": "iVBORw0KGgo=",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = super::request(
+        "GetUICustomization",
+        serde_json::json!({ "ClientId": client_id, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    let customization = &super::body(&res)["UICustomization"];
+    assert_eq!(css, customization["CSS"]);
+    assert_eq!(client_id, customization["ClientId"]);
+    let image_url = customization["ImageUrl"].as_str().unwrap().to_string();
+    let res = super::request(
+        "GetUICustomization",
+        serde_json::json!({ "UserPoolId": user_pool_id }),
+    )
+    .await;
+    let customization = &super::body(&res)["UICustomization"];
+    assert_eq!("ALL", customization["ClientId"]);
+    assert!(customization.get("CSS").is_none());
+
+    let filter = routes::hosted_ui_routes();
+    let image_path = &image_url[image_url.find(&format!("/{}/", user_pool_id)).unwrap()..];
+    let res = warp::test::request()
+        .method("GET")
+        .path(image_path)
+        .reply(&filter)
+        .await;
+    assert_eq!(200, res.status());
+    assert_eq!("image/png", res.headers().get("content-type").unwrap());
+
+    let login_path = format!(
+        "/login?client_id={}&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&response_type=token&state=xyz",
+        client_id
+    );
+    let res = warp::test::request()
+        .method("GET")
+        .path(&login_path)
+        .header("host", host.as_str())
+        .reply(&filter)
+        .await;
+    assert_eq!(200, res.status());
+    let page = String::from_utf8_lossy(res.body()).into_owned();
+    assert!(page.contains(css));
+    assert!(page.contains(&image_url));
+
+    let login = |password: &str| {
+        warp::test::request()
+            .method("POST")
+            .path(&login_path)
+            .header("host", host.as_str())
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(format!("username=user&password={}", password))
+            .reply(&filter)
+    };
+    let res = login("wrong").await;
+    assert_eq!(200, res.status());
+    assert!(String::from_utf8_lossy(res.body()).contains("Incorrect username or password."));
+    let res = login("Passw0rd%21").await;
+    assert_eq!(302, res.status());
+    let location = res.headers().get("location").unwrap().to_str().unwrap();
+    assert!(location.starts_with("https://example.com/callback#id_token="));
+    assert!(location.ends_with("&state=xyz"));
+
+    let res = warp::test::request()
+        .method("GET")
+        .path(&login_path.replace("response_type=token", "response_type=code"))
+        .header("host", host.as_str())
+        .reply(&filter)
+        .await;
+    assert_eq!(400, res.status());
+}
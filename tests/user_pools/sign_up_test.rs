@@ -0,0 +1,214 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp")
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "ClientId": "string",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "Password": "password",
+            "SecretHash": "string",
+            "UserAttributes": [ 
+               { 
+                  "Name": "email",
+                  "Value": "user@example.com"
+               }
+            ],
+            "UserContextData": { 
+               "EncodedData": "string"
+            },
+            "Username": "string",
+            "ValidationData": [ 
+               { 
+                  "Name": "string",
+                  "Value": "string"
+               }
+            ]
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp")
+        .body(
+            r#"{
+            "ClientId": "string",
+            "Password": "",
+            "Username": "string"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_without_attributes() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp")
+        .body(r#"{"ClientId":"string","Password":"password","Username":"string"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body.get("CodeDeliveryDetails").is_none());
+    assert_eq!(false, body["UserConfirmed"]);
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_unknown_attribute() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp")
+        .body(
+            r#"{
+            "ClientId": "string",
+            "Password": "password",
+            "UserAttributes": [{ "Name": "unknown", "Value": "string" }],
+            "Username": "string"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_sign_up_and_confirm_with_code() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "SignUp",
+        serde_json::json!({
+            "ClientId": client_id,
+            "Password": "Passw0rd!",
+            "UserAttributes": [{ "Name": "email", "Value": "user@example.com" }],
+            "Username": "user",
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let code = res
+        .headers()
+        .get(super::CODE_DEBUG_HEADER)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = super::body(&res);
+    assert_eq!(false, body["UserConfirmed"]);
+
+    let res = super::request(
+        "AdminInitiateAuth",
+        serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": { "USERNAME": "user", "PASSWORD": "Passw0rd!" },
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(
+        "UserNotConfirmedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let confirm = |code: &str| {
+        super::request(
+            "ConfirmSignUp",
+            serde_json::json!({
+                "ClientId": client_id,
+                "ConfirmationCode": code,
+                "Username": "user",
+            }),
+        )
+    };
+    let res = confirm("000000x").await;
+    assert_eq!(
+        "CodeMismatchException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    let res = confirm(&code).await;
+    assert_eq!(200, res.status());
+
+    let tokens = super::sign_in(&user_pool_id, &client_id, "user", "Passw0rd!").await;
+    let claims = jwt::token_claims(tokens["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!(body["UserSub"], claims["sub"]);
+}
+
+#[tokio::test]
+async fn test_failure_to_sign_up_with_password_breaking_user_pool_policy() {
+    super::setup().await;
+
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "Policies": { "PasswordPolicy": { "MinimumLength": 12 } },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = super::request(
+        "SignUp",
+        serde_json::json!({
+            "ClientId": client_id,
+            "Password": "Passw0rd!",
+            "Username": "user",
+        }),
+    )
+    .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidPasswordException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+    assert_eq!(
+        "Password did not conform with policy: Password not long enough",
+        super::body(&res)["message"]
+    );
+}
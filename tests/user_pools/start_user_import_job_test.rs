@@ -0,0 +1,152 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.StartUserImportJob",
+        )
+        .body(
+            r#"{
+            "JobId": "import-0123456789",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.StartUserImportJob",
+        )
+        .body(
+            r#"{
+            "JobId": "",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_import_uploaded_csv() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::request(
+        "CreateUserImportJob",
+        serde_json::json!({
+            "CloudWatchLogsRoleArn": "arn:aws:iam::123456789012:role/CognitoImportRole",
+            "JobName": "job",
+            "UserPoolId": user_pool_id,
+        }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let job = super::body(&res)["UserImportJob"].clone();
+    assert_eq!("Created", job["Status"]);
+    let job_id = job["JobId"].as_str().unwrap().to_string();
+    let pre_signed_url = job["PreSignedUrl"].as_str().unwrap().to_string();
+    let path = &pre_signed_url[pre_signed_url.find(&user_pool_id).unwrap() - 1..];
+
+    let res = super::request(
+        "GetCSVHeader",
+        serde_json::json!({ "UserPoolId": user_pool_id }),
+    )
+    .await;
+    let header = super::body(&res)["CSVHeader"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c.as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    let row = |username: &str| {
+        header
+            .iter()
+            .map(|column| match column.as_str() {
+                "cognito:username" => username,
+                "email" => "user@example.com",
+                "email_verified" => "true",
+                "cognito:mfa_enabled" => "false",
+                _ => "",
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let csv = [header.join(","), row("imported"), row("other")].join("\n");
+    let res = warp::test::request()
+        .method("PUT")
+        .path(path)
+        .body(csv)
+        .reply(&routes::user_import_routes())
+        .await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "StartUserImportJob",
+        serde_json::json!({ "JobId": job_id, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!("Pending", super::body(&res)["UserImportJob"]["Status"]);
+
+    let mut job = serde_json::Value::Null;
+    for _ in 0..100 {
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        let res = super::request(
+            "DescribeUserImportJob",
+            serde_json::json!({ "JobId": job_id, "UserPoolId": user_pool_id }),
+        )
+        .await;
+        job = super::body(&res)["UserImportJob"].clone();
+        if job["Status"] == "Succeeded" {
+            break;
+        }
+    }
+    assert_eq!("Succeeded", job["Status"]);
+    assert_eq!(2, job["ImportedUsers"]);
+
+    let res = super::request(
+        "AdminGetUser",
+        serde_json::json!({ "Username": "imported", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    assert_eq!("RESET_REQUIRED", super::body(&res)["UserStatus"]);
+
+    let res = super::request(
+        "StopUserImportJob",
+        serde_json::json!({ "JobId": job_id, "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "PreconditionNotMetException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
@@ -0,0 +1,137 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.TagResource",
+        )
+        .body(
+            r#"{
+            "ResourceArn": "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example",
+            "Tags": { 
+               "env" : "dev" 
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.TagResource",
+        )
+        .body(
+            r#"{
+            "ResourceArn": "",
+            "Tags": { 
+               "env" : "dev" 
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_invalid_tags() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.TagResource",
+        )
+        .body(
+            r#"{
+            "ResourceArn": "arn:aws:cognito-idp:us-east-1:123456789012:userpool/us-east-1_example",
+            "Tags": { 
+               "env" : "dev",
+               "aws:env" : "dev"
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_keep_tags_of_resource() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "UserPoolTags": { "env": "dev", "team": "auth" }
+    }))
+    .await;
+    let res = super::request(
+        "DescribeUserPool",
+        serde_json::json!({ "UserPoolId": user_pool_id }),
+    )
+    .await;
+    let arn = super::body(&res)["UserPool"]["Arn"].clone();
+
+    let res = super::request(
+        "TagResource",
+        serde_json::json!({ "ResourceArn": arn, "Tags": { "env": "prod", "owner": "me" } }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let res = super::request(
+        "UntagResource",
+        serde_json::json!({ "ResourceArn": arn, "TagKeys": ["team"] }),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::request(
+        "ListTagsForResource",
+        serde_json::json!({ "ResourceArn": arn }),
+    )
+    .await;
+    assert_eq!(
+        serde_json::json!({ "env": "prod", "owner": "me" }),
+        super::body(&res)["Tags"]
+    );
+    let res = super::request(
+        "DescribeUserPool",
+        serde_json::json!({ "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert_eq!(
+        serde_json::json!({ "env": "prod", "owner": "me" }),
+        super::body(&res)["UserPool"]["UserPoolTags"]
+    );
+}
@@ -0,0 +1,95 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateIdentityProvider",
+        )
+        .body(
+            r#"{
+            "AttributeMapping": { 
+               "email" : "email" 
+            },
+            "IdpIdentifiers": [ "example.com" ],
+            "ProviderDetails": { 
+               "authorize_scopes" : "openid email" 
+            },
+            "ProviderName": "Google",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateIdentityProvider",
+        )
+        .body(
+            r#"{
+            "ProviderName": "",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_echoes_provider() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateIdentityProvider",
+        )
+        .body(
+            r#"{
+            "IdpIdentifiers": [ "example.com" ],
+            "ProviderName": "Google",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let provider = &body["IdentityProvider"];
+    assert_eq!("Google", provider["ProviderName"]);
+    assert_eq!(
+        serde_json::json!(["example.com"]),
+        provider["IdpIdentifiers"]
+    );
+    assert_eq!(serde_json::json!({}), provider["AttributeMapping"]);
+}
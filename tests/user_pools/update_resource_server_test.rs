@@ -0,0 +1,106 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateResourceServer",
+        )
+        .body(
+            r#"{
+            "Identifier": "https://api.example.com",
+            "Name": "api",
+            "Scopes": [ 
+               { 
+                  "ScopeDescription": "read",
+                  "ScopeName": "read"
+               },
+               { 
+                  "ScopeDescription": "write",
+                  "ScopeName": "write"
+               }
+            ],
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateResourceServer",
+        )
+        .body(
+            r#"{
+            "Identifier": "",
+            "Name": "api",
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request_with_duplicated_scopes() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateResourceServer",
+        )
+        .body(
+            r#"{
+            "Identifier": "https://api.example.com",
+            "Name": "api",
+            "Scopes": [ 
+               { 
+                  "ScopeDescription": "read",
+                  "ScopeName": "read"
+               },
+               { 
+                  "ScopeDescription": "read again",
+                  "ScopeName": "read"
+               }
+            ],
+            "UserPoolId": "user_pool_id"
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,125 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let (_, _, tokens) = super::signed_in_user().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserAttributes",
+        )
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "ClientMetadata": {
+                    "string": "string",
+                },
+                "UserAttributes": [
+                    {
+                        "Name": "name",
+                        "Value": "string",
+                    },
+                ],
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserAttributes",
+        )
+        .body(
+            r#"{
+            "AccessToken": "access_token01234",
+            "UserAttributes": []
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_success_to_request_with_verifiable_attributes() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "AutoVerifiedAttributes": ["email", "phone_number"],
+    }))
+    .await;
+    let (_, _, tokens) = super::signed_in_user_of(user_pool_id).await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserAttributes",
+        )
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "UserAttributes": [
+                    {
+                        "Name": "email",
+                        "Value": "new@example.com",
+                    },
+                    {
+                        "Name": "name",
+                        "Value": "user",
+                    },
+                    {
+                        "Name": "phone_number",
+                        "Value": "+15555550100",
+                    },
+                ],
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let details = body["CodeDeliveryDetailsList"].as_array().unwrap();
+    assert_eq!(2, details.len());
+    assert_eq!("email", details[0]["AttributeName"]);
+    assert_eq!("EMAIL", details[0]["DeliveryMedium"]);
+    assert_eq!("phone_number", details[1]["AttributeName"]);
+    assert_eq!("SMS", details[1]["DeliveryMedium"]);
+    assert_eq!(
+        2,
+        res.headers()
+            .get(super::CODE_DEBUG_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .split(',')
+            .count()
+    );
+}
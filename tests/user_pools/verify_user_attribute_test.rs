@@ -0,0 +1,96 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let (user_pool_id, _, tokens) = super::signed_in_user().await;
+    let res = super::request(
+        "GetUserAttributeVerificationCode",
+        serde_json::json!({ "AccessToken": tokens["AccessToken"], "AttributeName": "email" }),
+    )
+    .await;
+    let code = res.headers().get(super::CODE_DEBUG_HEADER).unwrap().clone();
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.VerifyUserAttribute",
+        )
+        .body(
+            serde_json::json!({
+                "AccessToken": tokens["AccessToken"],
+                "AttributeName": "email",
+                "Code": code.to_str().unwrap(),
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(res.body().is_empty());
+
+    let res = super::request(
+        "AdminGetUser",
+        serde_json::json!({ "Username": "user", "UserPoolId": user_pool_id }),
+    )
+    .await;
+    assert!(super::body(&res)["UserAttributes"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!({ "Name": "email_verified", "Value": "true" })));
+}
+
+#[tokio::test]
+async fn test_failure_to_verify_with_wrong_code() {
+    super::setup().await;
+
+    let (_, _, tokens) = super::signed_in_user().await;
+    let res = super::request(
+        "VerifyUserAttribute",
+        serde_json::json!({
+            "AccessToken": tokens["AccessToken"],
+            "AttributeName": "email",
+            "Code": "123456",
+        }),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "CodeMismatchException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.VerifyUserAttribute",
+        )
+        .body(
+            r#"{
+            "AccessToken": "access_token01234",
+            "AttributeName": "email",
+            "Code": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
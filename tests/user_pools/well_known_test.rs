@@ -0,0 +1,59 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request_user_pool_openid_configuration() {
+    super::setup().await;
+
+    let filter = routes::well_known_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path("/us-east-1_pool/.well-known/openid-configuration")
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(
+        user_pools::user_pool_issuer("us-east-1_pool"),
+        body["issuer"]
+    );
+    assert!(body["jwks_uri"]
+        .as_str()
+        .unwrap()
+        .ends_with("/us-east-1_pool/.well-known/jwks.json"));
+}
+
+#[tokio::test]
+async fn test_success_to_verify_tokens_with_user_pool_jwks() {
+    super::setup().await;
+
+    let (user_pool_id, _, tokens) = super::signed_in_user().await;
+    let filter = routes::well_known_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path(&format!("/{}/.well-known/jwks.json", user_pool_id))
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let key = &body["keys"][0];
+    assert_eq!("RS256", key["alg"]);
+    assert!(key.get("d").is_none());
+
+    let header = tokens["IdToken"]
+        .as_str()
+        .unwrap()
+        .split('.')
+        .next()
+        .unwrap();
+    let header: serde_json::Value = serde_json::from_slice(
+        &base64::Engine::decode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, header).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(key["kid"], header["kid"]);
+    assert!(jwt::token_signer()
+        .verify(tokens["IdToken"].as_str().unwrap())
+        .is_some());
+}